@@ -0,0 +1,155 @@
+//! Runtime context passed to the [`ValidatorCtx`](crate::ValidatorCtx)
+//! validators, provided through [`FromJson::from_json_ctx`](crate::FromJson::from_json_ctx)
+//! and its family or through [`with_ctx`].
+//!
+//! The context is held by the current thread for the duration of the
+//! call (type-erased, its type is checked by each validator), so it is
+//! visible to all the nested values parsed or validated during the call
+//! but not to the code running on other threads. When no context of the
+//! type of a `ctx_validators` validator is provided (e.g. the struct is
+//! parsed with `from_json`, or with a context of another type), the
+//! validator fails with an [`ErrKind::MissingCtx`] error instead of
+//! being skipped.
+
+use std::{any::Any, cell::Cell};
+
+use crate::{ErrKind, Path, PropOrIdx, ValidationErr};
+
+thread_local! {
+    static CURRENT: Cell<Option<*const dyn Any>> = const { Cell::new(None) };
+}
+
+/// Runs `f` with `ctx` as the current context, the previous one is
+/// restored afterwards (even if `f` panics).
+///
+/// The context is type-erased with [`Any`], so `C` must be `'static`:
+/// it cannot borrow (e.g. `&DbPool` or `HashSet<&str>`), wrap the
+/// shared data in an owned type instead (e.g. `Arc<DbPool>` or
+/// `HashSet<String>`). It is only held by the current thread until `f`
+/// returns, the code that `f` runs on other threads (or defers past
+/// its return) does not see it and its `ctx_validators` fail with
+/// [`ErrKind::MissingCtx`].
+pub fn with_ctx<C: Any, R, F: FnOnce() -> R>(ctx: &C, f: F) -> R {
+    let prev = CURRENT.with(|current| current.replace(Some(ctx as &dyn Any as *const dyn Any)));
+
+    let _restore = Restore(prev);
+
+    f()
+}
+
+/// Calls `f` with the current context if it is of type `C`, otherwise
+/// with `None`.
+pub fn with<C: Any, R, F: FnOnce(Option<&C>) -> R>(f: F) -> R {
+    let current = CURRENT.with(|current| current.get());
+
+    // SAFETY: the pointer is only set while `with_ctx` borrows the context
+    // and the reference handed to `f` cannot escape it
+    f(current.and_then(|ctx| unsafe { &*ctx }.downcast_ref::<C>()))
+}
+
+/// Calls `f` with the current context if it is of type `C`, otherwise
+/// returns the [`ErrKind::MissingCtx`] error of `target` (the value at
+/// `path` itself if `None`).
+pub fn require<C, F>(target: Option<PropOrIdx>, path: &Path, f: F) -> Result<(), ValidationErr>
+where
+    C: Any,
+    F: FnOnce(&C) -> Result<(), ValidationErr>,
+{
+    with(|ctx| match ctx {
+        Some(ctx) => f(ctx),
+        None => Err(missing::<C>(target, path)),
+    })
+}
+
+/// Same as [`require`] for the stack errors methods.
+pub fn require_stack_errs<C, F>(
+    target: Option<PropOrIdx>,
+    path: &Path,
+    f: F,
+) -> Result<(), Vec<ValidationErr>>
+where
+    C: Any,
+    F: FnOnce(&C) -> Result<(), Vec<ValidationErr>>,
+{
+    with(|ctx| match ctx {
+        Some(ctx) => f(ctx),
+        None => Err(vec![missing::<C>(target, path)]),
+    })
+}
+
+fn missing<C: Any>(target: Option<PropOrIdx>, path: &Path) -> ValidationErr {
+    let (target, path) = match target {
+        Some(target) => (target, path.clone()),
+
+        None => match path.split_last() {
+            Some((target, path)) => (target.clone(), path.to_vec()),
+            None => (PropOrIdx::Prop(String::new()), Path::new()),
+        },
+    };
+
+    let ty = std::any::type_name::<C>();
+
+    ValidationErr::new(target, path, &format!("missing context of type '{}'", ty))
+        .with_kind(ErrKind::MissingCtx)
+        .with_param("ctx", ty)
+}
+
+struct Restore(Option<*const dyn Any>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let prev = self.0;
+        CURRENT.with(|current| current.set(prev));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn current_ctx() {
+        assert_eq!(with(|ctx: Option<&u8>| ctx.copied()), None);
+
+        with_ctx(&1u8, || {
+            assert_eq!(with(|ctx: Option<&u8>| ctx.copied()), Some(1));
+            assert_eq!(with(|ctx: Option<&u16>| ctx.copied()), None);
+
+            with_ctx(&2u16, || {
+                assert_eq!(with(|ctx: Option<&u8>| ctx.copied()), None);
+                assert_eq!(with(|ctx: Option<&u16>| ctx.copied()), Some(2));
+            });
+
+            assert_eq!(with(|ctx: Option<&u8>| ctx.copied()), Some(1));
+        });
+
+        assert_eq!(with(|ctx: Option<&u8>| ctx.copied()), None);
+    }
+
+    #[test]
+    fn required_ctx() {
+        let path = vec![PropOrIdx::from("user")];
+
+        let err = require(Some(PropOrIdx::from("name")), &path, |_: &u8| Ok(())).unwrap_err();
+
+        assert_eq!(err.kind, ErrKind::MissingCtx);
+        assert_eq!(err.target, PropOrIdx::from("name"));
+        assert_eq!(err.path, path);
+        assert_eq!(err.msg, "missing context of type 'u8'");
+
+        let errs = require_stack_errs(None, &path, |_: &u8| Ok(())).unwrap_err();
+
+        assert_eq!(errs[0].target, PropOrIdx::from("user"));
+        assert_eq!(errs[0].path, Path::new());
+
+        with_ctx(&1u8, || {
+            assert_eq!(
+                require(None, &path, |ctx: &u8| {
+                    assert_eq!(*ctx, 1);
+                    Ok(())
+                }),
+                Ok(())
+            );
+        });
+    }
+}
@@ -10,11 +10,7 @@ pub enum Err {
 
 impl Err {
     pub fn new_validation_err<M: Display>(target: PropOrIdx, path: Vec<PropOrIdx>, msg: M) -> Self {
-        Self::ValidationErr(ValidationErr {
-            target,
-            path,
-            msg: msg.to_string(),
-        })
+        Self::ValidationErr(ValidationErr::new(target, path, &msg.to_string()))
     }
 
     #[inline]
@@ -59,11 +55,7 @@ impl Errs {
         path: Vec<PropOrIdx>,
         msg: M,
     ) -> Self {
-        Self::ValidationErrs(vec![ValidationErr {
-            target,
-            path,
-            msg: msg.to_string(),
-        }])
+        Self::ValidationErrs(vec![ValidationErr::new(target, path, &msg.to_string())])
     }
 
     #[inline]
@@ -146,4 +138,17 @@ mod test {
             String::from("[{\"target\":\"field\",\"path\":[\"obj\",1],\"msg\":\"test\\\"\"},{\"target\":5,\"path\":[7,\"field\"],\"msg\":\"\\\"nice\"}]")
         )
     }
+
+    #[test]
+    fn validation_err_with_code_to_json() {
+        assert_eq!(
+            ValidationErr::new(From::from("name"), Vec::new(), "too long")
+                .with_code("max_len")
+                .with_param("max", 20usize)
+                .with_param("unit", "chars")
+                .with_param("values", [1.5, f64::NAN])
+                .to_json(),
+            String::from("{\"target\":\"name\",\"path\":[],\"msg\":\"too long\",\"code\":\"max_len\",\"params\":{\"max\":20,\"unit\":\"chars\",\"values\":[1.5,null]}}")
+        )
+    }
 }
@@ -0,0 +1,92 @@
+/// The kind of a [`ValidationErr`](super::ValidationErr), one per
+/// built-in constraint (named after its attribute) plus `Custom` for
+/// the errors of the custom validators.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrKind {
+    Required,
+    RequiredIf,
+    RequiredUnless,
+    ExactlyOneOf,
+    AtMostOneOf,
+    NotNull,
+    TypeMismatch,
+    TooLarge,
+    TooSmall,
+    Max,
+    Min,
+    Range,
+    MultipleOf,
+    Enum,
+    NotEnum,
+    Infinite,
+    MaxFracs,
+    Len,
+    MaxLen,
+    MinLen,
+    StartsWith,
+    EndsWith,
+    Contains,
+    NotContains,
+    Charset,
+    Format,
+    Pattern,
+    Sorted,
+    SortedDesc,
+    MustBe,
+    EqField,
+    GtField,
+    LtField,
+
+    /// Returned by the `ctx_validators` when no context of their type
+    /// is provided (see [`ctx`](crate::ctx)).
+    MissingCtx,
+
+    /// Returned by the custom validators, optionally with a code of
+    /// their own (see [`ValidationErr::with_code`](super::ValidationErr::with_code)).
+    Custom(Option<&'static str>),
+}
+
+impl ErrKind {
+    /// The stable code of the kind (e.g. `"max_len"`), the code of a
+    /// `Custom` kind defaults to `"custom"`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Required => "required",
+            Self::RequiredIf => "required_if",
+            Self::RequiredUnless => "required_unless",
+            Self::ExactlyOneOf => "exactly_one_of",
+            Self::AtMostOneOf => "at_most_one_of",
+            Self::NotNull => "not_null",
+            Self::TypeMismatch => "type_mismatch",
+            Self::TooLarge => "too_large",
+            Self::TooSmall => "too_small",
+            Self::Max => "max",
+            Self::Min => "min",
+            Self::Range => "range",
+            Self::MultipleOf => "multiple_of",
+            Self::Enum => "enum",
+            Self::NotEnum => "not_enum",
+            Self::Infinite => "infinite",
+            Self::MaxFracs => "max_fracs",
+            Self::Len => "len",
+            Self::MaxLen => "max_len",
+            Self::MinLen => "min_len",
+            Self::StartsWith => "starts_with",
+            Self::EndsWith => "ends_with",
+            Self::Contains => "contains",
+            Self::NotContains => "not_contains",
+            Self::Charset => "charset",
+            Self::Format => "format",
+            Self::Pattern => "pattern",
+            Self::Sorted => "sorted",
+            Self::SortedDesc => "sorted_desc",
+            Self::MustBe => "must_be",
+            Self::EqField => "eq_field",
+            Self::GtField => "gt_field",
+            Self::LtField => "lt_field",
+            Self::MissingCtx => "missing_ctx",
+            Self::Custom(code) => code.unwrap_or("custom"),
+        }
+    }
+}
@@ -2,11 +2,17 @@ mod prop_or_idx;
 pub use prop_or_idx::*;
 
 mod syntx_err;
-pub use syntx_err::SyntaxErr;
+pub use syntx_err::{SyntaxErr, SyntaxErrKind};
 
 mod validation_err;
 pub use validation_err::ValidationErr;
 
+mod kind;
+pub use kind::ErrKind;
+
+mod param;
+pub use param::Param;
+
 mod err;
 pub use err::{Err, Errs};
 
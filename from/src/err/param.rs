@@ -0,0 +1,133 @@
+use std::fmt;
+
+/// A typed parameter of a [`ValidationErr`](super::ValidationErr)
+/// (e.g. the limit of `max_len` and the actual length).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Param {
+    Int(i128),
+    UInt(u128),
+    Float(f64),
+    Str(String),
+    Bool(bool),
+    List(Vec<Param>),
+}
+
+impl Param {
+    pub fn to_json(&self) -> String {
+        match self {
+            Param::Int(i) => i.to_string(),
+            Param::UInt(u) => u.to_string(),
+            // JSON has no representation of 'NaN' and infinity
+            Param::Float(f) if !f.is_finite() => String::from("null"),
+            Param::Float(f) => f.to_string(),
+            Param::Str(s) => super::string_to_json(s),
+            Param::Bool(b) => b.to_string(),
+            Param::List(params) => {
+                let mut json = String::from('[');
+
+                for (i, param) in params.iter().enumerate() {
+                    if i > 0 {
+                        json.push(',');
+                    };
+
+                    json.push_str(&param.to_json());
+                }
+
+                json.push(']');
+
+                json
+            }
+        }
+    }
+}
+
+/// Used by the message placeholders (e.g. `{actual}`), the elements
+/// of a list are separated by commas.
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Param::Int(i) => i.fmt(f),
+            Param::UInt(u) => u.fmt(f),
+            Param::Float(fl) => fl.fmt(f),
+            Param::Str(s) => s.fmt(f),
+            Param::Bool(b) => b.fmt(f),
+            Param::List(params) => {
+                for (i, param) in params.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    };
+
+                    param.fmt(f)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident, $as:ty, $($ty:ty),+) => {
+        $(
+            impl From<$ty> for Param {
+                #[inline]
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value as $as)
+                }
+            }
+        )+
+    };
+}
+
+impl_from!(Int, i128, i8, i16, i32, i64, i128, isize);
+impl_from!(UInt, u128, u8, u16, u32, u64, u128, usize);
+impl_from!(Float, f64, f32, f64);
+
+impl From<bool> for Param {
+    #[inline]
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<&str> for Param {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Self::Str(String::from(value))
+    }
+}
+
+impl From<String> for Param {
+    #[inline]
+    fn from(value: String) -> Self {
+        Self::Str(value)
+    }
+}
+
+impl From<&String> for Param {
+    #[inline]
+    fn from(value: &String) -> Self {
+        Self::Str(value.clone())
+    }
+}
+
+impl<T: Into<Param>, const N: usize> From<[T; N]> for Param {
+    #[inline]
+    fn from(value: [T; N]) -> Self {
+        Self::List(value.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<Param>> From<Vec<T>> for Param {
+    #[inline]
+    fn from(value: Vec<T>) -> Self {
+        Self::List(value.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<char> for Param {
+    #[inline]
+    fn from(value: char) -> Self {
+        Self::Str(String::from(value))
+    }
+}
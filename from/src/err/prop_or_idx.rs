@@ -1,3 +1,5 @@
+use std::fmt;
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum PropOrIdx {
     Prop(String),
@@ -14,6 +16,16 @@ impl PropOrIdx {
     }
 }
 
+/// The name of the property or the index of the element.
+impl fmt::Display for PropOrIdx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PropOrIdx::Prop(p) => p.fmt(f),
+            PropOrIdx::Idx(i) => i.fmt(f),
+        }
+    }
+}
+
 impl From<&str> for PropOrIdx {
     #[inline]
     fn from(value: &str) -> Self {
@@ -1,8 +1,43 @@
-#[derive(PartialEq, Eq, Debug)]
+use crate::lang;
+
+/// The kind of a [`SyntaxErr`], `UnexpectedToken` carries the
+/// alternatives that were expected and what was found (e.g. `["','",
+/// "'}'"]` and `"x"`) and `Other` is the kind of the errors built with
+/// [`SyntaxErr::new`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SyntaxErrKind {
+    UnexpectedToken { expected: Vec<String>, found: String },
+    UnexpectedEnd,
+    InvalidEscape,
+    InvalidUtf8,
+    InvalidNumber,
+    Other,
+}
+
+impl SyntaxErrKind {
+    /// The stable code of the kind (e.g. `"unexpected_token"`).
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::UnexpectedToken { .. } => "unexpected_token",
+            Self::UnexpectedEnd => "unexpected_end",
+            Self::InvalidEscape => "invalid_escape",
+            Self::InvalidUtf8 => "invalid_utf8",
+            Self::InvalidNumber => "invalid_number",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// `msg` is the (English) message of the error, it is replaced by its
+/// translation by the `*_lang` methods (see [`localize`](Self::localize)).
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SyntaxErr {
     pub msg: String,
 
     pub offset: usize,
+
+    pub kind: SyntaxErrKind,
 }
 
 impl SyntaxErr {
@@ -11,24 +46,44 @@ impl SyntaxErr {
         Self {
             msg: String::from(msg),
             offset: *offset,
+            kind: SyntaxErrKind::Other,
         }
     }
 
+    #[inline]
+    pub fn with_kind(mut self, kind: SyntaxErrKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
     #[inline]
     pub fn unexpected_token(exp: &str, found: &[u8], offset: &mut usize) -> Self {
+        Self::unexpected_tokens(&[exp], found, offset)
+    }
+
+    /// Same as [`unexpected_token`](Self::unexpected_token) with the
+    /// alternatives that were expected (e.g. `&["','", "'}'"]`).
+    pub fn unexpected_tokens(exp: &[&str], found: &[u8], offset: &mut usize) -> Self {
         match core::str::from_utf8(found) {
             Ok(s) => {
+                let expected: Vec<String> = exp.iter().map(|exp| String::from(*exp)).collect();
+
                 let mut msg = String::from("expected: ");
-                msg.push_str(exp);
+                msg.push_str(&join(&expected, "en"));
                 msg.push_str(", found: ");
                 msg.push_str(s);
                 Self {
                     msg,
                     offset: *offset,
+                    kind: SyntaxErrKind::UnexpectedToken {
+                        expected,
+                        found: String::from(s),
+                    },
                 }
             }
 
-            Err(_) => Self::new("found an invalid utf8 byte", offset),
+            Err(_) => Self::new("found an invalid utf8 byte", offset)
+                .with_kind(SyntaxErrKind::InvalidUtf8),
         }
     }
 
@@ -37,6 +92,86 @@ impl SyntaxErr {
         Self {
             msg: String::from("unexpected end of json input"),
             offset: *offset,
+            kind: SyntaxErrKind::UnexpectedEnd,
+        }
+    }
+
+    /// Replaces `{expected}` and `{found}` of `template` by those of
+    /// the kind and `{offset}` by the offset of the error, unknown
+    /// placeholders are kept as they are. `{{` and `}}` are the
+    /// escaped braces. The expected alternatives are joined with "or"
+    /// (see [`render_lang`](Self::render_lang)).
+    #[inline]
+    pub fn render(&self, template: &str) -> String {
+        self.render_lang(template, "en")
+    }
+
+    /// Same as [`render`](Self::render) with the expected alternatives
+    /// joined in `lang` (e.g. "',' ou '}'" in French), English is used
+    /// for the languages without a built-in conjunction.
+    pub fn render_lang(&self, template: &str, lang: &str) -> String {
+        let mut msg = String::with_capacity(template.len());
+        let mut rest = template;
+
+        while let Some(idx) = rest.find(['{', '}']) {
+            msg.push_str(&rest[..idx]);
+
+            let tail = &rest[idx..];
+
+            if tail.starts_with("{{") || tail.starts_with("}}") {
+                msg.push_str(&tail[..1]);
+                rest = &tail[2..];
+                continue;
+            };
+
+            let end = match (tail.starts_with('{'), tail.find('}')) {
+                (true, Some(end)) => end,
+                _ => {
+                    msg.push_str(&tail[..1]);
+                    rest = &tail[1..];
+                    continue;
+                }
+            };
+
+            match (&tail[1..end], &self.kind) {
+                ("offset", _) => msg.push_str(&self.offset.to_string()),
+                ("expected", SyntaxErrKind::UnexpectedToken { expected, .. }) => {
+                    msg.push_str(&join(expected, lang))
+                }
+                ("found", SyntaxErrKind::UnexpectedToken { found, .. }) => msg.push_str(found),
+                _ => msg.push_str(&tail[..=end]),
+            };
+
+            rest = &tail[end + 1..];
+        }
+
+        msg.push_str(rest);
+
+        msg
+    }
+
+    /// Translates the message into `lang` (see [`lang`](crate::lang)),
+    /// the templates are looked up in `catalog` (e.g.
+    /// [`Messages::syntax_template`](crate::Messages::syntax_template))
+    /// then in the built-in translations (`locale-*` features) until
+    /// `dflt_lang` is reached, the message is kept if none is found.
+    pub fn localize<F>(&mut self, lang: &str, dflt_lang: &str, catalog: F)
+    where
+        F: Fn(&SyntaxErrKind, &str) -> Option<&'static str>,
+    {
+        let dflt_lang = dflt_lang.replace('_', "-").to_ascii_lowercase();
+
+        for tag in lang::fallbacks(lang) {
+            let template = catalog(&self.kind, &tag).or_else(|| translation(&self.kind, &tag));
+
+            if let Some(template) = template {
+                self.msg = self.render_lang(template, &tag);
+                return;
+            };
+
+            if tag == dflt_lang {
+                return;
+            };
         }
     }
 
@@ -47,8 +182,115 @@ impl SyntaxErr {
         json.push(',');
         json.push_str(r#""offset":"#);
         json.push_str(&self.offset.to_string());
+
+        // the errors built with 'new' are serialized as they were
+        // before the kinds were introduced
+        if self.kind != SyntaxErrKind::Other {
+            json.push_str(r#","code":"#);
+            json.push_str(&super::string_to_json(&String::from(self.kind.code())));
+        };
+
+        if let SyntaxErrKind::UnexpectedToken { expected, found } = &self.kind {
+            json.push_str(r#","params":{"expected":["#);
+
+            for (i, exp) in expected.iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                };
+
+                json.push_str(&super::string_to_json(exp));
+            }
+
+            json.push_str(r#"],"found":"#);
+            json.push_str(&super::string_to_json(found));
+            json.push('}');
+        };
+
         json.push('}');
 
         json
     }
 }
+
+// joins the expected alternatives (e.g. "a, b or c"), the conjunctions
+// are not gated by the 'locale-*' features since the catalogs can
+// translate the messages into any language
+fn join(alternatives: &[String], lang: &str) -> String {
+    let (sep, or) = match lang.split('-').next().unwrap_or(lang) {
+        "ar" => ("، ", "أو"),
+        "de" => (", ", "oder"),
+        "es" => (", ", "o"),
+        "fr" => (", ", "ou"),
+        _ => (", ", "or"),
+    };
+
+    match alternatives.split_last() {
+        Some((last, [])) => last.clone(),
+
+        Some((last, init)) => {
+            let mut joined = init.join(sep);
+            joined.push(' ');
+            joined.push_str(or);
+            joined.push(' ');
+            joined.push_str(last);
+            joined
+        }
+
+        None => String::new(),
+    }
+}
+
+// the built-in translations of the messages (one per enabled
+// 'locale-*' feature)
+#[allow(unused_imports, unused_variables)]
+fn translation(kind: &SyntaxErrKind, lang: &str) -> Option<&'static str> {
+    use SyntaxErrKind::*;
+
+    match (lang, kind) {
+        #[cfg(feature = "locale-ar")]
+        ("ar", UnexpectedToken { .. }) => Some("المتوقع: {expected}، المستلم: {found}"),
+        #[cfg(feature = "locale-ar")]
+        ("ar", UnexpectedEnd) => Some("نهاية غير متوقعة لمدخلات JSON"),
+        #[cfg(feature = "locale-ar")]
+        ("ar", InvalidEscape) => Some("تسلسل هروب غير صالح"),
+        #[cfg(feature = "locale-ar")]
+        ("ar", InvalidUtf8) => Some("بايت UTF-8 غير صالح"),
+        #[cfg(feature = "locale-ar")]
+        ("ar", InvalidNumber) => Some("رقم غير صالح"),
+
+        #[cfg(feature = "locale-de")]
+        ("de", UnexpectedToken { .. }) => Some("erwartet: {expected}, erhalten: {found}"),
+        #[cfg(feature = "locale-de")]
+        ("de", UnexpectedEnd) => Some("unerwartetes Ende der JSON-Eingabe"),
+        #[cfg(feature = "locale-de")]
+        ("de", InvalidEscape) => Some("ungültige Escape-Sequenz"),
+        #[cfg(feature = "locale-de")]
+        ("de", InvalidUtf8) => Some("ungültiges UTF-8-Byte"),
+        #[cfg(feature = "locale-de")]
+        ("de", InvalidNumber) => Some("ungültige Zahl"),
+
+        #[cfg(feature = "locale-es")]
+        ("es", UnexpectedToken { .. }) => Some("se esperaba: {expected}, se recibió: {found}"),
+        #[cfg(feature = "locale-es")]
+        ("es", UnexpectedEnd) => Some("fin inesperado de la entrada JSON"),
+        #[cfg(feature = "locale-es")]
+        ("es", InvalidEscape) => Some("secuencia de escape no válida"),
+        #[cfg(feature = "locale-es")]
+        ("es", InvalidUtf8) => Some("byte UTF-8 no válido"),
+        #[cfg(feature = "locale-es")]
+        ("es", InvalidNumber) => Some("número no válido"),
+
+        #[cfg(feature = "locale-fr")]
+        ("fr", UnexpectedToken { .. }) => Some("attendu : {expected}, reçu : {found}"),
+        #[cfg(feature = "locale-fr")]
+        ("fr", UnexpectedEnd) => Some("fin inattendue de l'entrée JSON"),
+        #[cfg(feature = "locale-fr")]
+        ("fr", InvalidEscape) => Some("séquence d'échappement invalide"),
+        #[cfg(feature = "locale-fr")]
+        ("fr", InvalidUtf8) => Some("octet UTF-8 invalide"),
+        #[cfg(feature = "locale-fr")]
+        ("fr", InvalidNumber) => Some("nombre invalide"),
+
+        _ => None,
+    }
+}
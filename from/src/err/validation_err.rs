@@ -1,10 +1,16 @@
-use super::{Path, PropOrIdx};
+use super::{ErrKind, Param, Path, PropOrIdx};
 
+/// `kind` and `params` are the machine-readable details of the error
+/// (e.g. `MaxLen` with `{"max": 20, "actual": 27}`), they are set by
+/// the built-in constraints and optionally by custom validators (see
+/// [`with_code`](Self::with_code) and [`with_param`](Self::with_param)).
 #[derive(Clone, Debug, PartialEq)]
 pub struct ValidationErr {
     pub target: PropOrIdx,
     pub path: Path,
     pub msg: String,
+    pub kind: ErrKind,
+    pub params: Vec<(&'static str, Param)>,
 }
 
 impl ValidationErr {
@@ -14,9 +20,85 @@ impl ValidationErr {
             target,
             path,
             msg: String::from(msg),
+            kind: ErrKind::Custom(None),
+            params: Vec::new(),
         }
     }
 
+    /// Sets the code of a custom error (i.e. `ErrKind::Custom(Some(code))`).
+    #[inline]
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.kind = ErrKind::Custom(Some(code));
+        self
+    }
+
+    #[inline]
+    pub fn with_kind(mut self, kind: ErrKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    #[inline]
+    pub fn with_param<P: Into<Param>>(mut self, name: &'static str, value: P) -> Self {
+        self.params.push((name, value.into()));
+        self
+    }
+
+    /// Returns the value of the parameter `name` if found.
+    pub fn param(&self, name: &str) -> Option<&Param> {
+        self.params
+            .iter()
+            .find(|(param_name, _)| *param_name == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Replaces the placeholders of `template` by the params of the
+    /// error (e.g. `{max}`) and `{field}` by its target, unknown
+    /// placeholders are kept as they are. `{{` and `}}` are the
+    /// escaped braces.
+    pub fn render(&self, template: &str) -> String {
+        let mut msg = String::with_capacity(template.len());
+        let mut rest = template;
+
+        while let Some(idx) = rest.find(['{', '}']) {
+            msg.push_str(&rest[..idx]);
+
+            let tail = &rest[idx..];
+
+            if tail.starts_with("{{") || tail.starts_with("}}") {
+                msg.push_str(&tail[..1]);
+                rest = &tail[2..];
+                continue;
+            };
+
+            let end = match (tail.starts_with('{'), tail.find('}')) {
+                (true, Some(end)) => end,
+                _ => {
+                    msg.push_str(&tail[..1]);
+                    rest = &tail[1..];
+                    continue;
+                }
+            };
+
+            let name = &tail[1..end];
+
+            match name {
+                "field" => msg.push_str(&self.target.to_string()),
+
+                _ => match self.param(name) {
+                    Some(param) => msg.push_str(&param.to_string()),
+                    None => msg.push_str(&tail[..=end]),
+                },
+            };
+
+            rest = &tail[end + 1..];
+        }
+
+        msg.push_str(rest);
+
+        msg
+    }
+
     #[inline]
     pub fn to_json(&self) -> String {
         let mut json = String::from(r#"{"target":"#);
@@ -27,6 +109,30 @@ impl ValidationErr {
         json.push(',');
         json.push_str(r#""msg":"#);
         json.push_str(&super::string_to_json(&self.msg));
+
+        // the errors of the custom validators without a code are
+        // serialized as they were before the kinds were introduced
+        if self.kind != ErrKind::Custom(None) {
+            json.push_str(r#","code":"#);
+            json.push_str(&super::string_to_json(&String::from(self.kind.code())));
+        };
+
+        if !self.params.is_empty() {
+            json.push_str(r#","params":{"#);
+
+            for (i, (name, value)) in self.params.iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                };
+
+                json.push_str(&super::string_to_json(&String::from(*name)));
+                json.push(':');
+                json.push_str(&value.to_json());
+            }
+
+            json.push('}');
+        };
+
         json.push('}');
 
         json
@@ -0,0 +1,72 @@
+//! Validation groups, selected at parse time through [`Opts`](crate::Opts).
+//!
+//! Attributes tagged with `groups(...)` are only checked when at least one of
+//! their groups is active, while untagged attributes are always checked.
+//!
+//! The active groups are held by the current thread for the duration of
+//! the `*_with` call (e.g. [`FromJson::from_json_with`](crate::FromJson::from_json_with)
+//! or [`Validate::validate_with`](crate::Validate::validate_with)), so the
+//! nested structs and vectors parsed or validated during the call see
+//! them, while the calls made on other threads (e.g. a custom
+//! `FromJsonValue` that spawns one) and the calls made outside of it do
+//! not. [`with_active`] selects the groups for any other code.
+
+use std::cell::RefCell;
+
+thread_local! {
+    static ACTIVE: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Returns `true` if any of `groups` is currently active.
+pub fn is_active(groups: &[&str]) -> bool {
+    ACTIVE.with(|active| {
+        let active = active.borrow();
+        groups.iter().any(|group| active.iter().any(|a| a == group))
+    })
+}
+
+/// Runs `f` with `groups` as the active groups, the previously active ones
+/// are restored afterwards (even if `f` panics).
+pub fn with_active<R, F: FnOnce() -> R>(groups: &[&str], f: F) -> R {
+    let prev = ACTIVE.with(|active| {
+        active.replace(groups.iter().map(|group| String::from(*group)).collect())
+    });
+
+    let _restore = Restore(prev);
+
+    f()
+}
+
+struct Restore(Vec<String>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let prev = std::mem::take(&mut self.0);
+        ACTIVE.with(|active| *active.borrow_mut() = prev);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn active_groups() {
+        assert!(!is_active(&["create"]));
+
+        with_active(&["create", "admin"], || {
+            assert!(is_active(&["create"]));
+            assert!(is_active(&["update", "admin"]));
+            assert!(!is_active(&["update"]));
+
+            with_active(&["update"], || {
+                assert!(!is_active(&["create"]));
+                assert!(is_active(&["update"]));
+            });
+
+            assert!(is_active(&["create"]));
+        });
+
+        assert!(!is_active(&["create"]));
+    }
+}
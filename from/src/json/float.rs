@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
 use super::{utils, SyntaxErr};
+use crate::SyntaxErrKind;
 
 #[derive(Debug, PartialEq)]
 pub enum ParseResult<T: FromStr> {
@@ -12,8 +13,8 @@ pub enum ParseResult<T: FromStr> {
 
 impl<T: FromStr> ParseResult<T> {
     #[inline]
-    fn syntax_err(msg: &str, idx: &mut usize) -> Self {
-        Self::SyntaxErr(SyntaxErr::new(msg, idx))
+    fn syntax_err(kind: SyntaxErrKind, msg: &str, idx: &mut usize) -> Self {
+        Self::SyntaxErr(SyntaxErr::new(msg, idx).with_kind(kind))
     }
 
     #[inline]
@@ -21,6 +22,11 @@ impl<T: FromStr> ParseResult<T> {
         Self::SyntaxErr(SyntaxErr::unexpected_token(exp, &[found], idx))
     }
 
+    #[inline]
+    fn unexpected_tokens(exp: &[&str], found: u8, idx: &mut usize) -> Self {
+        Self::SyntaxErr(SyntaxErr::unexpected_tokens(exp, &[found], idx))
+    }
+
     #[inline]
     fn type_mismatch(found: &str) -> Self {
         ParseResult::TypeMismatch(String::from(found))
@@ -32,7 +38,11 @@ fn parse_float<T: FromStr>(bytes: &[u8], mut idx: usize) -> ParseResult<T> {
     unsafe {
         match ::core::str::from_utf8_unchecked(bytes).parse() {
             Ok(f) => ParseResult::Ok(f),
-            Err(_) => ParseResult::syntax_err("invalid float literal", &mut idx),
+            Err(_) => ParseResult::syntax_err(
+                SyntaxErrKind::InvalidNumber,
+                "invalid float literal",
+                &mut idx,
+            ),
         }
     }
 }
@@ -181,7 +191,7 @@ pub mod f32 {
                         }
 
                         _ => {
-                            return ParseResult::unexpected_token("[digit, -, +]", byte, idx);
+                            return ParseResult::unexpected_tokens(&["digit", "-", "+"], byte, idx);
                         }
                     };
 
@@ -357,7 +367,7 @@ pub mod f32 {
 
             assert_eq!(
                 super::parse("1ee".as_bytes(), &mut 0),
-                ParseResult::unexpected_token("[digit, -, +]", b'e', &mut 2)
+                ParseResult::unexpected_tokens(&["digit", "-", "+"], b'e', &mut 2)
             );
         }
     }
@@ -507,7 +517,7 @@ pub mod f64 {
                         }
 
                         _ => {
-                            return ParseResult::unexpected_token("[digit, -, +]", byte, idx);
+                            return ParseResult::unexpected_tokens(&["digit", "-", "+"], byte, idx);
                         }
                     };
 
@@ -683,7 +693,7 @@ pub mod f64 {
 
             assert_eq!(
                 super::parse("1ee".as_bytes(), &mut 0),
-                ParseResult::unexpected_token("[digit, -, +]", b'e', &mut 2)
+                ParseResult::unexpected_tokens(&["digit", "-", "+"], b'e', &mut 2)
             );
         }
     }
@@ -1,4 +1,6 @@
-use super::{Err, Errs, Path};
+use super::{Err, Errs, Path, SyntaxErr};
+use crate::{ctx, groups, Opts};
+use std::any::Any;
 pub trait FromJson: FromJsonValue {
     fn from_json(json: &[u8]) -> Result<Self, Err> {
         let mut idx = 0usize;
@@ -9,7 +11,12 @@ pub trait FromJson: FromJsonValue {
     fn from_json_lang(json: &[u8], lang: &str) -> Result<Self, Err> {
         let mut idx = 0usize;
         let path = crate::json::Path::new();
-        Self::from_json_value_lang(json, &mut idx, &path, lang)
+        Self::from_json_value_lang(json, &mut idx, &path, lang).map_err(|mut err| {
+            if let Err::SyntaxErr(err) = &mut err {
+                Self::syntax_err_lang(err, lang);
+            };
+            err
+        })
     }
 
     fn from_json_stack_errs(json: &[u8]) -> Result<Self, Errs> {
@@ -20,10 +27,74 @@ pub trait FromJson: FromJsonValue {
     fn from_json_stack_errs_lang(json: &[u8], lang: &str) -> Result<Self, Errs> {
         let mut idx = 0usize;
         let path = crate::json::Path::new();
-        Self::from_json_value_stack_errs_lang(json, &mut idx, &path, lang)
+        Self::from_json_value_stack_errs_lang(json, &mut idx, &path, lang).map_err(|mut errs| {
+            if let Errs::SyntaxErr(err) = &mut errs {
+                Self::syntax_err_lang(err, lang);
+            };
+            errs
+        })
+    }
+
+    // translates the syntax errors of the '*_lang' methods, overridden
+    // by the derive with the 'dflt_lang' and the catalog of the struct
+
+    fn syntax_err_lang(err: &mut SyntaxErr, lang: &str) {
+        err.localize(lang, "en", |_, _| None);
+    }
+
+    // the languages in order of preference (see 'lang')
+
+    fn from_json_langs(json: &[u8], langs: &[&str]) -> Result<Self, Err> {
+        Self::from_json_lang(json, &langs.join(","))
+    }
+
+    fn from_json_stack_errs_langs(json: &[u8], langs: &[&str]) -> Result<Self, Errs> {
+        Self::from_json_stack_errs_lang(json, &langs.join(","))
+    }
+
+    fn from_json_with(json: &[u8], opts: &Opts) -> Result<Self, Err> {
+        groups::with_active(opts.groups, || match opts.lang {
+            Some(lang) => Self::from_json_lang(json, lang),
+            None => Self::from_json(json),
+        })
+    }
+
+    fn from_json_stack_errs_with(json: &[u8], opts: &Opts) -> Result<Self, Errs> {
+        groups::with_active(opts.groups, || match opts.lang {
+            Some(lang) => Self::from_json_stack_errs_lang(json, lang),
+            None => Self::from_json_stack_errs(json),
+        })
+    }
+
+    // the context is read by the 'ctx_validators' (see 'ValidatorCtx'),
+    // it is held by the current thread during the call and must be
+    // 'static (see 'ctx::with_ctx')
+
+    fn from_json_ctx<C: Any>(json: &[u8], ctx: &C) -> Result<Self, Err> {
+        ctx::with_ctx(ctx, || Self::from_json(json))
+    }
+
+    fn from_json_lang_ctx<C: Any>(json: &[u8], lang: &str, ctx: &C) -> Result<Self, Err> {
+        ctx::with_ctx(ctx, || Self::from_json_lang(json, lang))
+    }
+
+    fn from_json_stack_errs_ctx<C: Any>(json: &[u8], ctx: &C) -> Result<Self, Errs> {
+        ctx::with_ctx(ctx, || Self::from_json_stack_errs(json))
+    }
+
+    fn from_json_stack_errs_lang_ctx<C: Any>(
+        json: &[u8],
+        lang: &str,
+        ctx: &C,
+    ) -> Result<Self, Errs> {
+        ctx::with_ctx(ctx, || Self::from_json_stack_errs_lang(json, lang))
     }
 }
 
+/// Parses a value starting at `idx` (leading whitespaces allowed) and
+/// leaves `idx` right after it, e.g. after the closing `}` of an object
+/// (0.1.4 and earlier left it on the `}`). The hand-written impls must do
+/// the same since the callers continue from `idx`.
 pub trait FromJsonValue: Sized {
     fn from_json_value(json: &[u8], idx: &mut usize, path: &Path) -> Result<Self, Err>;
 
@@ -3,6 +3,7 @@ use std::ops::RangeInclusive;
 use crate::json::utils::get_or_unexpected_end;
 
 use super::{utils, SyntaxErr};
+use crate::SyntaxErrKind;
 
 pub const DIGITS: RangeInclusive<u8> = b'0'..=b'9';
 
@@ -18,8 +19,8 @@ pub enum ParseResult<T> {
 
 impl<T> ParseResult<T> {
     #[inline]
-    fn syntax_err(msg: &str, idx: &mut usize) -> Self {
-        Self::SyntaxErr(SyntaxErr::new(msg, idx))
+    fn syntax_err(kind: SyntaxErrKind, msg: &str, idx: &mut usize) -> Self {
+        Self::SyntaxErr(SyntaxErr::new(msg, idx).with_kind(kind))
     }
 
     #[inline]
@@ -155,7 +156,7 @@ macro_rules! define_signed_parser_and_test {
         pub mod $typ {
             use super::{
                 skip_and_return_mismatch_ty, skip_and_return_too_large_err,
-                skip_and_return_too_small_err, utils, DIGITS,
+                skip_and_return_too_small_err, utils, SyntaxErrKind, DIGITS,
             };
 
             pub type ParseResult = super::ParseResult<$typ>;
@@ -259,7 +260,11 @@ macro_rules! define_signed_parser_and_test {
                             ParseResult::type_mismatch("array")
                         }
                     }
-                    _ => ParseResult::syntax_err("invalid digit", idx),
+                    _ => ParseResult::syntax_err(
+                        SyntaxErrKind::InvalidNumber,
+                        "invalid digit",
+                        idx,
+                    ),
                 }
             }
 
@@ -317,7 +322,13 @@ macro_rules! define_signed_parser_and_test {
                             return skip_and_return_mismatch_ty(json, idx);
                         }
 
-                        _ => return ParseResult::syntax_err("invalid digit", idx),
+                        _ => {
+                            return ParseResult::syntax_err(
+                                SyntaxErrKind::InvalidNumber,
+                                "invalid digit",
+                                idx,
+                            );
+                        }
                     };
                 });
 
@@ -363,7 +374,13 @@ macro_rules! define_signed_parser_and_test {
 
                     b'.' => {}
 
-                    _ => return ParseResult::syntax_err("invalid digit", idx),
+                    _ => {
+                        return ParseResult::syntax_err(
+                            SyntaxErrKind::InvalidNumber,
+                            "invalid digit",
+                            idx,
+                        );
+                    }
                 };
 
                 //
@@ -394,7 +411,13 @@ macro_rules! define_signed_parser_and_test {
                         return skip_and_return_mismatch_ty(json, idx);
                     }
 
-                    _ => return ParseResult::syntax_err("invalid digit", idx),
+                    _ => {
+                        return ParseResult::syntax_err(
+                            SyntaxErrKind::InvalidNumber,
+                            "invalid digit",
+                            idx,
+                        );
+                    }
                 }
             }
 
@@ -582,7 +605,11 @@ macro_rules! define_signed_parser_and_test {
                 fn syntax_err() {
                     assert_eq!(
                         super::parse("12.8.".as_bytes(), &mut 0),
-                        super::ParseResult::syntax_err("expected: digit, found: .", &mut 4),
+                        super::ParseResult::SyntaxErr(crate::SyntaxErr::unexpected_token(
+                            "digit",
+                            b".",
+                            &mut 4
+                        )),
                     )
                 }
             }
@@ -691,7 +718,7 @@ macro_rules! define_unsigned_parser_and_test {
         pub mod $typ {
             use super::{
                 skip_and_return_mismatch_ty, skip_and_return_too_large_err,
-                skip_and_return_too_small_err, utils, DIGITS,
+                skip_and_return_too_small_err, utils, SyntaxErrKind, DIGITS,
             };
 
             pub type ParseResult = super::ParseResult<$typ>;
@@ -796,7 +823,11 @@ macro_rules! define_unsigned_parser_and_test {
                             ParseResult::type_mismatch("array")
                         }
                     }
-                    _ => ParseResult::syntax_err("invalid digit", idx),
+                    _ => ParseResult::syntax_err(
+                        SyntaxErrKind::InvalidNumber,
+                        "invalid digit",
+                        idx,
+                    ),
                 }
             }
 
@@ -1078,7 +1109,11 @@ macro_rules! define_unsigned_parser_and_test {
                 fn syntax_err() {
                     assert_eq!(
                         super::parse("12.8.".as_bytes(), &mut 0),
-                        super::ParseResult::syntax_err("expected: digit, found: .", &mut 4),
+                        super::ParseResult::SyntaxErr(crate::SyntaxErr::unexpected_token(
+                            "digit",
+                            b".",
+                            &mut 4
+                        )),
                     )
                 }
             }
@@ -17,8 +17,8 @@ pub fn parse<'a>(json: &'a [u8], idx: &mut usize) -> Result<&'a [u8], SyntaxErr>
         b'a'..=b'z' | b'A'..=b'Z' | b'_' => {}
 
         _ => {
-            return Err(SyntaxErr::unexpected_token(
-                "alphabet character or underscore",
+            return Err(SyntaxErr::unexpected_tokens(
+                &["alphabet character", "underscore"],
                 &[byte],
                 idx,
             ));
@@ -39,8 +39,8 @@ pub fn parse<'a>(json: &'a [u8], idx: &mut usize) -> Result<&'a [u8], SyntaxErr>
             }
 
             _ => {
-                return Err(SyntaxErr::unexpected_token(
-                    "alphabet character, underscore or digit",
+                return Err(SyntaxErr::unexpected_tokens(
+                    &["alphabet character", "underscore", "digit"],
                     &[byte],
                     idx,
                 ));
@@ -79,13 +79,14 @@ mod test {
     fn syntax_err() {
         assert_eq!(
             parse(r#"as"#.as_bytes(), &mut 0),
-            Err(SyntaxErr::new("expected: \", found: a", &mut 0)),
+            Err(SyntaxErr::unexpected_token("\"", &[b'a'], &mut 0)),
         );
 
         assert_eq!(
             parse(r#""17asf""#.as_bytes(), &mut 0),
-            Err(SyntaxErr::new(
-                "expected: alphabet character or underscore, found: 1",
+            Err(SyntaxErr::unexpected_tokens(
+                &["alphabet character", "underscore"],
+                &[b'1'],
                 &mut 1
             )),
         );
@@ -97,8 +98,8 @@ mod test {
 
         assert_eq!(
             parse(r#""abcd#""#.as_bytes(), &mut 0),
-            Err(SyntaxErr::unexpected_token(
-                "alphabet character, underscore or digit",
+            Err(SyntaxErr::unexpected_tokens(
+                &["alphabet character", "underscore", "digit"],
                 &[b'#'],
                 &mut 5
             )),
@@ -1,6 +1,7 @@
 use std::ops::Range;
 
 use super::{utils, SyntaxErr};
+use crate::SyntaxErrKind;
 
 #[derive(Debug, PartialEq)]
 pub enum ParseResult {
@@ -12,8 +13,8 @@ pub enum ParseResult {
 
 impl ParseResult {
     #[inline]
-    fn syntax_err(msg: &str, idx: &mut usize) -> Self {
-        Self::SyntaxErr(SyntaxErr::new(msg, idx))
+    fn syntax_err(kind: SyntaxErrKind, msg: &str, idx: &mut usize) -> Self {
+        Self::SyntaxErr(SyntaxErr::new(msg, idx).with_kind(kind))
     }
 
     #[inline]
@@ -181,7 +182,13 @@ pub fn parse(json: &[u8], idx: &mut usize) -> ParseResult {
 
                             b'a'..=b'f' => hex = (byte - 87) as u16,
 
-                            _ => return ParseResult::syntax_err("invalid hex digit", idx),
+                            _ => {
+                                return ParseResult::syntax_err(
+                                    SyntaxErrKind::InvalidEscape,
+                                    "invalid hex digit",
+                                    idx,
+                                )
+                            }
                         };
 
                         for _ in 0..3 {
@@ -195,7 +202,13 @@ pub fn parse(json: &[u8], idx: &mut usize) -> ParseResult {
 
                                 b'a'..=b'f' => hex += (byte - 87) as u16,
 
-                                _ => return ParseResult::syntax_err("invalid hex digit", idx),
+                                _ => {
+                                    return ParseResult::syntax_err(
+                                        SyntaxErrKind::InvalidEscape,
+                                        "invalid hex digit",
+                                        idx,
+                                    )
+                                }
                             };
                         }
 
@@ -228,7 +241,13 @@ pub fn parse(json: &[u8], idx: &mut usize) -> ParseResult {
                         ]);
                     }
 
-                    _ => return ParseResult::syntax_err("invalid control character", idx),
+                    _ => {
+                        return ParseResult::syntax_err(
+                            SyntaxErrKind::InvalidEscape,
+                            "invalid control character",
+                            idx,
+                        )
+                    }
                 }
             }
 
@@ -246,7 +265,11 @@ pub fn parse(json: &[u8], idx: &mut usize) -> ParseResult {
                     get_next_byte!();
 
                     if !MINION_BYTE.contains(&byte) {
-                        return ParseResult::syntax_err("invalid UTF-8 byte", idx);
+                        return ParseResult::syntax_err(
+                            SyntaxErrKind::InvalidUtf8,
+                            "invalid UTF-8 byte",
+                            idx,
+                        );
                     };
                     continue;
                 };
@@ -262,7 +285,11 @@ pub fn parse(json: &[u8], idx: &mut usize) -> ParseResult {
                         get_next_byte!();
 
                         if !MINION_BYTE.contains(&byte) {
-                            return ParseResult::syntax_err("invalid UTF-8 byte", idx);
+                            return ParseResult::syntax_err(
+                                SyntaxErrKind::InvalidUtf8,
+                                "invalid UTF-8 byte",
+                                idx,
+                            );
                         };
                     }
 
@@ -279,7 +306,11 @@ pub fn parse(json: &[u8], idx: &mut usize) -> ParseResult {
                         get_next_byte!();
 
                         if !MINION_BYTE.contains(&byte) {
-                            return ParseResult::syntax_err("invalid UTF-8 byte", idx);
+                            return ParseResult::syntax_err(
+                                SyntaxErrKind::InvalidUtf8,
+                                "invalid UTF-8 byte",
+                                idx,
+                            );
                         };
                     }
 
@@ -289,7 +320,11 @@ pub fn parse(json: &[u8], idx: &mut usize) -> ParseResult {
                 //
                 //
                 // invalid must not be outside the previous ranges
-                return ParseResult::syntax_err("invalid UTF-8 byte", idx);
+                return ParseResult::syntax_err(
+                    SyntaxErrKind::InvalidUtf8,
+                    "invalid UTF-8 byte",
+                    idx,
+                );
             }
         }
     }
@@ -365,12 +400,85 @@ pub fn sanitize_xss(s: &mut String) {
     *s = unsafe { String::from_utf8_unchecked(es) }
 }
 
+/// Replaces every run of whitespace with a single space and
+/// removes the leading and trailing whitespace.
+pub fn collapse_whitespace(s: &mut String) {
+    let mut collapsed = String::with_capacity(s.len());
+
+    for word in s.split_whitespace() {
+        if !collapsed.is_empty() {
+            collapsed.push(' ');
+        };
+
+        collapsed.push_str(word);
+    }
+
+    *s = collapsed;
+}
+
+/// Removes the control characters (`char::is_control`),
+/// including tabs and line breaks.
+#[inline]
+pub fn strip_control_chars(s: &mut String) {
+    s.retain(|c| !c.is_control());
+}
+
+#[cfg(feature = "normalize")]
+macro_rules! normalize {
+    ($name:ident, $quick_check:ident, $form:ident, $doc:literal) => {
+        #[doc = $doc]
+        pub fn $name(s: &mut String) {
+            use unicode_normalization::{IsNormalized, UnicodeNormalization};
+
+            if unicode_normalization::$quick_check(s.chars()) == IsNormalized::Yes {
+                return;
+            };
+
+            *s = s.$form().collect();
+        }
+    };
+}
+
+#[cfg(feature = "normalize")]
+normalize!(
+    normalize_nfc,
+    is_nfc_quick,
+    nfc,
+    "Converts the string to Unicode Normalization Form C."
+);
+
+#[cfg(feature = "normalize")]
+normalize!(
+    normalize_nfd,
+    is_nfd_quick,
+    nfd,
+    "Converts the string to Unicode Normalization Form D."
+);
+
+#[cfg(feature = "normalize")]
+normalize!(
+    normalize_nfkc,
+    is_nfkc_quick,
+    nfkc,
+    "Converts the string to Unicode Normalization Form KC."
+);
+
+#[cfg(feature = "normalize")]
+normalize!(
+    normalize_nfkd,
+    is_nfkd_quick,
+    nfkd,
+    "Converts the string to Unicode Normalization Form KD."
+);
+
 #[cfg(test)]
 mod test {
 
     use crate::json::SyntaxErr;
 
-    use super::{parse, sanitize_xss, ParseResult};
+    use super::{
+        collapse_whitespace, parse, sanitize_xss, strip_control_chars, ParseResult, SyntaxErrKind,
+    };
 
     #[test]
     fn valid() {
@@ -463,25 +571,25 @@ mod test {
         // minion byte must be greater than 127 not 17
         assert_eq!(
             parse(&[b'"', 192, 17, b'"'], &mut 0),
-            ParseResult::syntax_err("invalid UTF-8 byte", &mut 2)
+            ParseResult::syntax_err(SyntaxErrKind::InvalidUtf8, "invalid UTF-8 byte", &mut 2)
         );
 
         // leading byte must be covered by one of valid ranges
         assert_eq!(
             parse(&[b'"', 182, b'"'], &mut 0),
-            ParseResult::syntax_err("invalid UTF-8 byte", &mut 1)
+            ParseResult::syntax_err(SyntaxErrKind::InvalidUtf8, "invalid UTF-8 byte", &mut 1)
         );
 
         // must have extra byte before "
         assert_eq!(
             parse(&[b'"', 192, b'"'], &mut 0),
-            ParseResult::syntax_err("invalid UTF-8 byte", &mut 2)
+            ParseResult::syntax_err(SyntaxErrKind::InvalidUtf8, "invalid UTF-8 byte", &mut 2)
         );
 
         // must have extra byte before "
         assert_eq!(
             parse(&[b'"', 244, 128, b'"'], &mut 0),
-            ParseResult::syntax_err("invalid UTF-8 byte", &mut 3)
+            ParseResult::syntax_err(SyntaxErrKind::InvalidUtf8, "invalid UTF-8 byte", &mut 3)
         );
     }
 
@@ -489,7 +597,11 @@ mod test {
     fn invalid_control_character() {
         assert_eq!(
             parse(r#""\h""#.as_bytes(), &mut 0),
-            ParseResult::syntax_err("invalid control character", &mut 2)
+            ParseResult::syntax_err(
+                SyntaxErrKind::InvalidEscape,
+                "invalid control character",
+                &mut 2
+            )
         );
     }
 
@@ -502,4 +614,30 @@ mod test {
             String::from("&lt;h1&gt;hello &amp; nice &#34; &#39; \u{FFFD}&lt;/h1&gt;")
         );
     }
+
+    #[test]
+    fn whitespace_collapse() {
+        let mut s = String::from("  hello \t\n  world\u{3000}! ");
+        collapse_whitespace(&mut s);
+        assert_eq!(s, String::from("hello world !"));
+    }
+
+    #[test]
+    fn control_chars_strip() {
+        let mut s = String::from("he\u{0}llo\u{7}\r\n wor\u{9f}ld");
+        strip_control_chars(&mut s);
+        assert_eq!(s, String::from("hello world"));
+    }
+
+    #[cfg(feature = "normalize")]
+    #[test]
+    fn unicode_normalize() {
+        let mut s = String::from("e\u{301}");
+        super::normalize_nfc(&mut s);
+        assert_eq!(s, String::from("\u{e9}"));
+
+        let mut s = String::from("\u{fb01}le");
+        super::normalize_nfkc(&mut s);
+        assert_eq!(s, String::from("file"));
+    }
 }
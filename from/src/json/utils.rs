@@ -75,8 +75,8 @@ pub fn skip_value(json: &[u8], idx: &mut usize) -> Result<(), SyntaxErr> {
         b'n' => skip_null(json, idx, "null"),
         b't' => skip_true(json, idx, "true"),
         b'f' => skip_false(json, idx, "false"),
-        _ => Err(SyntaxErr::unexpected_token(
-            "['\"', '{', '[', '0..9']",
+        _ => Err(SyntaxErr::unexpected_tokens(
+            &["'\"'", "'{'", "'['", "'0..9'"],
             &[byte],
             idx,
         )),
@@ -0,0 +1,108 @@
+//! Language negotiation of the error messages.
+//!
+//! The `lang` of the `*_lang` methods is a language tag (BCP 47,
+//! e.g. `"ar-EG"`) or a comma separated list of them in order of
+//! preference (e.g. `"fr-CA,ar"`). The message is looked up for
+//! each tag from the most to the least specific (`"fr-CA"`, `"fr"`,
+//! `"ar"`) before falling back to the default language
+//! (`dflt_lang`). The custom validators receive `lang` as it is and
+//! pick the language of their messages with [`select`].
+
+/// Returns the tags to look up for `lang` in order (lowercased),
+/// each preference is followed by its prefixes (e.g. `"zh-hant-tw"`,
+/// `"zh-hant"`, `"zh"`).
+pub fn fallbacks(lang: &str) -> Vec<String> {
+    let mut tags = Vec::<String>::new();
+
+    for pref in lang.split(',') {
+        let mut tag = pref.trim().replace('_', "-").to_ascii_lowercase();
+
+        while !tag.is_empty() && tag != "*" {
+            if !tags.contains(&tag) {
+                tags.push(tag.clone());
+            };
+
+            // the singletons (e.g. 'x' of 'en-x-custom') are not
+            // tags of their own
+            let mut end = tag.rfind('-').unwrap_or(0);
+            if end >= 2 && tag.as_bytes()[end - 2] == b'-' {
+                end -= 2;
+            };
+
+            tag.truncate(end);
+        }
+    }
+
+    tags
+}
+
+/// Returns the first message found by `msg` for the [`fallbacks`]
+/// of `lang`.
+#[inline]
+pub fn negotiate<F>(lang: &str, mut msg: F) -> Option<String>
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    fallbacks(lang).iter().find_map(|tag| msg(tag))
+}
+
+/// Returns the first of the `supported` languages matched by the
+/// [`fallbacks`] of `lang` (case-insensitively), used by the custom
+/// validators to pick the language of their messages since their
+/// `lang` is the one passed to the `*_lang` methods as it is (e.g.
+/// `"ar-EG"` or `"fr,ar"`).
+///
+/// ```
+/// assert_eq!(from::lang::select("fr-CA,ar-EG", &["en", "ar"]), Some("ar"));
+/// assert_eq!(from::lang::select("fr", &["en", "ar"]), None);
+/// ```
+pub fn select<'a>(lang: &str, supported: &[&'a str]) -> Option<&'a str> {
+    fallbacks(lang).iter().find_map(|tag| {
+        supported
+            .iter()
+            .find(|supported| supported.replace('_', "-").eq_ignore_ascii_case(tag))
+            .copied()
+    })
+}
+
+/// Parses the value of an `Accept-Language` header into the list of
+/// the languages in order of preference (by their `q` values), the
+/// wildcard and the refused languages (`q=0`) are skipped.
+///
+/// ```
+/// assert_eq!(
+///     from::lang::accept_language("fr-CH, fr;q=0.9, en;q=0.8, de;q=0, *;q=0.5"),
+///     vec!["fr-CH", "fr", "en"],
+/// );
+/// ```
+pub fn accept_language(header: &str) -> Vec<&str> {
+    let mut langs = Vec::<(&str, f32)>::new();
+
+    for range in header.split(',') {
+        let mut parts = range.split(';');
+
+        let tag = parts.next().unwrap_or_default().trim();
+
+        let q = parts
+            .find_map(|param| {
+                let (name, value) = param.split_once('=')?;
+
+                match name.trim() {
+                    "q" => Some(value.trim().parse::<f32>().unwrap_or(0.0)),
+                    _ => None,
+                }
+            })
+            .unwrap_or(1.0);
+
+        if tag.is_empty() || tag == "*" || !(q > 0.0 && q <= 1.0) {
+            continue;
+        };
+
+        langs.push((tag, q));
+    }
+
+    // stable, so the equally preferred keep their order
+    langs.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    langs.into_iter().map(|(tag, _)| tag).collect()
+}
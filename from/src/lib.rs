@@ -13,7 +13,27 @@ pub use option_null::OptionNull;
 
 pub use from_proc::from;
 
+pub mod formats;
+
+#[cfg(feature = "regex")]
+pub mod pattern;
+
 pub mod utils;
 
+pub mod groups;
+
+pub mod ctx;
+
+mod opts;
+pub use opts::Opts;
+
 mod validator;
-pub use validator::Validator;
+pub use validator::{ParamValidator, Validator, ValidatorCtx};
+
+mod validate;
+pub use validate::Validate;
+
+mod messages;
+pub use messages::Messages;
+
+pub mod lang;
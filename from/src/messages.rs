@@ -0,0 +1,25 @@
+use crate::{ErrKind, SyntaxErrKind};
+
+/// A catalog of the default messages, selected with
+/// `#[from(json, messages = MyCatalog)]`.
+///
+/// The templates are rendered by [`ValidationErr::render`](crate::ValidationErr::render)
+/// (e.g. `"{field} must be at most {max} characters"`), the custom
+/// messages of the fields (`msgs{..}`) take precedence, and the
+/// built-in (English) message is used when `None` is returned.
+pub trait Messages {
+    /// Returns the template of the message of `kind` in `lang`, a
+    /// lowercased language tag (e.g. `"ar-eg"` then `"ar"`, see
+    /// [`lang`](crate::lang)). The default language (`dflt_lang`) is
+    /// requested by the methods that do not take a language.
+    fn template(kind: ErrKind, lang: &str) -> Option<&'static str>;
+
+    /// Returns the template of the message of a syntax error of `kind`
+    /// in `lang`, rendered by [`SyntaxErr::render`](crate::SyntaxErr::render)
+    /// (e.g. `"expected {expected} at {offset}"`). Only the `*_lang`
+    /// methods translate the syntax errors.
+    #[allow(unused_variables)]
+    fn syntax_template(kind: &SyntaxErrKind, lang: &str) -> Option<&'static str> {
+        None
+    }
+}
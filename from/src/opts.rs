@@ -0,0 +1,12 @@
+/// Options for [`FromJson::from_json_with`](crate::FromJson::from_json_with),
+/// [`FromJson::from_json_stack_errs_with`](crate::FromJson::from_json_stack_errs_with)
+/// and the `*_with` methods of [`Validate`](crate::Validate).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Opts<'a> {
+    /// The language of the error messages (the default one if `None`),
+    /// or the languages in order of preference (see [`lang`](crate::lang)).
+    pub lang: Option<&'a str>,
+
+    /// The active validation groups (see [`groups`](crate::groups)).
+    pub groups: &'a [&'a str],
+}
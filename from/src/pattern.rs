@@ -0,0 +1,79 @@
+//! Lazily compiled regular expressions used by the `pattern`
+//! attribute (requires the `regex` feature).
+
+use std::{cell::UnsafeCell, mem::MaybeUninit, sync::Once};
+
+pub use regex::Regex;
+
+/// A regular expression that is compiled on its first use.
+///
+/// The macro declares one `static` per distinct pattern, so the
+/// expression is compiled only once no matter how many fields or
+/// generated methods use it.
+pub struct Pattern {
+    src: &'static str,
+    once: Once,
+    regex: UnsafeCell<MaybeUninit<Regex>>,
+}
+
+// `regex` is written exactly once (inside `once.call_once`) and
+// only read after `once` has completed.
+unsafe impl Sync for Pattern {}
+
+impl Pattern {
+    #[inline]
+    pub const fn new(src: &'static str) -> Self {
+        Self {
+            src,
+            once: Once::new(),
+            regex: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    #[inline]
+    pub fn src(&self) -> &'static str {
+        self.src
+    }
+
+    pub fn regex(&self) -> &Regex {
+        self.once.call_once(|| {
+            // the macro refuses invalid patterns at compile time
+            let regex = Regex::new(self.src).expect("invalid regular expression");
+
+            unsafe {
+                (*self.regex.get()).write(regex);
+            };
+        });
+
+        unsafe { (*self.regex.get()).assume_init_ref() }
+    }
+
+    #[inline]
+    pub fn is_match(&self, s: &str) -> bool {
+        self.regex().is_match(s)
+    }
+}
+
+impl Drop for Pattern {
+    fn drop(&mut self) {
+        if self.once.is_completed() {
+            unsafe {
+                self.regex.get_mut().assume_init_drop();
+            };
+        };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Pattern;
+
+    static SLUG: Pattern = Pattern::new("^[a-z0-9]+(-[a-z0-9]+)*$");
+
+    #[test]
+    fn is_match() {
+        assert!(SLUG.is_match("hello-world-2"));
+        assert!(!SLUG.is_match("Hello World"));
+        assert!(!SLUG.is_match("-hello"));
+    }
+}
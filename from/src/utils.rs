@@ -1,3 +1,5 @@
+use crate::{Path, PropOrIdx, ValidationErr};
+
 #[inline]
 pub fn array_contains<const L: usize, T>(arr: [T; L], e: T) -> bool
 where
@@ -26,6 +28,51 @@ where
     true
 }
 
+/// Returns the first of `values` that `vec` does not contain.
+#[inline]
+pub fn first_missing<'a, T, E>(values: &'a [T], vec: &[E]) -> Option<&'a T>
+where
+    E: PartialEq<T>,
+{
+    values.iter().find(|v| !vec.iter().any(|e| e == *v))
+}
+
+/// Removes the duplicated elements, keeping the first occurrence
+/// of each one (unlike `Vec::dedup`, the duplicates do not have
+/// to be consecutive).
+pub fn dedup<T>(vec: &mut Vec<T>)
+where
+    T: PartialEq,
+{
+    let mut deduped: Vec<T> = Vec::with_capacity(vec.len());
+
+    for elem in vec.drain(..) {
+        if !deduped.contains(&elem) {
+            deduped.push(elem);
+        };
+    }
+
+    *vec = deduped;
+}
+
+/// Checks whether one of `errs` belongs to the property `prop` of
+/// the object at `path`, either the property itself or one of its
+/// nested values.
+pub fn has_err_at(errs: &[ValidationErr], path: &Path, prop: &str) -> bool {
+    let is_prop = |p: &PropOrIdx| matches!(p, PropOrIdx::Prop(p) if p == prop);
+
+    errs.iter().any(|err| {
+        if !err.path.starts_with(path) {
+            return false;
+        };
+
+        match err.path.get(path.len()) {
+            Some(p) => is_prop(p),
+            None => is_prop(&err.target),
+        }
+    })
+}
+
 #[inline]
 pub fn num_of_f32_fracs(f: f32) -> usize {
     if !f.is_finite() {
@@ -51,3 +98,41 @@ pub fn num_of_f64_fracs(f: f64) -> usize {
         None => 0,
     }
 }
+
+/// Checks that `f` is a multiple of `step`, tolerating the
+/// representation error of decimal values (e.g. `0.15` is a
+/// multiple of `0.05`): the quotient `f / step` must be within
+/// `4 * f32::EPSILON` (relative to its magnitude, or absolute
+/// when it is smaller than `1`) of an integer. The tolerance is
+/// capped at a quarter of a step, so a large value halfway between
+/// two multiples is still rejected. Non-finite values are never
+/// multiples.
+#[inline]
+pub fn is_multiple_of_f32(f: f32, step: f32) -> bool {
+    let q = f / step;
+
+    if !q.is_finite() {
+        return false;
+    };
+
+    (q - q.round()).abs() <= (4.0 * f32::EPSILON * q.abs().max(1.0)).min(0.25)
+}
+
+/// Checks that `f` is a multiple of `step`, tolerating the
+/// representation error of decimal values (e.g. `0.15` is a
+/// multiple of `0.05`): the quotient `f / step` must be within
+/// `4 * f64::EPSILON` (relative to its magnitude, or absolute
+/// when it is smaller than `1`) of an integer. The tolerance is
+/// capped at a quarter of a step, so a large value halfway between
+/// two multiples is still rejected. Non-finite values are never
+/// multiples.
+#[inline]
+pub fn is_multiple_of_f64(f: f64, step: f64) -> bool {
+    let q = f / step;
+
+    if !q.is_finite() {
+        return false;
+    };
+
+    (q - q.round()).abs() <= (4.0 * f64::EPSILON * q.abs().max(1.0)).min(0.25)
+}
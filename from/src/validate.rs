@@ -0,0 +1,55 @@
+use super::{groups, Opts, Path, ValidationErr};
+
+/// Checks the constraints declared by the field attributes on an already
+/// constructed value (the sanitizers are not applied). Implemented by the
+/// `from` attribute for every struct, nested structs and vectors are
+/// checked too.
+pub trait Validate {
+    fn validate_at(&self, path: &Path) -> Result<(), ValidationErr>;
+
+    fn validate_lang_at(&self, path: &Path, lang: &str) -> Result<(), ValidationErr>;
+
+    fn validate_stack_errs_at(&self, path: &Path) -> Result<(), Vec<ValidationErr>>;
+
+    fn validate_stack_errs_lang_at(
+        &self,
+        path: &Path,
+        lang: &str,
+    ) -> Result<(), Vec<ValidationErr>>;
+
+    #[inline]
+    fn validate(&self) -> Result<(), ValidationErr> {
+        self.validate_at(&Path::new())
+    }
+
+    #[inline]
+    fn validate_lang(&self, lang: &str) -> Result<(), ValidationErr> {
+        self.validate_lang_at(&Path::new(), lang)
+    }
+
+    #[inline]
+    fn validate_stack_errs(&self) -> Result<(), Vec<ValidationErr>> {
+        self.validate_stack_errs_at(&Path::new())
+    }
+
+    #[inline]
+    fn validate_stack_errs_lang(&self, lang: &str) -> Result<(), Vec<ValidationErr>> {
+        self.validate_stack_errs_lang_at(&Path::new(), lang)
+    }
+
+    /// Same as [`FromJson::from_json_with`](crate::FromJson::from_json_with),
+    /// `opts` selects the language and the active validation groups.
+    fn validate_with(&self, opts: &Opts) -> Result<(), ValidationErr> {
+        groups::with_active(opts.groups, || match opts.lang {
+            Some(lang) => self.validate_lang(lang),
+            None => self.validate(),
+        })
+    }
+
+    fn validate_stack_errs_with(&self, opts: &Opts) -> Result<(), Vec<ValidationErr>> {
+        groups::with_active(opts.groups, || match opts.lang {
+            Some(lang) => self.validate_stack_errs_lang(lang),
+            None => self.validate_stack_errs(),
+        })
+    }
+}
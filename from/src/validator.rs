@@ -1,4 +1,9 @@
 use super::{Path, ValidationErr};
+
+/// `lang` is the language passed to the `*_lang` methods as it is, so
+/// it may be a regional tag or a list of them in order of preference
+/// (e.g. `"ar-EG"` or `"fr,ar"`, see [`lang`](crate::lang)), the
+/// language of the messages is picked with [`lang::select`](crate::lang::select).
 pub trait Validator<V> {
     fn none(val: &V, path: &Path) -> Result<(), ValidationErr>;
 
@@ -25,3 +30,71 @@ pub trait Validator<V> {
         }
     }
 }
+
+/// Method-based counterpart of [`Validator`] for parameterised
+/// validators (e.g. `#[validators(MinAge(18))]`), the parameters are
+/// read from `self`.
+///
+/// Tuple struct constructors and struct expressions are evaluated once
+/// in a `static` (so the validator must be `Sync`), other expressions
+/// (e.g. `Len::new(3)`) are evaluated on every call.
+///
+/// `lang` is the same as the one of [`Validator`].
+pub trait ParamValidator<V> {
+    fn none(&self, val: &V, path: &Path) -> Result<(), ValidationErr>;
+
+    #[inline(always)]
+    fn lang(&self, val: &V, path: &Path, _: &str) -> Result<(), ValidationErr> {
+        self.none(val, path)
+    }
+
+    #[inline(always)]
+    fn stack_errs(&self, val: &V, path: &Path) -> Result<(), Vec<ValidationErr>> {
+        match self.none(val, path) {
+            Ok(_) => Ok(()),
+
+            Err(e) => Err(vec![e]),
+        }
+    }
+
+    #[inline(always)]
+    fn stack_errs_lang(&self, val: &V, path: &Path, _: &str) -> Result<(), Vec<ValidationErr>> {
+        match self.none(val, path) {
+            Ok(_) => Ok(()),
+
+            Err(e) => Err(vec![e]),
+        }
+    }
+}
+
+/// Context-aware counterpart of [`Validator`] used by the `ctx_validators`
+/// attribute, `ctx` is the value passed to [`FromJson::from_json_ctx`](crate::FromJson::from_json_ctx)
+/// and its family (see [`ctx`](crate::ctx)).
+///
+/// `lang` is the same as the one of [`Validator`].
+pub trait ValidatorCtx<V, C> {
+    fn none(val: &V, ctx: &C, path: &Path) -> Result<(), ValidationErr>;
+
+    #[inline(always)]
+    fn lang(val: &V, ctx: &C, path: &Path, _: &str) -> Result<(), ValidationErr> {
+        Self::none(val, ctx, path)
+    }
+
+    #[inline(always)]
+    fn stack_errs(val: &V, ctx: &C, path: &Path) -> Result<(), Vec<ValidationErr>> {
+        match Self::none(val, ctx, path) {
+            Ok(_) => Ok(()),
+
+            Err(e) => Err(vec![e]),
+        }
+    }
+
+    #[inline(always)]
+    fn stack_errs_lang(val: &V, ctx: &C, path: &Path, _: &str) -> Result<(), Vec<ValidationErr>> {
+        match Self::none(val, ctx, path) {
+            Ok(_) => Ok(()),
+
+            Err(e) => Err(vec![e]),
+        }
+    }
+}
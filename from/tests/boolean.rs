@@ -1,4 +1,4 @@
-use from::{from, FromJson, Null, OptionNull, Path, ValidationErr};
+use from::{from, ErrKind, FromJson, Null, OptionNull, Path, ValidationErr};
 
 #[derive(Debug, PartialEq)]
 #[from(json)]
@@ -63,22 +63,38 @@ fn must_be_valid() {
 fn must_be_invalid() {
     assert_eq!(
         MustBe::from_json(r#"{"field": true}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "value must be false",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "value must be false")
+                .with_kind(ErrKind::MustBe)
+                .with_param("value", false),
+        )
     );
 
     assert_eq!(
         MustBe::from_json_lang(r#"{"field": true}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "value must be false",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "value must be false")
+                .with_kind(ErrKind::MustBe)
+                .with_param("value", false),
+        )
     );
 
     assert_eq!(
         MustBe::from_json_stack_errs(r#"{"field": true}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "value must be false",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "value must be false")
+                .with_kind(ErrKind::MustBe)
+                .with_param("value", false),
+        ])
     );
 
     assert_eq!(
         MustBe::from_json_stack_errs_lang(r#"{"field": true}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "value must be false",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "value must be false")
+                .with_kind(ErrKind::MustBe)
+                .with_param("value", false),
+        ])
     );
 }
 
@@ -117,23 +133,39 @@ fn must_be_overwrite_msg_valid() {
 fn must_be_overwrite_msg_invalid() {
     assert_eq!(
         MustBeOverwriteMsg::from_json(r#"{"field": false}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "must be true",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "must be true")
+                .with_kind(ErrKind::MustBe)
+                .with_param("value", true),
+        )
     );
 
     assert_eq!(
         MustBeOverwriteMsg::from_json_lang(r#"{"field": false}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "يجب أن يكون صحيحا",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "يجب أن يكون صحيحا")
+                .with_kind(ErrKind::MustBe)
+                .with_param("value", true),
+        )
     );
 
     assert_eq!(
         MustBeOverwriteMsg::from_json_stack_errs(r#"{"field": false}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "must be true",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "must be true")
+                .with_kind(ErrKind::MustBe)
+                .with_param("value", true),
+        ])
     );
 
     assert_eq!(
         MustBeOverwriteMsg::from_json_stack_errs_lang(r#"{"field": false}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "must be true",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "must be true")
+                .with_kind(ErrKind::MustBe)
+                .with_param("value", true),
+        ])
     );
 }
 
@@ -173,19 +205,31 @@ fn must_be_overwrite_msg_dflt_lang_valid() {
 fn must_be_overwrite_msg_dflt_lang_invalid() {
     assert_eq!(
         MustBeOverwriteMsgDfltLang::from_json(r#"{"field": false}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "يجب أن يكون صحيحا",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "يجب أن يكون صحيحا")
+                .with_kind(ErrKind::MustBe)
+                .with_param("value", true),
+        )
     );
 
     assert_eq!(
         MustBeOverwriteMsgDfltLang::from_json_lang(r#"{"field": false}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "must be true",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "must be true")
+                .with_kind(ErrKind::MustBe)
+                .with_param("value", true),
+        )
     );
 
     assert_eq!(
         MustBeOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": false}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "يجب أن يكون صحيحا",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "يجب أن يكون صحيحا")
+                .with_kind(ErrKind::MustBe)
+                .with_param("value", true),
+        ])
     );
 
     assert_eq!(
@@ -194,7 +238,11 @@ fn must_be_overwrite_msg_dflt_lang_invalid() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "must be true",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "must be true")
+                .with_kind(ErrKind::MustBe)
+                .with_param("value", true),
+        ])
     );
 }
 
@@ -208,22 +256,34 @@ struct Required {
 fn required() {
     assert_eq!(
         Required::from_json(r#"{"field1": true}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         Required::from_json_lang(r#"{"field2": true}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         Required::from_json_stack_errs(r#"{"field4": true}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        ])
     );
 
     assert_eq!(
         Required::from_json_stack_errs_lang(r#"{"field7": true}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        ])
     );
 }
 
@@ -238,22 +298,34 @@ struct RequiredOverwriteMsg {
 fn required_overwrite_msg() {
     assert_eq!(
         RequiredOverwriteMsg::from_json(r#"{"field7": true}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         RequiredOverwriteMsg::from_json_lang(r#"{"field9": true}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         RequiredOverwriteMsg::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        ])
     );
 
     assert_eq!(
         RequiredOverwriteMsg::from_json_stack_errs_lang(r#"{  }"#.as_bytes(), "ar").unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        ])
     );
 }
 
@@ -269,24 +341,36 @@ struct RequiredOverwriteMsgDfltLang {
 fn required_overwrite_msg_dflt_lang() {
     assert_eq!(
         RequiredOverwriteMsgDfltLang::from_json(r#"{"field7": true}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         RequiredOverwriteMsgDfltLang::from_json_lang(r#"{"field9": true}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         RequiredOverwriteMsgDfltLang::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        ])
     );
 
     assert_eq!(
         RequiredOverwriteMsgDfltLang::from_json_stack_errs_lang(r#"{  }"#.as_bytes(), "ar")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        ])
     );
 }
 
@@ -300,83 +384,128 @@ struct TypeMismatch {
 fn type_mismatch() {
     assert_eq!(
         TypeMismatch::from_json(r#"{"field": 25}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: number",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: number",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "number"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_lang(r#"{"field": 25}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: number",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: number",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "number"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs(r#"{"field": 25}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: number",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: number",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "number"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs_lang(r#"{"field": 25}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: number",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: number",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "number"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json(r#"{"field": "true"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: string",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: string",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "string"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_lang(r#"{"field": "hi"}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: string",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: string",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "string"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs(r#"{"field": ""}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: string",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: string",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "string"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs_lang(r#"{"field": "false"}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: string",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: string",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "string"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json(r#"{"field": {this will be ignored}}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: object",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: object",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "object"),
         )
     );
 
@@ -386,21 +515,31 @@ fn type_mismatch() {
             "en"
         )
         .unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: object",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: object",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "object"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs(r#"{"field": {because it does not care}}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: object",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: object",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "object"),
+        ])
     );
 
     assert_eq!(
@@ -409,19 +548,29 @@ fn type_mismatch() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: object",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: object",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "object"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json(r#"{"field": [this will be ignored]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: array",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: array",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "array"),
         )
     );
 
@@ -431,21 +580,31 @@ fn type_mismatch() {
             "en"
         )
         .unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: array",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: array",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "array"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs(r#"{"field": [because it does not care]}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: array",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: array",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "array"),
+        ])
     );
 
     assert_eq!(
@@ -454,11 +613,16 @@ fn type_mismatch() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: array",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: array",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "boolean")
+            .with_param("found", "array"),
+        ])
     );
 }
 
@@ -473,17 +637,32 @@ struct TypeMismatchOverwriteMsg {
 fn type_mismatch_overwrite_msg() {
     assert_eq!(
         TypeMismatchOverwriteMsg::from_json(r#"{"field": "false"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "invalid type",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid type")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "boolean")
+                .with_param("found", "string"),
+        )
     );
 
     assert_eq!(
         TypeMismatchOverwriteMsg::from_json_lang(r#"{"field": 25}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "نوع غير صالح",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "نوع غير صالح")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "boolean")
+                .with_param("found", "number"),
+        )
     );
 
     assert_eq!(
         TypeMismatchOverwriteMsg::from_json_stack_errs(r#"{"field": {}}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "invalid type",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid type")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "boolean")
+                .with_param("found", "object"),
+        ])
     );
 
     assert_eq!(
@@ -492,7 +671,12 @@ fn type_mismatch_overwrite_msg() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "invalid type",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid type")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "boolean")
+                .with_param("found", "array"),
+        ])
     );
 }
 
@@ -508,19 +692,34 @@ struct TypeMismatchOverwriteMsgDfltLang {
 fn type_mismatch_overwrite_msg_dflt_lang() {
     assert_eq!(
         TypeMismatchOverwriteMsgDfltLang::from_json(r#"{"field": ""}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "نوع غير صالح",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "نوع غير صالح")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "boolean")
+                .with_param("found", "string"),
+        )
     );
 
     assert_eq!(
         TypeMismatchOverwriteMsgDfltLang::from_json_lang(r#"{"field": 25}"#.as_bytes(), "ar")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "نوع غير صالح",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "نوع غير صالح")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "boolean")
+                .with_param("found", "number"),
+        )
     );
 
     assert_eq!(
         TypeMismatchOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": {}}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "نوع غير صالح",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "نوع غير صالح")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "boolean")
+                .with_param("found", "object"),
+        ])
     );
 
     assert_eq!(
@@ -529,7 +728,12 @@ fn type_mismatch_overwrite_msg_dflt_lang() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "invalid type",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid type")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "boolean")
+                .with_param("found", "array"),
+        ])
     );
 }
 
@@ -543,38 +747,52 @@ struct NotNull {
 fn not_null() {
     assert_eq!(
         NotNull::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: null",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
         )
     );
 
+    // the built-in message is translated with 'locale-ar'
+    #[cfg(not(feature = "locale-ar"))]
     assert_eq!(
         NotNull::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: null",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
         )
     );
 
     assert_eq!(
         NotNull::from_json_stack_errs(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: null",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
+        ])
     );
 
     assert_eq!(
         NotNull::from_json_stack_errs_lang(r#"{"field": null}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: boolean, found: null",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: boolean, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
+        ])
     );
 }
 
@@ -589,23 +807,35 @@ struct NotNullOverwriteMsg {
 fn not_null_overwrite_msg() {
     assert_eq!(
         NotNullOverwriteMsg::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsg::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsg::from_json_stack_errs(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        ])
     );
 
     assert_eq!(
         NotNullOverwriteMsg::from_json_stack_errs_lang(r#"{"field": null}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        ])
     );
 }
 
@@ -621,23 +851,32 @@ struct NotNullOverwriteMsgDfltLang {
 fn not_null_overwrite_msg_dflt_lang() {
     assert_eq!(
         NotNullOverwriteMsgDfltLang::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsgDfltLang::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": null}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "القيمة الخالية غير مسموحة",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "القيمة الخالية غير مسموحة",
+            )
+            .with_kind(ErrKind::NotNull),
+        ])
     );
 
     assert_eq!(
@@ -646,7 +885,10 @@ fn not_null_overwrite_msg_dflt_lang() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        ])
     );
 }
 
@@ -930,16 +1172,20 @@ fn multi_err() {
     assert_eq!(
         MultiErr::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
         from::Errs::ValidationErrs(vec![
-            ValidationErr::new(From::from("field1"), Vec::new(), "required field"),
-            ValidationErr::new(From::from("field2"), Vec::new(), "required field"),
+            ValidationErr::new(From::from("field1"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+            ValidationErr::new(From::from("field2"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
         ])
     );
 
     assert_eq!(
         MultiErr::from_json_stack_errs_lang(r#"{}"#.as_bytes(), "en").unwrap_err(),
         from::Errs::ValidationErrs(vec![
-            ValidationErr::new(From::from("field1"), Vec::new(), "required field"),
-            ValidationErr::new(From::from("field2"), Vec::new(), "required field"),
+            ValidationErr::new(From::from("field1"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+            ValidationErr::new(From::from("field2"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
         ])
     );
 }
@@ -0,0 +1,213 @@
+use from::{ctx, from, lang, ErrKind, Errs, FromJson, Path, Validate, ValidationErr, ValidatorCtx};
+
+struct AppCtx {
+    taken: Vec<&'static str>,
+    currencies: Vec<&'static str>,
+    max_total: u32,
+}
+
+struct UniqueUsername;
+
+impl ValidatorCtx<String, AppCtx> for UniqueUsername {
+    fn none(val: &String, ctx: &AppCtx, path: &Path) -> Result<(), ValidationErr> {
+        if ctx.taken.contains(&val.as_str()) {
+            return Err(ValidationErr::new(
+                From::from("username"),
+                path.clone(),
+                "username is already taken",
+            ));
+        };
+
+        Ok(())
+    }
+
+    fn lang(val: &String, ctx: &AppCtx, path: &Path, lang: &str) -> Result<(), ValidationErr> {
+        match (Self::none(val, ctx, path), lang::select(lang, &["ar"])) {
+            (Err(mut e), Some("ar")) => {
+                e.msg = String::from("اسم المستخدم مستخدم بالفعل");
+                Err(e)
+            }
+
+            (res, _) => res,
+        }
+    }
+}
+
+struct SupportedCurrency;
+
+impl ValidatorCtx<String, AppCtx> for SupportedCurrency {
+    fn none(val: &String, ctx: &AppCtx, path: &Path) -> Result<(), ValidationErr> {
+        if !ctx.currencies.contains(&val.as_str()) {
+            return Err(ValidationErr::new(
+                From::from("currency"),
+                path.clone(),
+                "currency is not supported",
+            ));
+        };
+
+        Ok(())
+    }
+}
+
+struct TotalBelowLimit;
+
+impl ValidatorCtx<Order, AppCtx> for TotalBelowLimit {
+    fn none(val: &Order, ctx: &AppCtx, path: &Path) -> Result<(), ValidationErr> {
+        if val.payments.iter().map(|p| p.amount).sum::<u32>() > ctx.max_total {
+            return Err(ValidationErr::new(
+                From::from("payments"),
+                path.clone(),
+                "total exceeds the limit",
+            ));
+        };
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Payment {
+    #[ctx_validators(SupportedCurrency)]
+    currency: String,
+
+    #[max(value = 1000)]
+    amount: u32,
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+#[ctx_validators(TotalBelowLimit)]
+struct Order {
+    #[min_len(value = 3)]
+    #[ctx_validators(UniqueUsername)]
+    username: String,
+
+    payments: Vec<Payment>,
+}
+
+fn app_ctx() -> AppCtx {
+    AppCtx {
+        taken: vec!["alice"],
+        currencies: vec!["USD", "EGP"],
+        max_total: 100,
+    }
+}
+
+const INVALID: &str = r#"{
+    "username": "alice",
+    "payments": [
+        {"currency": "USD", "amount": 60},
+        {"currency": "JPY", "amount": 50}
+    ]
+}"#;
+
+#[test]
+fn without_ctx() {
+    let ty = std::any::type_name::<AppCtx>();
+
+    let missing = |target: &str, path: Path| {
+        ValidationErr::new(
+            From::from(target),
+            path,
+            &format!("missing context of type '{}'", ty),
+        )
+        .with_kind(ErrKind::MissingCtx)
+        .with_param("ctx", ty)
+    };
+
+    // the context-aware validators fail instead of being skipped
+    assert_eq!(
+        Order::from_json(INVALID.as_bytes()),
+        Err(from::Err::ValidationErr(missing("username", Vec::new())))
+    );
+
+    // a context of another type is not the expected one
+    assert_eq!(
+        Order::from_json_ctx(INVALID.as_bytes(), &1u8),
+        Err(from::Err::ValidationErr(missing("username", Vec::new())))
+    );
+
+    assert_eq!(
+        Order::from_json_stack_errs(INVALID.as_bytes()),
+        Err(Errs::ValidationErrs(vec![
+            missing("username", Vec::new()),
+            missing("currency", vec![From::from("payments"), From::from(0)]),
+            missing("currency", vec![From::from("payments"), From::from(1)]),
+        ]))
+    );
+}
+
+#[test]
+fn with_ctx() {
+    let app_ctx = app_ctx();
+
+    assert_eq!(
+        Order::from_json_lang_ctx(INVALID.as_bytes(), "fr,ar-EG", &app_ctx),
+        Err(from::Err::new_validation_err(
+            From::from("username"),
+            Vec::new(),
+            "اسم المستخدم مستخدم بالفعل"
+        ))
+    );
+
+    assert_eq!(
+        Order::from_json_stack_errs_ctx(INVALID.as_bytes(), &app_ctx),
+        Err(Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("username"),
+                Vec::new(),
+                "username is already taken"
+            ),
+            ValidationErr::new(
+                From::from("currency"),
+                vec![From::from("payments"), From::from(1)],
+                "currency is not supported"
+            ),
+        ]))
+    );
+
+    assert_eq!(
+        Order::from_json_ctx(
+            r#"{"username": "bob", "payments": [{"currency": "EGP", "amount": 101}]}"#.as_bytes(),
+            &app_ctx
+        ),
+        Err(from::Err::new_validation_err(
+            From::from("payments"),
+            Vec::new(),
+            "total exceeds the limit"
+        ))
+    );
+
+    let order = Order::from_json_ctx(
+        r#"{"username": "bob", "payments": [{"currency": "EGP", "amount": 100}]}"#.as_bytes(),
+        &app_ctx,
+    )
+    .unwrap();
+
+    assert_eq!(ctx::with_ctx(&app_ctx, || order.validate()), Ok(()));
+
+    let order = Order {
+        username: String::from("alice"),
+        payments: vec![
+            Payment {
+                currency: String::from("USD"),
+                amount: 60,
+            },
+            Payment {
+                currency: String::from("JPY"),
+                amount: 50,
+            },
+        ],
+    };
+
+    assert_eq!(
+        order.validate_stack_errs().map_err(|errs| errs.len()),
+        Err(3)
+    );
+
+    assert_eq!(
+        ctx::with_ctx(&app_ctx, || order.validate_stack_errs()).map_err(|errs| errs.len()),
+        Err(2)
+    );
+}
@@ -1,4 +1,4 @@
-use from::{from, FromJson, Null, OptionNull, Path, ValidationErr};
+use from::{from, ErrKind, FromJson, Null, OptionNull, Path, ValidationErr};
 
 #[derive(Debug, PartialEq)]
 #[from(json)]
@@ -66,10 +66,15 @@ fn normal_valid() {
 fn normal_invalid() {
     assert_eq!(
         Normal::from_json("{\"field\": {\"sub_field\": [null, 79, null]}}".as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("sub_field"),
-            vec![From::from("field")],
-            "array must not contains more than 2 elements"
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("sub_field"),
+                vec![From::from("field")],
+                "array must not contains more than 2 elements"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 2usize)
+            .with_param("actual", 3usize),
         ),
     );
 
@@ -79,21 +84,31 @@ fn normal_invalid() {
             "en"
         )
         .unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("sub_field"),
-            vec![From::from("field")],
-            "array must not contains more than 2 elements"
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("sub_field"),
+                vec![From::from("field")],
+                "array must not contains more than 2 elements"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 2usize)
+            .with_param("actual", 3usize),
         ),
     );
 
     assert_eq!(
         Normal::from_json_stack_errs("{\"field\": {\"sub_field\": [null, null, null]}}".as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("sub_field"),
-            vec![From::from("field")],
-            "array must not contains more than 2 elements"
-        ),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("sub_field"),
+                vec![From::from("field")],
+                "array must not contains more than 2 elements"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 2usize)
+            .with_param("actual", 3usize),
+        ]),
     );
 
     assert_eq!(
@@ -102,11 +117,16 @@ fn normal_invalid() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("sub_field"),
-            vec![From::from("field")],
-            "array must not contains more than 2 elements"
-        ),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("sub_field"),
+                vec![From::from("field")],
+                "array must not contains more than 2 elements"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 2usize)
+            .with_param("actual", 3usize),
+        ]),
     );
 }
 
@@ -264,22 +284,34 @@ struct Required {
 fn required() {
     assert_eq!(
         Required::from_json(r#"{"field1": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         Required::from_json_lang(r#"{"field2": "value"}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         Required::from_json_stack_errs(r#"{"field4": "value"}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        ])
     );
 
     assert_eq!(
         Required::from_json_stack_errs_lang(r#"{"field7": "value"}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        ])
     );
 }
 
@@ -294,23 +326,35 @@ struct RequiredOverwriteMsg {
 fn required_overwrite_msg() {
     assert_eq!(
         RequiredOverwriteMsg::from_json(r#"{"field7": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         RequiredOverwriteMsg::from_json_lang(r#"{"field9": "value"}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         RequiredOverwriteMsg::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        ])
     );
 
     assert_eq!(
         RequiredOverwriteMsg::from_json_stack_errs_lang(r#"{  }"#.as_bytes(), "ar").unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        ])
     );
 }
 
@@ -326,24 +370,36 @@ struct RequiredOverwriteMsgDfltLang {
 fn required_overwrite_msg_dflt_lang() {
     assert_eq!(
         RequiredOverwriteMsgDfltLang::from_json(r#"{"field7": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         RequiredOverwriteMsgDfltLang::from_json_lang(r#"{"field9": "value"}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         RequiredOverwriteMsgDfltLang::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        ])
     );
 
     assert_eq!(
         RequiredOverwriteMsgDfltLang::from_json_stack_errs_lang(r#"{  }"#.as_bytes(), "ar")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        ])
     );
 }
 
@@ -357,38 +413,52 @@ struct NotNull {
 fn not_null() {
     assert_eq!(
         NotNull::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: object, found: null",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: object, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
         )
     );
 
+    // the built-in message is translated with 'locale-ar'
+    #[cfg(not(feature = "locale-ar"))]
     assert_eq!(
         NotNull::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: object, found: null",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: object, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
         )
     );
 
     assert_eq!(
         NotNull::from_json_stack_errs(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: object, found: null",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: object, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
+        ])
     );
 
     assert_eq!(
         NotNull::from_json_stack_errs_lang(r#"{"field": null}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: object, found: null",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: object, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
+        ])
     );
 }
 
@@ -403,23 +473,35 @@ struct NotNullOverwriteMsg {
 fn not_null_overwrite_msg() {
     assert_eq!(
         NotNullOverwriteMsg::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsg::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsg::from_json_stack_errs(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        ])
     );
 
     assert_eq!(
         NotNullOverwriteMsg::from_json_stack_errs_lang(r#"{"field": null}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        ])
     );
 }
 
@@ -435,23 +517,32 @@ struct NotNullOverwriteMsgDfltLang {
 fn not_null_overwrite_msg_dflt_lang() {
     assert_eq!(
         NotNullOverwriteMsgDfltLang::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsgDfltLang::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": null}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "القيمة الخالية غير مسموحة",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "القيمة الخالية غير مسموحة",
+            )
+            .with_kind(ErrKind::NotNull),
+        ])
     );
 
     assert_eq!(
@@ -460,7 +551,10 @@ fn not_null_overwrite_msg_dflt_lang() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        ])
     );
 }
 
@@ -783,16 +877,20 @@ fn multi_err() {
     assert_eq!(
         MultiErr::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
         from::Errs::ValidationErrs(vec![
-            ValidationErr::new(From::from("field1"), Vec::new(), "required field"),
-            ValidationErr::new(From::from("field2"), Vec::new(), "required field"),
+            ValidationErr::new(From::from("field1"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+            ValidationErr::new(From::from("field2"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
         ])
     );
 
     assert_eq!(
         MultiErr::from_json_stack_errs_lang(r#"{}"#.as_bytes(), "en").unwrap_err(),
         from::Errs::ValidationErrs(vec![
-            ValidationErr::new(From::from("field1"), Vec::new(), "required field"),
-            ValidationErr::new(From::from("field2"), Vec::new(), "required field"),
+            ValidationErr::new(From::from("field1"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+            ValidationErr::new(From::from("field2"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
         ])
     );
 }
@@ -0,0 +1,149 @@
+use from::{from, ErrKind, Errs, FromJson, Param, ValidationErr};
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Account {
+    #[max_len(value = 4, unit = "chars")]
+    name: String,
+
+    #[min(value = 18)]
+    age: u8,
+
+    #[r#enum(values = ["admin", "user"])]
+    role: String,
+
+    #[format(email)]
+    email: String,
+
+    #[min_len(value = 1)]
+    tags: Vec<String>,
+
+    #[eq_field = "password"]
+    confirm: String,
+
+    password: String,
+
+    nickname: Option<String>,
+}
+
+fn errs(json: &str) -> Vec<ValidationErr> {
+    match Account::from_json_stack_errs(json.as_bytes()) {
+        Err(Errs::ValidationErrs(errs)) => errs,
+        res => panic!("expected validation errors, found: {:?}", res),
+    }
+}
+
+#[test]
+fn built_in_constraints() {
+    let errs = errs(
+        r#"{
+            "name": "عبدالله",
+            "age": 17,
+            "role": "root",
+            "email": "invalid",
+            "tags": [],
+            "confirm": "a",
+            "password": "a"
+        }"#,
+    );
+
+    let kinds: Vec<_> = errs.iter().map(|e| e.kind).collect();
+
+    assert_eq!(
+        kinds,
+        vec![
+            ErrKind::MaxLen,
+            ErrKind::Min,
+            ErrKind::Enum,
+            ErrKind::Format,
+            ErrKind::MinLen,
+        ]
+    );
+
+    assert_eq!(errs[0].param("max"), Some(&Param::UInt(4)));
+    assert_eq!(errs[0].param("actual"), Some(&Param::UInt(7)));
+    assert_eq!(errs[0].param("unit"), Some(&Param::Str(String::from("chars"))));
+
+    assert_eq!(errs[1].param("min"), Some(&Param::UInt(18)));
+    assert_eq!(errs[1].param("exclusive"), Some(&Param::Bool(false)));
+    assert_eq!(errs[1].param("actual"), Some(&Param::UInt(17)));
+
+    assert_eq!(
+        errs[2].param("values"),
+        Some(&Param::from(vec!["admin", "user"]))
+    );
+    assert_eq!(errs[2].param("actual"), Some(&Param::from("root")));
+
+    assert_eq!(errs[3].param("format"), Some(&Param::from("email")));
+
+    assert_eq!(
+        errs[1].to_json(),
+        r#"{"target":"age","path":[],"msg":"number must be greater than or equal to 18","code":"min","params":{"min":18,"exclusive":false,"actual":17}}"#
+    );
+
+    // struct-level constraints
+    let errs = self::errs(
+        r#"{"name": "abc", "age": 20, "role": "user", "email": "a@b.c", "tags": ["x"], "confirm": "a", "password": "b"}"#,
+    );
+
+    assert_eq!(errs[0].kind, ErrKind::EqField);
+    assert_eq!(errs[0].param("other"), Some(&Param::from("password")));
+}
+
+#[test]
+fn parsing_errs() {
+    let errs = errs(r#"{"name": 5, "age": 20, "role": "user", "email": "a@b.c", "tags": ["x"], "confirm": "a", "password": "a"}"#);
+
+    assert_eq!(errs[0].kind, ErrKind::TypeMismatch);
+    assert_eq!(errs[0].param("expected"), Some(&Param::from("string")));
+    assert_eq!(errs[0].param("found"), Some(&Param::from("number")));
+
+    let errs = self::errs(r#"{"name": "abc", "age": null}"#);
+
+    assert_eq!(errs[0].kind, ErrKind::NotNull);
+    assert_eq!(errs[0].params, Vec::new());
+
+    assert!(errs.iter().any(|e| e.kind == ErrKind::Required));
+}
+
+#[test]
+fn custom_errs() {
+    let err = ValidationErr::new(From::from("age"), Vec::new(), "too young")
+        .with_code("min_age")
+        .with_param("min", 18u8);
+
+    // the kind and the params are compared
+    assert_ne!(
+        err,
+        ValidationErr::new(From::from("age"), Vec::new(), "too young")
+    );
+
+    assert_eq!(
+        err,
+        ValidationErr::new(From::from("age"), Vec::new(), "too young")
+            .with_code("min_age")
+            .with_param("min", 18u8)
+    );
+
+    assert_eq!(
+        from::Err::from(err.clone()).to_json(),
+        r#"{"target":"age","path":[],"msg":"too young","code":"min_age","params":{"min":18}}"#
+    );
+
+    assert_eq!(err.kind, ErrKind::Custom(Some("min_age")));
+    assert_eq!(err.kind.code(), "min_age");
+    assert_eq!(err.param("max"), None);
+
+    // without a code, the kind is not serialized
+    assert_eq!(
+        ValidationErr::new(From::from("age"), Vec::new(), "too young").to_json(),
+        r#"{"target":"age","path":[],"msg":"too young"}"#
+    );
+
+    assert_eq!(
+        ValidationErr::new(From::from("age"), Vec::new(), "too young")
+            .with_kind(ErrKind::Min)
+            .to_json(),
+        r#"{"target":"age","path":[],"msg":"too young","code":"min"}"#
+    );
+}
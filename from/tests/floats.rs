@@ -2,9 +2,10 @@ macro_rules! floats {
     (
         typ: $typ:ident,
         typ_str: $typ_str: expr,
+        large_non_multiple: $large_non_multiple: expr,
     ) => {
         pub mod $typ {
-            use from::{from, FromJson, Null, OptionNull, Path, ValidationErr};
+            use from::{from, ErrKind, FromJson, Null, OptionNull, Path, ValidationErr};
 
             #[derive(Debug, PartialEq)]
             #[from(json)]
@@ -69,39 +70,63 @@ macro_rules! floats {
             fn max_invalid() {
                 assert_eq!(
                     Max::from_json(r#"{"field": 4.01}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be less than or equal to 4",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than or equal to 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4.0)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 4.01 as $typ),
                     )
                 );
 
                 assert_eq!(
                     Max::from_json_lang(r#"{"field": 4.03}"#.as_bytes(), "en").unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be less than or equal to 4",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than or equal to 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4.0)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 4.03 as $typ),
                     )
                 );
 
                 assert_eq!(
                     Max::from_json_stack_errs(r#"{"field": 4.001}"#.as_bytes()).unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be less than or equal to 4",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than or equal to 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4.0)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 4.001 as $typ),
+                    ])
                 );
 
                 assert_eq!(
                     Max::from_json_stack_errs_lang(r#"{"field": 4.0001}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be less than or equal to 4",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than or equal to 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4.0)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 4.0001 as $typ),
+                    ])
                 );
             }
 
@@ -140,30 +165,48 @@ macro_rules! floats {
             fn max_inclusive_invalid() {
                 assert_eq!(
                     MaxInclusive::from_json(r#"{"field": 4.00}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be less than 4",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4.0)
+                        .with_param("exclusive", true)
+                        .with_param("actual", 4.0),
                     )
                 );
 
                 assert_eq!(
                     MaxInclusive::from_json_lang(r#"{"field": 4}"#.as_bytes(), "en").unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be less than 4",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4.0)
+                        .with_param("exclusive", true)
+                        .with_param("actual", 4.0),
                     )
                 );
 
                 assert_eq!(
                     MaxInclusive::from_json_stack_errs(r#"{"field": 4.000}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be less than 4",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4.0)
+                        .with_param("exclusive", true)
+                        .with_param("actual", 4.0),
+                    ])
                 );
 
                 assert_eq!(
@@ -172,11 +215,17 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be less than 4",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4.0)
+                        .with_param("exclusive", true)
+                        .with_param("actual", 4.0),
+                    ])
                 );
             }
 
@@ -215,19 +264,37 @@ macro_rules! floats {
             fn max_overwrite_msg_invalid() {
                 assert_eq!(
                     MaxOverwriteMsg::from_json(r#"{"field": 4.002}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "too big",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "too big")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 4.002 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     MaxOverwriteMsg::from_json_lang(r#"{"field": 4.003}"#.as_bytes(), "ar")
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "كبير جدا",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "كبير جدا")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 4.003 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     MaxOverwriteMsg::from_json_stack_errs(r#"{"field": 4.001}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "too big",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "too big")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 4.001 as $typ),
+                    ])
                 );
 
                 assert_eq!(
@@ -236,7 +303,13 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "too big",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "too big")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 4.00001 as $typ),
+                    ])
                 );
             }
 
@@ -282,7 +355,13 @@ macro_rules! floats {
                 assert_eq!(
                     MaxOverwriteMsgDfltLang::from_json(r#"{"field": 4.00001}"#.as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "كبير جدا",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "كبير جدا")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 4.00001 as $typ),
+                    )
                 );
 
                 assert_eq!(
@@ -291,7 +370,13 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "too big",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "too big")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 4.00002 as $typ),
+                    )
                 );
 
                 assert_eq!(
@@ -299,7 +384,13 @@ macro_rules! floats {
                         r#"{"field": 4.00003}"#.as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "كبير جدا",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "كبير جدا")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 4.00003 as $typ),
+                    ])
                 );
 
                 assert_eq!(
@@ -308,7 +399,13 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "too big",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "too big")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 4.00004 as $typ),
+                    ])
                 );
             }
 
@@ -346,39 +443,63 @@ macro_rules! floats {
             fn min_invalid() {
                 assert_eq!(
                     Min::from_json(r#"{"field": 5.3}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be greater than or equal to 6",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be greater than or equal to 6",
+                        )
+                        .with_kind(ErrKind::Min)
+                        .with_param("min", 6.0)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 5.3 as $typ),
                     )
                 );
 
                 assert_eq!(
                     Min::from_json_lang(r#"{"field": 5.3}"#.as_bytes(), "en").unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be greater than or equal to 6",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be greater than or equal to 6",
+                        )
+                        .with_kind(ErrKind::Min)
+                        .with_param("min", 6.0)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 5.3 as $typ),
                     )
                 );
 
                 assert_eq!(
                     Min::from_json_stack_errs(r#"{"field": 5.3}"#.as_bytes()).unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be greater than or equal to 6",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be greater than or equal to 6",
+                        )
+                        .with_kind(ErrKind::Min)
+                        .with_param("min", 6.0)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 5.3 as $typ),
+                    ])
                 );
 
                 assert_eq!(
                     Min::from_json_stack_errs_lang(r#"{"field": 5.3}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be greater than or equal to 6",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be greater than or equal to 6",
+                        )
+                        .with_kind(ErrKind::Min)
+                        .with_param("min", 6.0)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 5.3 as $typ),
+                    ])
                 );
             }
 
@@ -417,40 +538,64 @@ macro_rules! floats {
             fn min_inclusive_invalid() {
                 assert_eq!(
                     MinInclusive::from_json(r#"{"field": 6.000}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be greater than 6",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be greater than 6",
+                        )
+                        .with_kind(ErrKind::Min)
+                        .with_param("min", 6.0)
+                        .with_param("exclusive", true)
+                        .with_param("actual", 6.0),
                     )
                 );
 
                 assert_eq!(
                     MinInclusive::from_json_lang(r#"{"field": 6}"#.as_bytes(), "en").unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be greater than 6",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be greater than 6",
+                        )
+                        .with_kind(ErrKind::Min)
+                        .with_param("min", 6.0)
+                        .with_param("exclusive", true)
+                        .with_param("actual", 6.0),
                     )
                 );
 
                 assert_eq!(
                     MinInclusive::from_json_stack_errs(r#"{"field": 6.00000}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be greater than 6",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be greater than 6",
+                        )
+                        .with_kind(ErrKind::Min)
+                        .with_param("min", 6.0)
+                        .with_param("exclusive", true)
+                        .with_param("actual", 6.0),
+                    ])
                 );
 
                 assert_eq!(
                     MinInclusive::from_json_stack_errs_lang(r#"{"field": 6}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be greater than 6",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be greater than 6",
+                        )
+                        .with_kind(ErrKind::Min)
+                        .with_param("min", 6.0)
+                        .with_param("exclusive", true)
+                        .with_param("actual", 6.0),
+                    ])
                 );
             }
 
@@ -489,19 +634,37 @@ macro_rules! floats {
             fn min_overwrite_msg_invalid() {
                 assert_eq!(
                     MinOverwriteMsg::from_json(r#"{"field": 5.3}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "too small",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "too small")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5.3 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     MinOverwriteMsg::from_json_lang(r#"{"field": 5.3}"#.as_bytes(), "ar")
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "صغير جدا",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "صغير جدا")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5.3 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     MinOverwriteMsg::from_json_stack_errs(r#"{"field": 5.3}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "too small",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "too small")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5.3 as $typ),
+                    ])
                 );
 
                 assert_eq!(
@@ -510,7 +673,13 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "too small",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "too small")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5.3 as $typ),
+                    ])
                 );
             }
 
@@ -555,19 +724,37 @@ macro_rules! floats {
             fn min_overwrite_msg_dflt_lang_invalid() {
                 assert_eq!(
                     MinOverwriteMsgDfltLang::from_json(r#"{"field": 5.3}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "صغير جدا",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "صغير جدا")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5.3 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     MinOverwriteMsgDfltLang::from_json_lang(r#"{"field": 5.3}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "too small",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "too small")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5.3 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     MinOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": 5.3}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "صغير جدا",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "صغير جدا")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5.3 as $typ),
+                    ])
                 );
 
                 assert_eq!(
@@ -576,7 +763,13 @@ macro_rules! floats {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "صغير جدا",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "صغير جدا")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6.0)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5.3 as $typ),
+                    ])
                 );
             }
 
@@ -614,39 +807,59 @@ macro_rules! floats {
             fn enum_invalid() {
                 assert_eq!(
                     Enum::from_json(r#"{"field": 6}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be one of: [5.3, 7.0]",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be one of: [5.3, 7.0]",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5.3 as $typ, 7.0])
+                        .with_param("actual", 6.0),
                     )
                 );
 
                 assert_eq!(
                     Enum::from_json_lang(r#"{"field": 6}"#.as_bytes(), "en").unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be one of: [5.3, 7.0]",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be one of: [5.3, 7.0]",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5.3 as $typ, 7.0])
+                        .with_param("actual", 6.0),
                     )
                 );
 
                 assert_eq!(
                     Enum::from_json_stack_errs(r#"{"field": 9}"#.as_bytes()).unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be one of: [5.3, 7.0]",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be one of: [5.3, 7.0]",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5.3 as $typ, 7.0])
+                        .with_param("actual", 9.0),
+                    ])
                 );
 
                 assert_eq!(
                     Enum::from_json_stack_errs_lang(r#"{"field": 8}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be one of: [5.3, 7.0]",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be one of: [5.3, 7.0]",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5.3 as $typ, 7.0])
+                        .with_param("actual", 8.0),
+                    ])
                 );
             }
 
@@ -685,27 +898,42 @@ macro_rules! floats {
             fn enum_overwrite_msg_invalid() {
                 assert_eq!(
                     EnumOverwriteMsg::from_json(r#"{"field": 8}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "invalid number",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "invalid number",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5.3 as $typ, 7.0])
+                        .with_param("actual", 8.0),
                     )
                 );
 
                 assert_eq!(
                     EnumOverwriteMsg::from_json_lang(r#"{"field": 9}"#.as_bytes(), "ar")
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "عدد غير صالح",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "عدد غير صالح")
+                            .with_kind(ErrKind::Enum)
+                            .with_param("values", [5.3 as $typ, 7.0])
+                            .with_param("actual", 9.0),
+                    )
                 );
 
                 assert_eq!(
                     EnumOverwriteMsg::from_json_stack_errs(r#"{"field": 15.3}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "invalid number",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "invalid number",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5.3 as $typ, 7.0])
+                        .with_param("actual", 15.3 as $typ),
+                    ])
                 );
 
                 assert_eq!(
@@ -714,7 +942,12 @@ macro_rules! floats {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "عدد غير صالح",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "عدد غير صالح")
+                            .with_kind(ErrKind::Enum)
+                            .with_param("values", [5.3 as $typ, 7.0])
+                            .with_param("actual", 20.0),
+                    ])
                 );
             }
 
@@ -759,23 +992,38 @@ macro_rules! floats {
             fn enum_overwrite_msg_dflt_lang_invalid() {
                 assert_eq!(
                     EnumOverwriteMsgDfltLang::from_json(r#"{"field": 8}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "عدد غير صالح",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "عدد غير صالح")
+                            .with_kind(ErrKind::Enum)
+                            .with_param("values", [5.3 as $typ, 7.0])
+                            .with_param("actual", 8.0),
+                    )
                 );
 
                 assert_eq!(
                     EnumOverwriteMsgDfltLang::from_json_lang(r#"{"field": 75.3}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "invalid number",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "invalid number",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5.3 as $typ, 7.0])
+                        .with_param("actual", 75.3 as $typ),
                     )
                 );
 
                 assert_eq!(
                     EnumOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": 5.33}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "عدد غير صالح",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "عدد غير صالح")
+                            .with_kind(ErrKind::Enum)
+                            .with_param("values", [5.3 as $typ, 7.0])
+                            .with_param("actual", 5.33 as $typ),
+                    ])
                 );
 
                 assert_eq!(
@@ -784,11 +1032,16 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "invalid number",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "invalid number",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5.3 as $typ, 7.0])
+                        .with_param("actual", 25.3 as $typ),
+                    ])
                 );
             }
 
@@ -802,31 +1055,40 @@ macro_rules! floats {
             fn infinite() {
                 assert_eq!(
                     Infinite::from_json(r#"{"field": 5.3e789}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must not be Infinite",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must not be Infinite",
+                        )
+                        .with_kind(ErrKind::Infinite),
                     )
                 );
 
                 assert_eq!(
                     Infinite::from_json_lang(r#"{"field": 0.246e78979}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must not be Infinite",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must not be Infinite",
+                        )
+                        .with_kind(ErrKind::Infinite),
                     )
                 );
 
                 assert_eq!(
                     Infinite::from_json_stack_errs(r#"{"field": -0.46e898}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must not be Infinite",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must not be Infinite",
+                        )
+                        .with_kind(ErrKind::Infinite),
+                    ])
                 );
 
                 assert_eq!(
@@ -835,11 +1097,14 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must not be Infinite",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must not be Infinite",
+                        )
+                        .with_kind(ErrKind::Infinite),
+                    ])
                 );
             }
 
@@ -855,31 +1120,40 @@ macro_rules! floats {
                 assert_eq!(
                     InfiniteOverwriteMsg::from_json(r#"{"field": 100e978}"#.as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "infinte is not allowed",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "infinte is not allowed",
+                        )
+                        .with_kind(ErrKind::Infinite),
                     )
                 );
 
                 assert_eq!(
                     InfiniteOverwriteMsg::from_json_lang(r#"{"field": 5.3e789}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "infinte is not allowed",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "infinte is not allowed",
+                        )
+                        .with_kind(ErrKind::Infinite),
                     )
                 );
 
                 assert_eq!(
                     InfiniteOverwriteMsg::from_json_stack_errs(r#"{"field": 79e8987}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "infinte is not allowed",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "infinte is not allowed",
+                        )
+                        .with_kind(ErrKind::Infinite),
+                    ])
                 );
 
                 assert_eq!(
@@ -888,11 +1162,14 @@ macro_rules! floats {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "اللانهائية غير مسموحة",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "اللانهائية غير مسموحة",
+                        )
+                        .with_kind(ErrKind::Infinite),
+                    ])
                 );
             }
 
@@ -909,10 +1186,13 @@ macro_rules! floats {
                 assert_eq!(
                     InfiniteOverwriteMsgDfltLang::from_json(r#"{"field": 5.0e7897}"#.as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "اللانهائية غير مسموحة",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "اللانهائية غير مسموحة",
+                        )
+                        .with_kind(ErrKind::Infinite),
                     )
                 );
 
@@ -922,10 +1202,13 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "infinte is not allowed",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "infinte is not allowed",
+                        )
+                        .with_kind(ErrKind::Infinite),
                     )
                 );
 
@@ -934,11 +1217,14 @@ macro_rules! floats {
                         r#"{"field": 77e7899}"#.as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "اللانهائية غير مسموحة",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "اللانهائية غير مسموحة",
+                        )
+                        .with_kind(ErrKind::Infinite),
+                    ])
                 );
 
                 assert_eq!(
@@ -947,10 +1233,402 @@ macro_rules! floats {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "اللانهائية غير مسموحة",
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "اللانهائية غير مسموحة",
+                        )
+                        .with_kind(ErrKind::Infinite),
+                    ])
+                );
+            }
+
+            #[derive(Debug, PartialEq)]
+            #[from(json)]
+            struct MaxExclusive {
+                #[max(value = 4.0, exclusive)]
+                field: $typ,
+            }
+
+            #[test]
+            fn max_exclusive() {
+                assert_eq!(
+                    MaxExclusive::from_json("{\"field\": 3.9}".as_bytes()).unwrap(),
+                    MaxExclusive { field: 3.9 }
+                );
+
+                assert_eq!(
+                    MaxExclusive::from_json_lang(r#"{"field": 4}"#.as_bytes(), "en")
+                        .unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4.0)
+                        .with_param("exclusive", true)
+                        .with_param("actual", 4.0),
+                    )
+                );
+
+                assert_eq!(
+                    MaxExclusive::from_json_stack_errs(r#"{"field": 4}"#.as_bytes())
+                        .unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4.0)
+                        .with_param("exclusive", true)
+                        .with_param("actual", 4.0),
+                    ])
+                );
+            }
+
+            #[derive(Debug, PartialEq)]
+            #[from(json)]
+            struct Range {
+                #[range(min = 0.5, max = 2.5)]
+                field: $typ,
+            }
+
+            #[test]
+            fn range_valid() {
+                assert_eq!(
+                    Range::from_json("{\"field\": 0.5}".as_bytes()).unwrap(),
+                    Range { field: 0.5 }
+                );
+
+                assert_eq!(
+                    Range::from_json_lang("{\"field\": 2.5}".as_bytes(), "en").unwrap(),
+                    Range { field: 2.5 }
+                );
+
+                assert_eq!(
+                    Range::from_json_stack_errs("{\"field\": 1.3}".as_bytes()).unwrap(),
+                    Range { field: 1.3 }
+                );
+
+                assert_eq!(
+                    Range::from_json_stack_errs_lang("{\"field\": 2}".as_bytes(), "en")
+                        .unwrap(),
+                    Range { field: 2.0 }
+                );
+            }
+
+            #[test]
+            fn range_invalid() {
+                assert_eq!(
+                    Range::from_json(r#"{"field": 0.4}"#.as_bytes()).unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be between 0.5 and 2.5",
+                        )
+                        .with_kind(ErrKind::Range)
+                        .with_param("min", 0.5)
+                        .with_param("max", 2.5)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 0.4 as $typ),
+                    )
+                );
+
+                assert_eq!(
+                    Range::from_json_lang(r#"{"field": 2.6}"#.as_bytes(), "en").unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be between 0.5 and 2.5",
+                        )
+                        .with_kind(ErrKind::Range)
+                        .with_param("min", 0.5)
+                        .with_param("max", 2.5)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 2.6 as $typ),
+                    )
+                );
+
+                assert_eq!(
+                    Range::from_json_stack_errs(r#"{"field": 3}"#.as_bytes()).unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be between 0.5 and 2.5",
+                        )
+                        .with_kind(ErrKind::Range)
+                        .with_param("min", 0.5)
+                        .with_param("max", 2.5)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 3.0),
+                    ])
+                );
+
+                assert_eq!(
+                    Range::from_json_stack_errs_lang(r#"{"field": -1}"#.as_bytes(), "en")
+                        .unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be between 0.5 and 2.5",
+                        )
+                        .with_kind(ErrKind::Range)
+                        .with_param("min", 0.5)
+                        .with_param("max", 2.5)
+                        .with_param("exclusive", false)
+                        .with_param("actual", -1.0),
+                    ])
+                );
+            }
+
+            #[derive(Debug, PartialEq)]
+            #[from(json)]
+            struct RangeExclusiveOverwriteMsg {
+                #[range(
+                    min = 0.5,
+                    max = 2.5,
+                    exclusive,
+                    msgs{en="out of range", ar="خارج النطاق"},
+                )]
+                field: $typ,
+            }
+
+            #[test]
+            fn range_exclusive_overwrite_msg() {
+                assert_eq!(
+                    RangeExclusiveOverwriteMsg::from_json("{\"field\": 2.4}".as_bytes())
+                        .unwrap(),
+                    RangeExclusiveOverwriteMsg { field: 2.4 }
+                );
+
+                assert_eq!(
+                    RangeExclusiveOverwriteMsg::from_json_lang(
+                        r#"{"field": 2.5}"#.as_bytes(),
+                        "ar"
+                    )
+                    .unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "خارج النطاق")
+                            .with_kind(ErrKind::Range)
+                            .with_param("min", 0.5)
+                            .with_param("max", 2.5)
+                            .with_param("exclusive", true)
+                            .with_param("actual", 2.5),
+                    )
+                );
+
+                assert_eq!(
+                    RangeExclusiveOverwriteMsg::from_json_stack_errs(
+                        r#"{"field": 0.5}"#.as_bytes()
+                    )
+                    .unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "out of range")
+                            .with_kind(ErrKind::Range)
+                            .with_param("min", 0.5)
+                            .with_param("max", 2.5)
+                            .with_param("exclusive", true)
+                            .with_param("actual", 0.5),
+                    ])
+                );
+            }
+
+            #[derive(Debug, PartialEq)]
+            #[from(json)]
+            struct NotEnum {
+                #[not_enum(values = [0.0, 7.5])]
+                field: $typ,
+            }
+
+            #[test]
+            fn not_enum() {
+                assert_eq!(
+                    NotEnum::from_json("{\"field\": 5.5}".as_bytes()).unwrap(),
+                    NotEnum { field: 5.5 }
+                );
+
+                assert_eq!(
+                    NotEnum::from_json(r#"{"field": 7.5}"#.as_bytes()).unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number 7.5 is not allowed",
+                        )
+                        .with_kind(ErrKind::NotEnum)
+                        .with_param("values", [0.0, 7.5])
+                        .with_param("actual", 7.5),
+                    )
+                );
+
+                assert_eq!(
+                    NotEnum::from_json_lang(r#"{"field": 0}"#.as_bytes(), "en").unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number 0 is not allowed",
+                        )
+                        .with_kind(ErrKind::NotEnum)
+                        .with_param("values", [0.0, 7.5])
+                        .with_param("actual", 0.0),
+                    )
+                );
+
+                assert_eq!(
+                    NotEnum::from_json_stack_errs(r#"{"field": 7.5}"#.as_bytes()).unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number 7.5 is not allowed",
+                        )
+                        .with_kind(ErrKind::NotEnum)
+                        .with_param("values", [0.0, 7.5])
+                        .with_param("actual", 7.5),
+                    ])
+                );
+
+                assert_eq!(
+                    NotEnum::from_json_stack_errs_lang(r#"{"field": 0}"#.as_bytes(), "en")
+                        .unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number 0 is not allowed",
+                        )
+                        .with_kind(ErrKind::NotEnum)
+                        .with_param("values", [0.0, 7.5])
+                        .with_param("actual", 0.0),
+                    ])
+                );
+            }
+
+            #[derive(Debug, PartialEq)]
+            #[from(json)]
+            struct MultipleOf {
+                #[multiple_of(value = 0.05)]
+                field: $typ,
+            }
+
+            #[test]
+            fn multiple_of_valid() {
+                assert_eq!(
+                    MultipleOf::from_json("{\"field\": 0.15}".as_bytes()).unwrap(),
+                    MultipleOf { field: 0.15 }
+                );
+
+                assert_eq!(
+                    MultipleOf::from_json_lang("{\"field\": 1.1}".as_bytes(), "en").unwrap(),
+                    MultipleOf { field: 1.1 }
+                );
+
+                assert_eq!(
+                    MultipleOf::from_json_stack_errs("{\"field\": 19.95}".as_bytes()).unwrap(),
+                    MultipleOf { field: 19.95 }
+                );
+
+                assert_eq!(
+                    MultipleOf::from_json_stack_errs_lang("{\"field\": -0.3}".as_bytes(), "en")
+                        .unwrap(),
+                    MultipleOf { field: -0.3 }
+                );
+            }
+
+            #[test]
+            fn multiple_of_invalid() {
+                assert_eq!(
+                    MultipleOf::from_json(r#"{"field": 0.12}"#.as_bytes()).unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be a multiple of 0.05",
+                        )
+                        .with_kind(ErrKind::MultipleOf)
+                        .with_param("multiple_of", 0.05 as $typ)
+                        .with_param("actual", 0.12 as $typ),
+                    )
+                );
+
+                assert_eq!(
+                    MultipleOf::from_json_lang(r#"{"field": 1.01}"#.as_bytes(), "en").unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be a multiple of 0.05",
+                        )
+                        .with_kind(ErrKind::MultipleOf)
+                        .with_param("multiple_of", 0.05 as $typ)
+                        .with_param("actual", 1.01 as $typ),
+                    )
+                );
+
+                assert_eq!(
+                    MultipleOf::from_json_stack_errs(r#"{"field": 0.051}"#.as_bytes())
+                        .unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be a multiple of 0.05",
+                        )
+                        .with_kind(ErrKind::MultipleOf)
+                        .with_param("multiple_of", 0.05 as $typ)
+                        .with_param("actual", 0.051 as $typ),
+                    ])
+                );
+
+                assert_eq!(
+                    MultipleOf::from_json_stack_errs_lang(r#"{"field": 19.99}"#.as_bytes(), "en")
+                        .unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be a multiple of 0.05",
+                        )
+                        .with_kind(ErrKind::MultipleOf)
+                        .with_param("multiple_of", 0.05 as $typ)
+                        .with_param("actual", 19.99 as $typ),
+                    ])
+                );
+            }
+
+            #[derive(Debug, PartialEq)]
+            #[from(json)]
+            struct MultipleOfOne {
+                #[multiple_of(value = 1.0)]
+                field: $typ,
+            }
+
+            #[test]
+            fn multiple_of_large_invalid() {
+                assert_eq!(
+                    MultipleOfOne::from_json(
+                        format!(r#"{{"field": {}}}"#, $large_non_multiple).as_bytes()
+                    )
+                    .unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be a multiple of 1",
+                        )
+                        .with_kind(ErrKind::MultipleOf)
+                        .with_param("multiple_of", 1.0)
+                        .with_param("actual", $large_non_multiple.parse::<$typ>().unwrap()),
                     )
                 );
             }
@@ -965,39 +1643,51 @@ macro_rules! floats {
             fn required() {
                 assert_eq!(
                     Required::from_json(r#"{"field1": 5.3}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "required field",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "required field",
+                        )
+                        .with_kind(ErrKind::Required),
                     )
                 );
 
                 assert_eq!(
                     Required::from_json_lang(r#"{"field2": 5.3}"#.as_bytes(), "en").unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "required field",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "required field",
+                        )
+                        .with_kind(ErrKind::Required),
                     )
                 );
 
                 assert_eq!(
                     Required::from_json_stack_errs(r#"{"field4": 5.3}"#.as_bytes()).unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "required field",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "required field",
+                        )
+                        .with_kind(ErrKind::Required),
+                    ])
                 );
 
                 assert_eq!(
                     Required::from_json_stack_errs_lang(r#"{"field7": 5.3}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "required field",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "required field",
+                        )
+                        .with_kind(ErrKind::Required),
+                    ])
                 );
             }
 
@@ -1012,36 +1702,48 @@ macro_rules! floats {
             fn required_overwrite_msg() {
                 assert_eq!(
                     RequiredOverwriteMsg::from_json(r#"{"field7": 5.3}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "value is required",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "value is required",
+                        )
+                        .with_kind(ErrKind::Required),
                     )
                 );
 
                 assert_eq!(
                     RequiredOverwriteMsg::from_json_lang(r#"{"field9": 5.3}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "value is required",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "value is required",
+                        )
+                        .with_kind(ErrKind::Required),
                     )
                 );
 
                 assert_eq!(
                     RequiredOverwriteMsg::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "value is required",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "value is required",
+                        )
+                        .with_kind(ErrKind::Required),
+                    ])
                 );
 
                 assert_eq!(
                     RequiredOverwriteMsg::from_json_stack_errs_lang(r#"{  }"#.as_bytes(), "ar")
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                            .with_kind(ErrKind::Required),
+                    ])
                 );
             }
 
@@ -1058,7 +1760,10 @@ macro_rules! floats {
                 assert_eq!(
                     RequiredOverwriteMsgDfltLang::from_json(r#"{"field7": 5.3}"#.as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                            .with_kind(ErrKind::Required),
+                    )
                 );
 
                 assert_eq!(
@@ -1067,17 +1772,23 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "value is required",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "value is required",
+                        )
+                        .with_kind(ErrKind::Required),
                     )
                 );
 
                 assert_eq!(
                     RequiredOverwriteMsgDfltLang::from_json_stack_errs(r#"{}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                            .with_kind(ErrKind::Required),
+                    ])
                 );
 
                 assert_eq!(
@@ -1086,7 +1797,10 @@ macro_rules! floats {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                            .with_kind(ErrKind::Required),
+                    ])
                 );
             }
 
@@ -1104,31 +1818,46 @@ macro_rules! floats {
             fn type_mismatch() {
                 assert_eq!(
                     TypeMismatch::from_json(r#"{"field": ""}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: string", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: string", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "string"),
                     )
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json_lang(r#"{"field": "as"}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: string", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: string", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "string"),
                     )
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json_stack_errs(r#"{"field": "25.3"}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: string", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: string", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "string"),
+                    ])
                 );
 
                 assert_eq!(
@@ -1137,59 +1866,89 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: string", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: string", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "string"),
+                    ])
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json(r#"{"field": true}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: boolean", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: boolean", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "boolean"),
                     )
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json_lang(r#"{"field": false}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: boolean", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: boolean", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "boolean"),
                     )
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json_stack_errs(r#"{"field": true}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: boolean", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: boolean", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "boolean"),
+                    ])
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json_stack_errs_lang(r#"{"field": true}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: boolean", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: boolean", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "boolean"),
+                    ])
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json(r#"{"field": {this will be ignored}}"#.as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: object", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: object", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "object"),
                     )
                 );
 
@@ -1199,10 +1958,15 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: object", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: object", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "object"),
                     )
                 );
 
@@ -1211,11 +1975,16 @@ macro_rules! floats {
                         r#"{"field": {because it does not care}}"#.as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: object", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: object", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "object"),
+                    ])
                 );
 
                 assert_eq!(
@@ -1224,20 +1993,30 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: object", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: object", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "object"),
+                    ])
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json(r#"{"field": [this will be ignored]}"#.as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: array", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: array", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "array"),
                     )
                 );
 
@@ -1247,10 +2026,15 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: array", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: array", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "array"),
                     )
                 );
 
@@ -1259,11 +2043,16 @@ macro_rules! floats {
                         r#"{"field": [because it does not care]}"#.as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: array", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: array", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "array"),
+                    ])
                 );
 
                 assert_eq!(
@@ -1272,11 +2061,16 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: array", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: array", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "array"),
+                    ])
                 );
             }
 
@@ -1292,7 +2086,12 @@ macro_rules! floats {
                 assert_eq!(
                     TypeMismatchOverwriteMsg::from_json(r#"{"field": false}"#.as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "boolean"),
+                    )
                 );
 
                 assert_eq!(
@@ -1301,13 +2100,23 @@ macro_rules! floats {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "string"),
+                    )
                 );
 
                 assert_eq!(
                     TypeMismatchOverwriteMsg::from_json_stack_errs(r#"{"field": {}}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "object"),
+                    ])
                 );
 
                 assert_eq!(
@@ -1316,7 +2125,12 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "array"),
+                    ])
                 );
             }
 
@@ -1333,7 +2147,12 @@ macro_rules! floats {
                 assert_eq!(
                     TypeMismatchOverwriteMsgDfltLang::from_json(r#"{"field": false}"#.as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "boolean"),
+                    )
                 );
 
                 assert_eq!(
@@ -1342,7 +2161,12 @@ macro_rules! floats {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "string"),
+                    )
                 );
 
                 assert_eq!(
@@ -1350,7 +2174,12 @@ macro_rules! floats {
                         r#"{"field": {}}"#.as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "object"),
+                    ])
                 );
 
                 assert_eq!(
@@ -1359,7 +2188,12 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "array"),
+                    ])
                 );
             }
 
@@ -1373,39 +2207,53 @@ macro_rules! floats {
             fn not_null() {
                 assert_eq!(
                     NotNull::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: null", $typ_str)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: null", $typ_str)
+                        )
+                        .with_kind(ErrKind::NotNull),
                     )
                 );
 
+                // the built-in message is translated with 'locale-ar'
+                #[cfg(not(feature = "locale-ar"))]
                 assert_eq!(
                     NotNull::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar").unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: null", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: null", $typ_str),
+                        )
+                        .with_kind(ErrKind::NotNull),
                     )
                 );
 
                 assert_eq!(
                     NotNull::from_json_stack_errs(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: null", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: null", $typ_str),
+                        )
+                        .with_kind(ErrKind::NotNull),
+                    ])
                 );
 
                 assert_eq!(
                     NotNull::from_json_stack_errs_lang(r#"{"field": null}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: null", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: null", $typ_str),
+                        )
+                        .with_kind(ErrKind::NotNull),
+                    ])
                 );
             }
 
@@ -1420,31 +2268,40 @@ macro_rules! floats {
             fn not_null_overwrite_msg() {
                 assert_eq!(
                     NotNullOverwriteMsg::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "null is not allowed",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "null is not allowed",
+                        )
+                        .with_kind(ErrKind::NotNull),
                     )
                 );
 
                 assert_eq!(
                     NotNullOverwriteMsg::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar")
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "القيمة الخالية غير مسموحة",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "القيمة الخالية غير مسموحة",
+                        )
+                        .with_kind(ErrKind::NotNull),
                     )
                 );
 
                 assert_eq!(
                     NotNullOverwriteMsg::from_json_stack_errs(r#"{"field": null}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "null is not allowed",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "null is not allowed",
+                        )
+                        .with_kind(ErrKind::NotNull),
+                    ])
                 );
 
                 assert_eq!(
@@ -1453,11 +2310,14 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "null is not allowed",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "null is not allowed",
+                        )
+                        .with_kind(ErrKind::NotNull),
+                    ])
                 );
             }
 
@@ -1474,10 +2334,13 @@ macro_rules! floats {
                 assert_eq!(
                     NotNullOverwriteMsgDfltLang::from_json(r#"{"field": null}"#.as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "القيمة الخالية غير مسموحة",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "القيمة الخالية غير مسموحة",
+                        )
+                        .with_kind(ErrKind::NotNull),
                     )
                 );
 
@@ -1487,10 +2350,13 @@ macro_rules! floats {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "القيمة الخالية غير مسموحة",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "القيمة الخالية غير مسموحة",
+                        )
+                        .with_kind(ErrKind::NotNull),
                     )
                 );
 
@@ -1499,11 +2365,14 @@ macro_rules! floats {
                         r#"{"field": null}"#.as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "القيمة الخالية غير مسموحة",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "القيمة الخالية غير مسموحة",
+                        )
+                        .with_kind(ErrKind::NotNull),
+                    ])
                 );
 
                 assert_eq!(
@@ -1512,11 +2381,14 @@ macro_rules! floats {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "null is not allowed",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "null is not allowed",
+                        )
+                        .with_kind(ErrKind::NotNull),
+                    ])
                 );
             }
 
@@ -1809,16 +2681,20 @@ macro_rules! floats {
                 assert_eq!(
                     MultiErr::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
                     from::Errs::ValidationErrs(vec![
-                        ValidationErr::new(From::from("field1"), Vec::new(), "required field"),
-                        ValidationErr::new(From::from("field2"), Vec::new(), "required field"),
+                        ValidationErr::new(From::from("field1"), Vec::new(), "required field")
+                            .with_kind(ErrKind::Required),
+                        ValidationErr::new(From::from("field2"), Vec::new(), "required field")
+                            .with_kind(ErrKind::Required),
                     ])
                 );
 
                 assert_eq!(
                     MultiErr::from_json_stack_errs_lang(r#"{}"#.as_bytes(), "en").unwrap_err(),
                     from::Errs::ValidationErrs(vec![
-                        ValidationErr::new(From::from("field1"), Vec::new(), "required field"),
-                        ValidationErr::new(From::from("field2"), Vec::new(), "required field"),
+                        ValidationErr::new(From::from("field1"), Vec::new(), "required field")
+                            .with_kind(ErrKind::Required),
+                        ValidationErr::new(From::from("field2"), Vec::new(), "required field")
+                            .with_kind(ErrKind::Required),
                     ])
                 );
             }
@@ -1880,11 +2756,13 @@ macro_rules! floats {
 floats!(
     typ: f32,
     typ_str: "f32",
+    large_non_multiple: "2000000.5",
 );
 
 floats!(
     typ: f64,
     typ_str: "f64",
+    large_non_multiple: "1000000000000000.5",
 );
 
 // Nan
@@ -0,0 +1,238 @@
+use from::{from, groups, ErrKind, Errs, FromJson, Opts, Validate, ValidationErr};
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Account {
+    #[min_len(value = 3, groups(create))]
+    #[max_len(value = 8)]
+    name: String,
+
+    #[required(groups(create))]
+    #[required_msgs{
+        en = "password is required",
+    }]
+    password: Option<String>,
+
+    #[max(value = 5, groups(create, admin))]
+    level: u8,
+
+    #[sorted(groups(update))]
+    tags: Vec<u8>,
+}
+
+#[test]
+fn without_groups() {
+    let json = r#"{"name": "ab", "level": 7, "tags": [2, 1]}"#.as_bytes();
+
+    let expected = Account {
+        name: String::from("ab"),
+        password: None,
+        level: 7,
+        tags: vec![2, 1],
+    };
+
+    assert_eq!(Account::from_json(json).unwrap(), expected);
+
+    assert_eq!(
+        Account::from_json_with(json, &Opts::default()).unwrap(),
+        expected
+    );
+
+    assert_eq!(
+        Account::from_json(r#"{"name": "too long name", "level": 7, "tags": []}"#.as_bytes()),
+        Err(from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("name"),
+                Vec::new(),
+                "the string value must be no longer than 8 bytes"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 8usize)
+            .with_param("actual", 13usize)
+            .with_param("unit", "bytes"),
+        ))
+    );
+}
+
+#[test]
+fn create_group() {
+    let json = r#"{"name": "ab", "level": 7, "tags": [2, 1]}"#.as_bytes();
+
+    let opts = Opts {
+        lang: None,
+        groups: &["create"],
+    };
+
+    assert_eq!(
+        Account::from_json_with(json, &opts),
+        Err(from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("name"),
+                Vec::new(),
+                "the string value must be at least 3 bytes long"
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 3usize)
+            .with_param("actual", 2usize)
+            .with_param("unit", "bytes"),
+        ))
+    );
+
+    assert_eq!(
+        Account::from_json_stack_errs_with(json, &opts),
+        Err(Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("name"),
+                Vec::new(),
+                "the string value must be at least 3 bytes long"
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 3usize)
+            .with_param("actual", 2usize)
+            .with_param("unit", "bytes"),
+            ValidationErr::new(
+                From::from("level"),
+                Vec::new(),
+                "number must be less than or equal to 5"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 5usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 7usize),
+            ValidationErr::new(From::from("password"), Vec::new(), "password is required")
+                .with_kind(ErrKind::Required),
+        ]))
+    );
+
+    assert_eq!(
+        Account::from_json_with(r#"{"name": "abc", "level": 5, "tags": []}"#.as_bytes(), &opts),
+        Err(from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("password"),
+                Vec::new(),
+                "password is required"
+            )
+            .with_kind(ErrKind::Required),
+        ))
+    );
+
+    // the groups are only active during the call
+    assert!(Account::from_json(json).is_ok());
+}
+
+#[test]
+fn other_groups() {
+    let json = r#"{"name": "ab", "level": 7, "tags": [2, 1]}"#.as_bytes();
+
+    assert_eq!(
+        Account::from_json_stack_errs_with(
+            json,
+            &Opts {
+                lang: Some("en"),
+                groups: &["admin", "update"],
+            }
+        ),
+        Err(Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("level"),
+                Vec::new(),
+                "number must be less than or equal to 5"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 5usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 7usize),
+            ValidationErr::new(
+                From::from("tags"),
+                Vec::new(),
+                "array must be sorted in ascending order (element at index 1 is out of order)"
+            )
+            .with_kind(ErrKind::Sorted)
+            .with_param("index", 1usize),
+        ]))
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Team {
+    #[max_len(value = 2, groups(create))]
+    members: Vec<Account>,
+
+    owner: Account,
+}
+
+#[test]
+fn nested_groups() {
+    let json = r#"{
+        "members": [{"name": "ab", "level": 1, "tags": []}],
+        "owner": {"name": "abc", "level": 1, "tags": [], "password": "x"}
+    }"#
+    .as_bytes();
+
+    let opts = Opts {
+        lang: None,
+        groups: &["create"],
+    };
+
+    // the groups are seen by the nested structs and the vector elements
+    assert_eq!(
+        Team::from_json_stack_errs_with(json, &opts),
+        Err(Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("name"),
+                vec![From::from("members"), From::from(0)],
+                "the string value must be at least 3 bytes long"
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 3usize)
+            .with_param("actual", 2usize)
+            .with_param("unit", "bytes"),
+            ValidationErr::new(
+                From::from("password"),
+                vec![From::from("members"), From::from(0)],
+                "password is required"
+            )
+            .with_kind(ErrKind::Required),
+        ]))
+    );
+
+    let team = Team::from_json(json).unwrap();
+
+    assert_eq!(team.validate(), Ok(()));
+
+    assert_eq!(
+        team.validate_with(&opts),
+        Err(ValidationErr::new(
+            From::from("name"),
+            vec![From::from("members"), From::from(0)],
+            "the string value must be at least 3 bytes long"
+        )
+        .with_kind(ErrKind::MinLen)
+        .with_param("min", 3usize)
+        .with_param("actual", 2usize)
+        .with_param("unit", "bytes"))
+    );
+
+    assert_eq!(
+        team.owner.validate_stack_errs_with(&Opts {
+            lang: Some("en"),
+            groups: &["admin"],
+        }),
+        Ok(())
+    );
+}
+
+#[test]
+fn groups_are_held_by_the_current_thread() {
+    let json = r#"{"name": "ab", "level": 7, "tags": []}"#.as_bytes();
+
+    groups::with_active(&["create"], || {
+        assert!(Account::from_json(json).is_err());
+
+        // another thread does not see the groups of this one
+        let handle = std::thread::spawn(move || Account::from_json(json).is_ok());
+
+        assert!(handle.join().unwrap());
+    });
+}
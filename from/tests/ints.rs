@@ -21,7 +21,7 @@ macro_rules! ints {
         typ_str: $typ_str: expr,
     ) => {
         pub mod $typ {
-            use from::{from, FromJson, Null, OptionNull, Path, ValidationErr};
+            use from::{from, ErrKind, FromJson, Null, OptionNull, Path, ValidationErr};
 
             use super::{json_too_large, json_too_small};
 
@@ -88,38 +88,62 @@ macro_rules! ints {
             fn max_invalid() {
                 assert_eq!(
                     Max::from_json(r#"{"field": 5}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be less than or equal to 4",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than or equal to 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4 as $typ)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 5 as $typ),
                     )
                 );
 
                 assert_eq!(
                     Max::from_json_lang(r#"{"field": 5}"#.as_bytes(), "en").unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be less than or equal to 4",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than or equal to 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4 as $typ)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 5 as $typ),
                     )
                 );
 
                 assert_eq!(
                     Max::from_json_stack_errs(r#"{"field": 5}"#.as_bytes()).unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be less than or equal to 4",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than or equal to 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4 as $typ)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 5 as $typ),
+                    ])
                 );
 
                 assert_eq!(
                     Max::from_json_stack_errs_lang(r#"{"field": 5}"#.as_bytes(), "en").unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be less than or equal to 4",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than or equal to 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4 as $typ)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 5 as $typ),
+                    ])
                 );
             }
 
@@ -158,25 +182,49 @@ macro_rules! ints {
             fn max_overwrite_msg_invalid() {
                 assert_eq!(
                     MaxOverwriteMsg::from_json(r#"{"field": 5}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "too big",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "too big")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     MaxOverwriteMsg::from_json_lang(r#"{"field": 5}"#.as_bytes(), "ar")
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "كبير جدا",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "كبير جدا")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     MaxOverwriteMsg::from_json_stack_errs(r#"{"field": 5}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "too big",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "too big")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    ])
                 );
 
                 assert_eq!(
                     MaxOverwriteMsg::from_json_stack_errs_lang(r#"{"field": 5}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "too big",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "too big")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    ])
                 );
             }
 
@@ -221,19 +269,37 @@ macro_rules! ints {
             fn max_overwrite_msg_dflt_lang_invalid() {
                 assert_eq!(
                     MaxOverwriteMsgDfltLang::from_json(r#"{"field": 5}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "كبير جدا",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "كبير جدا")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     MaxOverwriteMsgDfltLang::from_json_lang(r#"{"field": 5}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "too big",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "too big")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     MaxOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": 5}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "كبير جدا",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "كبير جدا")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    ])
                 );
 
                 assert_eq!(
@@ -242,7 +308,13 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "too big",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "too big")
+                            .with_kind(ErrKind::Max)
+                            .with_param("max", 4 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    ])
                 );
             }
 
@@ -280,38 +352,62 @@ macro_rules! ints {
             fn min_invalid() {
                 assert_eq!(
                     Min::from_json(r#"{"field": 5}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be greater than or equal to 6",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be greater than or equal to 6",
+                        )
+                        .with_kind(ErrKind::Min)
+                        .with_param("min", 6 as $typ)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 5 as $typ),
                     )
                 );
 
                 assert_eq!(
                     Min::from_json_lang(r#"{"field": 5}"#.as_bytes(), "en").unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be greater than or equal to 6",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be greater than or equal to 6",
+                        )
+                        .with_kind(ErrKind::Min)
+                        .with_param("min", 6 as $typ)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 5 as $typ),
                     )
                 );
 
                 assert_eq!(
                     Min::from_json_stack_errs(r#"{"field": 5}"#.as_bytes()).unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be greater than or equal to 6",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be greater than or equal to 6",
+                        )
+                        .with_kind(ErrKind::Min)
+                        .with_param("min", 6 as $typ)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 5 as $typ),
+                    ])
                 );
 
                 assert_eq!(
                     Min::from_json_stack_errs_lang(r#"{"field": 5}"#.as_bytes(), "en").unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be greater than or equal to 6",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be greater than or equal to 6",
+                        )
+                        .with_kind(ErrKind::Min)
+                        .with_param("min", 6 as $typ)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 5 as $typ),
+                    ])
                 );
             }
 
@@ -350,25 +446,49 @@ macro_rules! ints {
             fn min_overwrite_msg_invalid() {
                 assert_eq!(
                     MinOverwriteMsg::from_json(r#"{"field": 5}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "too small",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "too small")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     MinOverwriteMsg::from_json_lang(r#"{"field": 5}"#.as_bytes(), "ar")
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "صغير جدا",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "صغير جدا")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     MinOverwriteMsg::from_json_stack_errs(r#"{"field": 5}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "too small",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "too small")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    ])
                 );
 
                 assert_eq!(
                     MinOverwriteMsg::from_json_stack_errs_lang(r#"{"field": 5}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "too small",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "too small")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    ])
                 );
             }
 
@@ -413,19 +533,37 @@ macro_rules! ints {
             fn min_overwrite_msg_dflt_lang_invalid() {
                 assert_eq!(
                     MinOverwriteMsgDfltLang::from_json(r#"{"field": 5}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "صغير جدا",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "صغير جدا")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     MinOverwriteMsgDfltLang::from_json_lang(r#"{"field": 5}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "too small",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "too small")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     MinOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": 5}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "صغير جدا",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "صغير جدا")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    ])
                 );
 
                 assert_eq!(
@@ -434,7 +572,13 @@ macro_rules! ints {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "صغير جدا",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "صغير جدا")
+                            .with_kind(ErrKind::Min)
+                            .with_param("min", 6 as $typ)
+                            .with_param("exclusive", false)
+                            .with_param("actual", 5 as $typ),
+                    ])
                 );
             }
 
@@ -472,39 +616,59 @@ macro_rules! ints {
             fn enum_invalid() {
                 assert_eq!(
                     Enum::from_json(r#"{"field": 6}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be one of: [5, 7]",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be one of: [5, 7]",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5 as $typ, 7 as $typ])
+                        .with_param("actual", 6 as $typ),
                     )
                 );
 
                 assert_eq!(
                     Enum::from_json_lang(r#"{"field": 6}"#.as_bytes(), "en").unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be one of: [5, 7]",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be one of: [5, 7]",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5 as $typ, 7 as $typ])
+                        .with_param("actual", 6 as $typ),
                     )
                 );
 
                 assert_eq!(
                     Enum::from_json_stack_errs(r#"{"field": 9}"#.as_bytes()).unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be one of: [5, 7]",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be one of: [5, 7]",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5 as $typ, 7 as $typ])
+                        .with_param("actual", 9 as $typ),
+                    ])
                 );
 
                 assert_eq!(
                     Enum::from_json_stack_errs_lang(r#"{"field": 8}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "number must be one of: [5, 7]",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be one of: [5, 7]",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5 as $typ, 7 as $typ])
+                        .with_param("actual", 8 as $typ),
+                    ])
                 );
             }
 
@@ -543,27 +707,42 @@ macro_rules! ints {
             fn enum_overwrite_msg_invalid() {
                 assert_eq!(
                     EnumOverwriteMsg::from_json(r#"{"field": 8}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "invalid number",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "invalid number",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5 as $typ, 7 as $typ])
+                        .with_param("actual", 8 as $typ),
                     )
                 );
 
                 assert_eq!(
                     EnumOverwriteMsg::from_json_lang(r#"{"field": 9}"#.as_bytes(), "ar")
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "عدد غير صالح",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "عدد غير صالح")
+                            .with_kind(ErrKind::Enum)
+                            .with_param("values", [5 as $typ, 7 as $typ])
+                            .with_param("actual", 9 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     EnumOverwriteMsg::from_json_stack_errs(r#"{"field": 15}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "invalid number",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "invalid number",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5 as $typ, 7 as $typ])
+                        .with_param("actual", 15 as $typ),
+                    ])
                 );
 
                 assert_eq!(
@@ -572,7 +751,12 @@ macro_rules! ints {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "عدد غير صالح",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "عدد غير صالح")
+                            .with_kind(ErrKind::Enum)
+                            .with_param("values", [5 as $typ, 7 as $typ])
+                            .with_param("actual", 20 as $typ),
+                    ])
                 );
             }
 
@@ -617,23 +801,38 @@ macro_rules! ints {
             fn enum_overwrite_msg_dflt_lang_invalid() {
                 assert_eq!(
                     EnumOverwriteMsgDfltLang::from_json(r#"{"field": 8}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "عدد غير صالح",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "عدد غير صالح")
+                            .with_kind(ErrKind::Enum)
+                            .with_param("values", [5 as $typ, 7 as $typ])
+                            .with_param("actual", 8 as $typ),
+                    )
                 );
 
                 assert_eq!(
                     EnumOverwriteMsgDfltLang::from_json_lang(r#"{"field": 75}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "invalid number",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "invalid number",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5 as $typ, 7 as $typ])
+                        .with_param("actual", 75 as $typ),
                     )
                 );
 
                 assert_eq!(
                     EnumOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": 53}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "عدد غير صالح",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "عدد غير صالح")
+                            .with_kind(ErrKind::Enum)
+                            .with_param("values", [5 as $typ, 7 as $typ])
+                            .with_param("actual", 53 as $typ),
+                    ])
                 );
 
                 assert_eq!(
@@ -642,12 +841,419 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "invalid number",
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "invalid number",
+                        )
+                        .with_kind(ErrKind::Enum)
+                        .with_param("values", [5 as $typ, 7 as $typ])
+                        .with_param("actual", 25 as $typ),
+                    ])
+                );
+            }
+
+            #[derive(Debug, PartialEq)]
+            #[from(json)]
+            struct MaxExclusive {
+                #[max(value = 4, exclusive)]
+                field: $typ,
+            }
+
+            #[test]
+            fn max_exclusive() {
+                assert_eq!(
+                    MaxExclusive::from_json("{\"field\": 3}".as_bytes()).unwrap(),
+                    MaxExclusive { field: 3 }
+                );
+
+                assert_eq!(
+                    MaxExclusive::from_json_lang(r#"{"field": 4}"#.as_bytes(), "en")
+                        .unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4 as $typ)
+                        .with_param("exclusive", true)
+                        .with_param("actual", 4 as $typ),
+                    )
+                );
+
+                assert_eq!(
+                    MaxExclusive::from_json_stack_errs(r#"{"field": 4}"#.as_bytes())
+                        .unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be less than 4",
+                        )
+                        .with_kind(ErrKind::Max)
+                        .with_param("max", 4 as $typ)
+                        .with_param("exclusive", true)
+                        .with_param("actual", 4 as $typ),
+                    ])
+                );
+            }
+
+            #[derive(Debug, PartialEq)]
+            #[from(json)]
+            struct Range {
+                #[range(min = 1, max = 100)]
+                field: $typ,
+            }
+
+            #[test]
+            fn range_valid() {
+                assert_eq!(
+                    Range::from_json("{\"field\": 1}".as_bytes()).unwrap(),
+                    Range { field: 1 }
+                );
+
+                assert_eq!(
+                    Range::from_json_lang("{\"field\": 100}".as_bytes(), "en").unwrap(),
+                    Range { field: 100 }
+                );
+
+                assert_eq!(
+                    Range::from_json_stack_errs("{\"field\": 50}".as_bytes()).unwrap(),
+                    Range { field: 50 }
+                );
+
+                assert_eq!(
+                    Range::from_json_stack_errs_lang("{\"field\": 99}".as_bytes(), "en")
+                        .unwrap(),
+                    Range { field: 99 }
+                );
+            }
+
+            #[test]
+            fn range_invalid() {
+                assert_eq!(
+                    Range::from_json(r#"{"field": 0}"#.as_bytes()).unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be between 1 and 100",
+                        )
+                        .with_kind(ErrKind::Range)
+                        .with_param("min", 1 as $typ)
+                        .with_param("max", 100 as $typ)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 0 as $typ),
+                    )
+                );
+
+                assert_eq!(
+                    Range::from_json_lang(r#"{"field": 101}"#.as_bytes(), "en").unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be between 1 and 100",
+                        )
+                        .with_kind(ErrKind::Range)
+                        .with_param("min", 1 as $typ)
+                        .with_param("max", 100 as $typ)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 101 as $typ),
+                    )
+                );
+
+                assert_eq!(
+                    Range::from_json_stack_errs(r#"{"field": 120}"#.as_bytes()).unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be between 1 and 100",
+                        )
+                        .with_kind(ErrKind::Range)
+                        .with_param("min", 1 as $typ)
+                        .with_param("max", 100 as $typ)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 120 as $typ),
+                    ])
+                );
+
+                assert_eq!(
+                    Range::from_json_stack_errs_lang(r#"{"field": 0}"#.as_bytes(), "en")
+                        .unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be between 1 and 100",
+                        )
+                        .with_kind(ErrKind::Range)
+                        .with_param("min", 1 as $typ)
+                        .with_param("max", 100 as $typ)
+                        .with_param("exclusive", false)
+                        .with_param("actual", 0 as $typ),
+                    ])
+                );
+            }
+
+            #[derive(Debug, PartialEq)]
+            #[from(json)]
+            struct RangeExclusiveOverwriteMsg {
+                #[range(
+                    min = 1,
+                    max = 100,
+                    exclusive,
+                    msgs{en="out of range", ar="خارج النطاق"},
+                )]
+                field: $typ,
+            }
+
+            #[test]
+            fn range_exclusive_overwrite_msg() {
+                assert_eq!(
+                    RangeExclusiveOverwriteMsg::from_json("{\"field\": 99}".as_bytes())
+                        .unwrap(),
+                    RangeExclusiveOverwriteMsg { field: 99 }
+                );
+
+                assert_eq!(
+                    RangeExclusiveOverwriteMsg::from_json_lang(
+                        r#"{"field": 100}"#.as_bytes(),
+                        "ar"
+                    )
+                    .unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "خارج النطاق")
+                            .with_kind(ErrKind::Range)
+                            .with_param("min", 1 as $typ)
+                            .with_param("max", 100 as $typ)
+                            .with_param("exclusive", true)
+                            .with_param("actual", 100 as $typ),
+                    )
+                );
+
+                assert_eq!(
+                    RangeExclusiveOverwriteMsg::from_json_stack_errs(
+                        r#"{"field": 1}"#.as_bytes()
+                    )
+                    .unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "out of range")
+                            .with_kind(ErrKind::Range)
+                            .with_param("min", 1 as $typ)
+                            .with_param("max", 100 as $typ)
+                            .with_param("exclusive", true)
+                            .with_param("actual", 1 as $typ),
+                    ])
+                );
+            }
+
+            #[derive(Debug, PartialEq)]
+            #[from(json)]
+            struct NotEnum {
+                #[not_enum(values = [0, 7])]
+                field: $typ,
+            }
+
+            #[test]
+            fn not_enum() {
+                assert_eq!(
+                    NotEnum::from_json("{\"field\": 5}".as_bytes()).unwrap(),
+                    NotEnum { field: 5 }
+                );
+
+                assert_eq!(
+                    NotEnum::from_json(r#"{"field": 7}"#.as_bytes()).unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number 7 is not allowed",
+                        )
+                        .with_kind(ErrKind::NotEnum)
+                        .with_param("values", [0 as $typ, 7 as $typ])
+                        .with_param("actual", 7 as $typ),
+                    )
+                );
+
+                assert_eq!(
+                    NotEnum::from_json_lang(r#"{"field": 0}"#.as_bytes(), "en").unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number 0 is not allowed",
+                        )
+                        .with_kind(ErrKind::NotEnum)
+                        .with_param("values", [0 as $typ, 7 as $typ])
+                        .with_param("actual", 0 as $typ),
+                    )
+                );
+
+                assert_eq!(
+                    NotEnum::from_json_stack_errs(r#"{"field": 7}"#.as_bytes()).unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number 7 is not allowed",
+                        )
+                        .with_kind(ErrKind::NotEnum)
+                        .with_param("values", [0 as $typ, 7 as $typ])
+                        .with_param("actual", 7 as $typ),
+                    ])
+                );
+
+                assert_eq!(
+                    NotEnum::from_json_stack_errs_lang(r#"{"field": 0}"#.as_bytes(), "en")
+                        .unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number 0 is not allowed",
+                        )
+                        .with_kind(ErrKind::NotEnum)
+                        .with_param("values", [0 as $typ, 7 as $typ])
+                        .with_param("actual", 0 as $typ),
+                    ])
+                );
+            }
+
+            #[derive(Debug, PartialEq)]
+            #[from(json)]
+            struct MultipleOf {
+                #[multiple_of(value = 5)]
+                field: $typ,
+            }
+
+            #[test]
+            fn multiple_of_valid() {
+                assert_eq!(
+                    MultipleOf::from_json("{\"field\": 0}".as_bytes()).unwrap(),
+                    MultipleOf { field: 0 }
+                );
+
+                assert_eq!(
+                    MultipleOf::from_json_lang("{\"field\": 10}".as_bytes(), "en").unwrap(),
+                    MultipleOf { field: 10 }
+                );
+
+                assert_eq!(
+                    MultipleOf::from_json_stack_errs("{\"field\": 25}".as_bytes()).unwrap(),
+                    MultipleOf { field: 25 }
+                );
+
+                assert_eq!(
+                    MultipleOf::from_json_stack_errs_lang("{\"field\": 120}".as_bytes(), "en")
+                        .unwrap(),
+                    MultipleOf { field: 120 }
+                );
+            }
+
+            #[test]
+            fn multiple_of_invalid() {
+                assert_eq!(
+                    MultipleOf::from_json(r#"{"field": 7}"#.as_bytes()).unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be a multiple of 5",
+                        )
+                        .with_kind(ErrKind::MultipleOf)
+                        .with_param("multiple_of", 5 as $typ)
+                        .with_param("actual", 7 as $typ),
+                    )
+                );
+
+                assert_eq!(
+                    MultipleOf::from_json_lang(r#"{"field": 12}"#.as_bytes(), "en").unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be a multiple of 5",
+                        )
+                        .with_kind(ErrKind::MultipleOf)
+                        .with_param("multiple_of", 5 as $typ)
+                        .with_param("actual", 12 as $typ),
+                    )
+                );
+
+                assert_eq!(
+                    MultipleOf::from_json_stack_errs(r#"{"field": 1}"#.as_bytes()).unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be a multiple of 5",
+                        )
+                        .with_kind(ErrKind::MultipleOf)
+                        .with_param("multiple_of", 5 as $typ)
+                        .with_param("actual", 1 as $typ),
+                    ])
+                );
+
+                assert_eq!(
+                    MultipleOf::from_json_stack_errs_lang(r#"{"field": 99}"#.as_bytes(), "en")
+                        .unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "number must be a multiple of 5",
+                        )
+                        .with_kind(ErrKind::MultipleOf)
+                        .with_param("multiple_of", 5 as $typ)
+                        .with_param("actual", 99 as $typ),
+                    ])
+                );
+            }
+
+            #[derive(Debug, PartialEq)]
+            #[from(json)]
+            struct MultipleOfOverwriteMsg {
+                #[multiple_of(value = 5, msgs{en="invalid quantity", ar="كمية غير صالحة"})]
+                field: $typ,
+            }
+
+            #[test]
+            fn multiple_of_overwrite_msg() {
+                assert_eq!(
+                    MultipleOfOverwriteMsg::from_json("{\"field\": 15}".as_bytes()).unwrap(),
+                    MultipleOfOverwriteMsg { field: 15 }
+                );
+
+                assert_eq!(
+                    MultipleOfOverwriteMsg::from_json_lang(r#"{"field": 9}"#.as_bytes(), "ar")
+                        .unwrap_err(),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "كمية غير صالحة")
+                            .with_kind(ErrKind::MultipleOf)
+                            .with_param("multiple_of", 5 as $typ)
+                            .with_param("actual", 9 as $typ),
                     )
                 );
+
+                assert_eq!(
+                    MultipleOfOverwriteMsg::from_json_stack_errs(r#"{"field": 9}"#.as_bytes())
+                        .unwrap_err(),
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "invalid quantity",
+                        )
+                        .with_kind(ErrKind::MultipleOf)
+                        .with_param("multiple_of", 5 as $typ)
+                        .with_param("actual", 9 as $typ),
+                    ])
+                );
             }
 
             #[derive(Debug, PartialEq)]
@@ -660,39 +1266,51 @@ macro_rules! ints {
             fn required() {
                 assert_eq!(
                     Required::from_json(r#"{"field1": 5}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "required field",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "required field",
+                        )
+                        .with_kind(ErrKind::Required),
                     )
                 );
 
                 assert_eq!(
                     Required::from_json_lang(r#"{"field2": 5}"#.as_bytes(), "en").unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "required field",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "required field",
+                        )
+                        .with_kind(ErrKind::Required),
                     )
                 );
 
                 assert_eq!(
                     Required::from_json_stack_errs(r#"{"field4": 5}"#.as_bytes()).unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "required field",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "required field",
+                        )
+                        .with_kind(ErrKind::Required),
+                    ])
                 );
 
                 assert_eq!(
                     Required::from_json_stack_errs_lang(r#"{"field7": 5}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "required field",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "required field",
+                        )
+                        .with_kind(ErrKind::Required),
+                    ])
                 );
             }
 
@@ -707,36 +1325,48 @@ macro_rules! ints {
             fn required_overwrite_msg() {
                 assert_eq!(
                     RequiredOverwriteMsg::from_json(r#"{"field7": 5}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "value is required",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "value is required",
+                        )
+                        .with_kind(ErrKind::Required),
                     )
                 );
 
                 assert_eq!(
                     RequiredOverwriteMsg::from_json_lang(r#"{"field9": 5}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "value is required",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "value is required",
+                        )
+                        .with_kind(ErrKind::Required),
                     )
                 );
 
                 assert_eq!(
                     RequiredOverwriteMsg::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "value is required",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "value is required",
+                        )
+                        .with_kind(ErrKind::Required),
+                    ])
                 );
 
                 assert_eq!(
                     RequiredOverwriteMsg::from_json_stack_errs_lang(r#"{  }"#.as_bytes(), "ar")
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                            .with_kind(ErrKind::Required),
+                    ])
                 );
             }
 
@@ -753,7 +1383,10 @@ macro_rules! ints {
                 assert_eq!(
                     RequiredOverwriteMsgDfltLang::from_json(r#"{"field7": 5}"#.as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                            .with_kind(ErrKind::Required),
+                    )
                 );
 
                 assert_eq!(
@@ -762,17 +1395,23 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "value is required",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "value is required",
+                        )
+                        .with_kind(ErrKind::Required),
                     )
                 );
 
                 assert_eq!(
                     RequiredOverwriteMsgDfltLang::from_json_stack_errs(r#"{}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                            .with_kind(ErrKind::Required),
+                    ])
                 );
 
                 assert_eq!(
@@ -781,7 +1420,10 @@ macro_rules! ints {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                            .with_kind(ErrKind::Required),
+                    ])
                 );
             }
 
@@ -800,10 +1442,14 @@ macro_rules! ints {
                 assert_eq!(
                     TooLarge::from_json(json_too_large($typ::MAX.to_string()).as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("number is too large to fit in '{}' type", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("number is too large to fit in '{}' type", $typ_str),
+                        )
+                        .with_kind(ErrKind::TooLarge)
+                        .with_param("type", $typ_str),
                     )
                 );
 
@@ -813,10 +1459,14 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("number is too large to fit in '{}' type", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("number is too large to fit in '{}' type", $typ_str),
+                        )
+                        .with_kind(ErrKind::TooLarge)
+                        .with_param("type", $typ_str),
                     )
                 );
 
@@ -825,11 +1475,15 @@ macro_rules! ints {
                         json_too_large($typ::MAX.to_string()).as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("number is too large to fit in '{}' type", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("number is too large to fit in '{}' type", $typ_str),
+                        )
+                        .with_kind(ErrKind::TooLarge)
+                        .with_param("type", $typ_str),
+                    ])
                 );
 
                 assert_eq!(
@@ -838,11 +1492,15 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("number is too large to fit in '{}' type", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("number is too large to fit in '{}' type", $typ_str),
+                        )
+                        .with_kind(ErrKind::TooLarge)
+                        .with_param("type", $typ_str),
+                    ])
                 );
             }
 
@@ -860,7 +1518,11 @@ macro_rules! ints {
                         json_too_large($typ::MAX.to_string()).as_bytes()
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TooLarge)
+                            .with_param("type", $typ_str),
+                    )
                 );
 
                 assert_eq!(
@@ -869,7 +1531,11 @@ macro_rules! ints {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TooLarge)
+                            .with_param("type", $typ_str),
+                    )
                 );
 
                 assert_eq!(
@@ -877,7 +1543,11 @@ macro_rules! ints {
                         json_too_large($typ::MAX.to_string()).as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TooLarge)
+                            .with_param("type", $typ_str),
+                    ])
                 );
 
                 assert_eq!(
@@ -886,7 +1556,11 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TooLarge)
+                            .with_param("type", $typ_str),
+                    ])
                 );
             }
 
@@ -905,7 +1579,11 @@ macro_rules! ints {
                         json_too_large($typ::MAX.to_string()).as_bytes()
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TooLarge)
+                            .with_param("type", $typ_str),
+                    )
                 );
 
                 assert_eq!(
@@ -914,7 +1592,11 @@ macro_rules! ints {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TooLarge)
+                            .with_param("type", $typ_str),
+                    )
                 );
 
                 assert_eq!(
@@ -922,7 +1604,11 @@ macro_rules! ints {
                         json_too_large($typ::MAX.to_string()).as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TooLarge)
+                            .with_param("type", $typ_str),
+                    ])
                 );
 
                 assert_eq!(
@@ -931,7 +1617,11 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TooLarge)
+                            .with_param("type", $typ_str),
+                    ])
                 );
             }
 
@@ -950,10 +1640,14 @@ macro_rules! ints {
                 assert_eq!(
                     TooSmall::from_json(json_too_small($typ::MIN.to_string()).as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("number is too small to fit in '{}' type", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("number is too small to fit in '{}' type", $typ_str),
+                        )
+                        .with_kind(ErrKind::TooSmall)
+                        .with_param("type", $typ_str),
                     )
                 );
 
@@ -963,10 +1657,14 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("number is too small to fit in '{}' type", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("number is too small to fit in '{}' type", $typ_str),
+                        )
+                        .with_kind(ErrKind::TooSmall)
+                        .with_param("type", $typ_str),
                     )
                 );
 
@@ -975,11 +1673,15 @@ macro_rules! ints {
                         json_too_small($typ::MIN.to_string()).as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("number is too small to fit in '{}' type", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("number is too small to fit in '{}' type", $typ_str),
+                        )
+                        .with_kind(ErrKind::TooSmall)
+                        .with_param("type", $typ_str),
+                    ])
                 );
 
                 assert_eq!(
@@ -988,11 +1690,15 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("number is too small to fit in '{}' type", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("number is too small to fit in '{}' type", $typ_str),
+                        )
+                        .with_kind(ErrKind::TooSmall)
+                        .with_param("type", $typ_str),
+                    ])
                 );
             }
 
@@ -1010,7 +1716,11 @@ macro_rules! ints {
                         json_too_small($typ::MIN.to_string()).as_bytes()
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TooSmall)
+                            .with_param("type", $typ_str),
+                    )
                 );
 
                 assert_eq!(
@@ -1019,7 +1729,11 @@ macro_rules! ints {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TooSmall)
+                            .with_param("type", $typ_str),
+                    )
                 );
 
                 assert_eq!(
@@ -1027,7 +1741,11 @@ macro_rules! ints {
                         json_too_small($typ::MIN.to_string()).as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TooSmall)
+                            .with_param("type", $typ_str),
+                    ])
                 );
 
                 assert_eq!(
@@ -1036,7 +1754,11 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TooSmall)
+                            .with_param("type", $typ_str),
+                    ])
                 );
             }
 
@@ -1055,7 +1777,11 @@ macro_rules! ints {
                         json_too_small($typ::MIN.to_string()).as_bytes()
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TooSmall)
+                            .with_param("type", $typ_str),
+                    )
                 );
 
                 assert_eq!(
@@ -1064,7 +1790,11 @@ macro_rules! ints {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TooSmall)
+                            .with_param("type", $typ_str),
+                    )
                 );
 
                 assert_eq!(
@@ -1072,7 +1802,11 @@ macro_rules! ints {
                         json_too_small($typ::MIN.to_string()).as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TooSmall)
+                            .with_param("type", $typ_str),
+                    ])
                 );
 
                 assert_eq!(
@@ -1081,7 +1815,11 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TooSmall)
+                            .with_param("type", $typ_str),
+                    ])
                 );
             }
 
@@ -1100,31 +1838,46 @@ macro_rules! ints {
             fn type_mismatch() {
                 assert_eq!(
                     TypeMismatch::from_json(r#"{"field": ""}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: string", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: string", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "string"),
                     )
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json_lang(r#"{"field": "as"}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: string", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: string", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "string"),
                     )
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json_stack_errs(r#"{"field": "25"}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: string", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: string", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "string"),
+                    ])
                 );
 
                 assert_eq!(
@@ -1133,59 +1886,89 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: string", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: string", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "string"),
+                    ])
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json(r#"{"field": true}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: boolean", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: boolean", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "boolean"),
                     )
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json_lang(r#"{"field": false}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: boolean", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: boolean", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "boolean"),
                     )
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json_stack_errs(r#"{"field": true}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: boolean", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: boolean", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "boolean"),
+                    ])
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json_stack_errs_lang(r#"{"field": true}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: boolean", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: boolean", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "boolean"),
+                    ])
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json(r#"{"field": {this will be ignored}}"#.as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: object", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: object", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "object"),
                     )
                 );
 
@@ -1195,10 +1978,15 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: object", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: object", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "object"),
                     )
                 );
 
@@ -1207,11 +1995,16 @@ macro_rules! ints {
                         r#"{"field": {because it does not care}}"#.as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: object", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: object", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "object"),
+                    ])
                 );
 
                 assert_eq!(
@@ -1220,20 +2013,30 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: object", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: object", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "object"),
+                    ])
                 );
 
                 assert_eq!(
                     TypeMismatch::from_json(r#"{"field": [this will be ignored]}"#.as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: array", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: array", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "array"),
                     )
                 );
 
@@ -1243,10 +2046,15 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: array", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: array", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "array"),
                     )
                 );
 
@@ -1255,11 +2063,16 @@ macro_rules! ints {
                         r#"{"field": [because it does not care]}"#.as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: array", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: array", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "array"),
+                    ])
                 );
 
                 assert_eq!(
@@ -1268,11 +2081,16 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: array", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: array", $typ_str),
+                        )
+                        .with_kind(ErrKind::TypeMismatch)
+                        .with_param("expected", $typ_str)
+                        .with_param("found", "array"),
+                    ])
                 );
             }
 
@@ -1288,19 +2106,34 @@ macro_rules! ints {
                 assert_eq!(
                     TypeMismatchOverwriteMsg::from_json(r#"{"field": false}"#.as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "boolean"),
+                    )
                 );
 
                 assert_eq!(
                     TypeMismatchOverwriteMsg::from_json_lang(r#"{"field": "25"}"#.as_bytes(), "ar")
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "string"),
+                    )
                 );
 
                 assert_eq!(
                     TypeMismatchOverwriteMsg::from_json_stack_errs(r#"{"field": {}}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "object"),
+                    ])
                 );
 
                 assert_eq!(
@@ -1309,7 +2142,12 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "array"),
+                    ])
                 );
             }
 
@@ -1326,7 +2164,12 @@ macro_rules! ints {
                 assert_eq!(
                     TypeMismatchOverwriteMsgDfltLang::from_json(r#"{"field": false}"#.as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "boolean"),
+                    )
                 );
 
                 assert_eq!(
@@ -1335,7 +2178,12 @@ macro_rules! ints {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "string"),
+                    )
                 );
 
                 assert_eq!(
@@ -1343,7 +2191,12 @@ macro_rules! ints {
                         r#"{"field": {}}"#.as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "لا يتناسب",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "لا يتناسب")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "object"),
+                    ])
                 );
 
                 assert_eq!(
@@ -1352,7 +2205,12 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(From::from("field"), Vec::new(), "cannot fit",)
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(From::from("field"), Vec::new(), "cannot fit")
+                            .with_kind(ErrKind::TypeMismatch)
+                            .with_param("expected", $typ_str)
+                            .with_param("found", "array"),
+                    ])
                 );
             }
 
@@ -1366,39 +2224,53 @@ macro_rules! ints {
             fn not_null() {
                 assert_eq!(
                     NotNull::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: null", $typ_str)
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: null", $typ_str)
+                        )
+                        .with_kind(ErrKind::NotNull),
                     )
                 );
 
+                // the built-in message is translated with 'locale-ar'
+                #[cfg(not(feature = "locale-ar"))]
                 assert_eq!(
                     NotNull::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar").unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: null", $typ_str),
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: null", $typ_str),
+                        )
+                        .with_kind(ErrKind::NotNull),
                     )
                 );
 
                 assert_eq!(
                     NotNull::from_json_stack_errs(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: null", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: null", $typ_str),
+                        )
+                        .with_kind(ErrKind::NotNull),
+                    ])
                 );
 
                 assert_eq!(
                     NotNull::from_json_stack_errs_lang(r#"{"field": null}"#.as_bytes(), "en")
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        format!("expected: {}, found: null", $typ_str),
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            &format!("expected: {}, found: null", $typ_str),
+                        )
+                        .with_kind(ErrKind::NotNull),
+                    ])
                 );
             }
 
@@ -1413,31 +2285,40 @@ macro_rules! ints {
             fn not_null_overwrite_msg() {
                 assert_eq!(
                     NotNullOverwriteMsg::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "null is not allowed",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "null is not allowed",
+                        )
+                        .with_kind(ErrKind::NotNull),
                     )
                 );
 
                 assert_eq!(
                     NotNullOverwriteMsg::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar")
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "القيمة الخالية غير مسموحة",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "القيمة الخالية غير مسموحة",
+                        )
+                        .with_kind(ErrKind::NotNull),
                     )
                 );
 
                 assert_eq!(
                     NotNullOverwriteMsg::from_json_stack_errs(r#"{"field": null}"#.as_bytes())
                         .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "null is not allowed",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "null is not allowed",
+                        )
+                        .with_kind(ErrKind::NotNull),
+                    ])
                 );
 
                 assert_eq!(
@@ -1446,11 +2327,14 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "null is not allowed",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "null is not allowed",
+                        )
+                        .with_kind(ErrKind::NotNull),
+                    ])
                 );
             }
 
@@ -1467,10 +2351,13 @@ macro_rules! ints {
                 assert_eq!(
                     NotNullOverwriteMsgDfltLang::from_json(r#"{"field": null}"#.as_bytes())
                         .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "القيمة الخالية غير مسموحة",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "القيمة الخالية غير مسموحة",
+                        )
+                        .with_kind(ErrKind::NotNull),
                     )
                 );
 
@@ -1480,10 +2367,13 @@ macro_rules! ints {
                         "ar"
                     )
                     .unwrap_err(),
-                    from::Err::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "القيمة الخالية غير مسموحة",
+                    from::Err::ValidationErr(
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "القيمة الخالية غير مسموحة",
+                        )
+                        .with_kind(ErrKind::NotNull),
                     )
                 );
 
@@ -1492,11 +2382,14 @@ macro_rules! ints {
                         r#"{"field": null}"#.as_bytes()
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "القيمة الخالية غير مسموحة",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "القيمة الخالية غير مسموحة",
+                        )
+                        .with_kind(ErrKind::NotNull),
+                    ])
                 );
 
                 assert_eq!(
@@ -1505,11 +2398,14 @@ macro_rules! ints {
                         "en"
                     )
                     .unwrap_err(),
-                    from::Errs::new_validation_err(
-                        From::from("field"),
-                        Vec::new(),
-                        "null is not allowed",
-                    )
+                    from::Errs::ValidationErrs(vec![
+                        ValidationErr::new(
+                            From::from("field"),
+                            Vec::new(),
+                            "null is not allowed",
+                        )
+                        .with_kind(ErrKind::NotNull),
+                    ])
                 );
             }
 
@@ -1802,16 +2698,20 @@ macro_rules! ints {
                 assert_eq!(
                     MultiErr::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
                     from::Errs::ValidationErrs(vec![
-                        ValidationErr::new(From::from("field1"), Vec::new(), "required field"),
-                        ValidationErr::new(From::from("field2"), Vec::new(), "required field"),
+                        ValidationErr::new(From::from("field1"), Vec::new(), "required field")
+                            .with_kind(ErrKind::Required),
+                        ValidationErr::new(From::from("field2"), Vec::new(), "required field")
+                            .with_kind(ErrKind::Required),
                     ])
                 );
 
                 assert_eq!(
                     MultiErr::from_json_stack_errs_lang(r#"{}"#.as_bytes(), "en").unwrap_err(),
                     from::Errs::ValidationErrs(vec![
-                        ValidationErr::new(From::from("field1"), Vec::new(), "required field"),
-                        ValidationErr::new(From::from("field2"), Vec::new(), "required field"),
+                        ValidationErr::new(From::from("field1"), Vec::new(), "required field")
+                            .with_kind(ErrKind::Required),
+                        ValidationErr::new(From::from("field2"), Vec::new(), "required field")
+                            .with_kind(ErrKind::Required),
                     ])
                 );
             }
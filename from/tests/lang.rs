@@ -0,0 +1,99 @@
+use from::{from, lang, ErrKind, Errs, FromJson, ValidationErr};
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Comment {
+    #[max_len(value = 3, msgs{en="too long", ar="طويل جدا", fr="trop long"})]
+    body: String,
+
+    #[max(value = 5, msgs{ar="كبير جدا"})]
+    rating: u8,
+}
+
+fn msgs(errs: Result<Comment, Errs>) -> Vec<String> {
+    match errs {
+        Err(Errs::ValidationErrs(errs)) => errs.into_iter().map(|e: ValidationErr| e.msg).collect(),
+        res => panic!("expected validation errors, found: {:?}", res),
+    }
+}
+
+#[test]
+fn fallback() {
+    let json = r#"{"body": "abcd", "rating": 6}"#.as_bytes();
+
+    // 'ar-EG' => 'ar'
+    assert_eq!(
+        msgs(Comment::from_json_stack_errs_lang(json, "ar-EG")),
+        vec!["طويل جدا", "كبير جدا"]
+    );
+
+    assert_eq!(
+        msgs(Comment::from_json_stack_errs_lang(json, "AR_eg")),
+        vec!["طويل جدا", "كبير جدا"]
+    );
+
+    // 'de-DE' => 'de' => 'en' (the default language)
+    assert_eq!(
+        msgs(Comment::from_json_stack_errs_lang(json, "de-DE")),
+        vec!["too long", "number must be less than or equal to 5"]
+    );
+}
+
+#[test]
+fn preferences() {
+    let json = r#"{"body": "abcd", "rating": 6}"#.as_bytes();
+
+    // each message is looked up in order of preference
+    assert_eq!(
+        msgs(Comment::from_json_stack_errs_langs(json, &["fr-CA", "ar"])),
+        vec!["trop long", "كبير جدا"]
+    );
+
+    // the default language is a candidate as well
+    assert_eq!(
+        msgs(Comment::from_json_stack_errs_langs(json, &["de", "en", "ar"])),
+        vec!["too long", "number must be less than or equal to 5"]
+    );
+
+    assert_eq!(
+        Comment::from_json_langs(json, &["fr", "ar"]),
+        Err(from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("body"),
+                Vec::new(),
+                "trop long"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 3usize)
+            .with_param("actual", 4usize)
+            .with_param("unit", "bytes"),
+        ))
+    );
+
+    let header = "de-CH, ar;q=0.5, fr;q=0.8";
+
+    assert_eq!(lang::accept_language(header), vec!["de-CH", "fr", "ar"]);
+
+    assert_eq!(
+        msgs(Comment::from_json_stack_errs_langs(json, &lang::accept_language(header))),
+        vec!["trop long", "كبير جدا"]
+    );
+}
+
+#[test]
+fn fallbacks() {
+    assert_eq!(lang::fallbacks("zh-Hant-TW"), vec!["zh-hant-tw", "zh-hant", "zh"]);
+    assert_eq!(lang::fallbacks("en-x-custom, fr"), vec!["en-x-custom", "en", "fr"]);
+    assert_eq!(lang::fallbacks("ar-EG,ar,*"), vec!["ar-eg", "ar"]);
+
+    assert_eq!(lang::accept_language("en;q=0, *, fr;q=x"), Vec::<&str>::new());
+    assert_eq!(lang::accept_language("en;q=0.5, fr, ar;q=0.5"), vec!["fr", "en", "ar"]);
+}
+
+#[test]
+fn select() {
+    assert_eq!(lang::select("ar-EG", &["en", "ar"]), Some("ar"));
+    assert_eq!(lang::select("fr-CA,AR", &["en", "ar"]), Some("ar"));
+    assert_eq!(lang::select("zh-Hant-TW", &["zh", "zh_Hant"]), Some("zh_Hant"));
+    assert_eq!(lang::select("de,*", &["en", "ar"]), None);
+}
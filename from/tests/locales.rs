@@ -0,0 +1,110 @@
+use from::{from, ErrKind, Errs, FromJson, ValidationErr};
+
+#[derive(Debug, PartialEq)]
+#[from(json, locales = "tests/locales")]
+struct Article {
+    #[max_len(value = 5, unit = "chars")]
+    title: String,
+
+    #[max_len(value = 3, unit = "chars", msgs{ar="وسم غير صالح"})]
+    tag: String,
+
+    #[max_len(value = 3, unit = "chars")]
+    author: String,
+
+    likes: u32,
+}
+
+fn errs(json: &str, lang: &str) -> Vec<ValidationErr> {
+    match Article::from_json_stack_errs_lang(json.as_bytes(), lang) {
+        Err(Errs::ValidationErrs(errs)) => errs,
+        res => panic!("expected validation errors, found: {:?}", res),
+    }
+}
+
+#[test]
+fn locales() {
+    let json = r#"{"title": "abcdef", "tag": "abcd", "author": "abcd", "likes": "x"}"#;
+
+    assert_eq!(
+        errs(json, "ar"),
+        vec![
+            // the messages of the field take precedence
+            ValidationErr::new(From::from("title"), Vec::new(), "يجب ألا يزيد العنوان عن 5 حرفا")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 5usize)
+                .with_param("actual", 6usize)
+                .with_param("unit", "chars"),
+            // then the custom messages
+            ValidationErr::new(From::from("tag"), Vec::new(), "وسم غير صالح")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 3usize)
+                .with_param("actual", 4usize)
+                .with_param("unit", "chars"),
+            ValidationErr::new(From::from("author"), Vec::new(), "يجب ألا يزيد author عن 3 حرفا")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 3usize)
+                .with_param("actual", 4usize)
+                .with_param("unit", "chars"),
+            ValidationErr::new(From::from("likes"), Vec::new(), "النوع المتوقع u32 وليس string")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "u32")
+                .with_param("found", "string"),
+        ]
+    );
+
+    // the default language
+    assert_eq!(
+        errs(json, "en")[..3],
+        [
+            ValidationErr::new(
+                From::from("title"),
+                Vec::new(),
+                "the title must be at most 5 characters"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 5usize)
+            .with_param("actual", 6usize)
+            .with_param("unit", "chars"),
+            ValidationErr::new(
+                From::from("tag"),
+                Vec::new(),
+                "the string value must be no longer than 3 characters"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 3usize)
+            .with_param("actual", 4usize)
+            .with_param("unit", "chars"),
+            ValidationErr::new(
+                From::from("author"),
+                Vec::new(),
+                "the string value must be no longer than 3 characters"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 3usize)
+            .with_param("actual", 4usize)
+            .with_param("unit", "chars"),
+        ]
+    );
+
+    assert_eq!(
+        Article::from_json_lang(r#"{"title": "a", "tag": "a", "author": "a"}"#.as_bytes(), "ar"),
+        Err(from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("likes"),
+                Vec::new(),
+                "likes مطلوب"
+            )
+            .with_kind(ErrKind::Required),
+        ))
+    );
+
+    // the escaped surrogate pairs are decoded
+    assert_eq!(
+        Article::from_json_lang(r#"{"title": "a", "tag": "a", "author": "a"}"#.as_bytes(), "en"),
+        Err(from::Err::ValidationErr(
+            ValidationErr::new(From::from("likes"), Vec::new(), "\u{1F6AB} likes is required")
+                .with_kind(ErrKind::Required),
+        ))
+    );
+}
@@ -0,0 +1,135 @@
+use from::{from, ErrKind, Errs, FromJson, Messages, ValidationErr};
+
+struct Catalog;
+
+impl Messages for Catalog {
+    fn template(kind: ErrKind, lang: &str) -> Option<&'static str> {
+        match (kind, lang) {
+            (ErrKind::Required, "en") => Some("{field} is required"),
+            (ErrKind::Required, "ar") => Some("{field} مطلوب"),
+            (ErrKind::MaxLen, "ar") => Some("يجب ألا يزيد {field} عن {max} حرفا"),
+            (ErrKind::Max, "ar") => Some("يجب ألا يزيد {field} عن {max}"),
+            (ErrKind::TypeMismatch, "ar") => Some("النوع المتوقع {expected} وليس {found}"),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json, messages = Catalog)]
+struct User {
+    #[max_len(value = 3, unit = "chars")]
+    name: String,
+
+    #[max(value = 120, msgs{ar="العمر غير صالح"})]
+    age: u8,
+
+    scores: Vec<u8>,
+}
+
+fn errs(json: &str, lang: &str) -> Vec<ValidationErr> {
+    match User::from_json_stack_errs_lang(json.as_bytes(), lang) {
+        Err(Errs::ValidationErrs(errs)) => errs,
+        res => panic!("expected validation errors, found: {:?}", res),
+    }
+}
+
+#[test]
+fn catalog() {
+    let json = r#"{"name": "abcd", "age": 130, "scores": [1, "x"]}"#;
+
+    assert_eq!(
+        errs(json, "ar"),
+        vec![
+            ValidationErr::new(From::from("name"), Vec::new(), "يجب ألا يزيد name عن 3 حرفا")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 3usize)
+                .with_param("actual", 4usize)
+                .with_param("unit", "chars"),
+            // the custom messages take precedence
+            ValidationErr::new(From::from("age"), Vec::new(), "العمر غير صالح")
+                .with_kind(ErrKind::Max)
+                .with_param("max", 120usize)
+                .with_param("exclusive", false)
+                .with_param("actual", 130usize),
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("scores")],
+                "النوع المتوقع u8 وليس string"
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "u8")
+            .with_param("found", "string"),
+        ]
+    );
+
+    // the built-in messages are used when the catalog has none
+    assert_eq!(
+        errs(json, "it"),
+        vec![
+            ValidationErr::new(
+                From::from("name"),
+                Vec::new(),
+                "the string value must be no longer than 3 characters"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 3usize)
+            .with_param("actual", 4usize)
+            .with_param("unit", "chars"),
+            ValidationErr::new(
+                From::from("age"),
+                Vec::new(),
+                "number must be less than or equal to 120"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 120usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 130usize),
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("scores")],
+                "expected: u8, found: string"
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "u8")
+            .with_param("found", "string"),
+        ]
+    );
+
+    // the default language is used by the methods without 'lang'
+    assert_eq!(
+        User::from_json(r#"{"name": "abc", "age": 20}"#.as_bytes()),
+        Err(from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("scores"),
+                Vec::new(),
+                "scores is required"
+            )
+            .with_kind(ErrKind::Required),
+        ))
+    );
+
+    assert_eq!(
+        User::from_json_lang(r#"{"name": "abc", "age": 20}"#.as_bytes(), "ar"),
+        Err(from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("scores"),
+                Vec::new(),
+                "scores مطلوب"
+            )
+            .with_kind(ErrKind::Required),
+        ))
+    );
+}
+
+#[test]
+fn render() {
+    let err = ValidationErr::new(From::from("name"), Vec::new(), "")
+        .with_param("max", 3)
+        .with_param("values", ["a", "b"]);
+
+    assert_eq!(
+        err.render("{field}: {max} {{max}} {values} {unknown} }"),
+        "name: 3 {max} a, b {unknown} }"
+    );
+}
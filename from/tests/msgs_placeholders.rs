@@ -0,0 +1,102 @@
+use from::{from, ErrKind, Errs, FromJson, ValidationErr};
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Product {
+    #[max_len(value = 5, msgs{en="{field} must be at most {max} bytes ({actual} given)", ar="يجب ألا يزيد {field} عن {value} بايت"})]
+    name: String,
+
+    #[max(value = 1.5, msgs{en="{field}: {actual} > {max}"})]
+    weight: f64,
+
+    #[r#enum(values = [1, 2], msgs{en="{{{field}}} must be one of: {values}"})]
+    #[type_mismatch_msgs{en="expected {expected} but found {found}"}]
+    #[not_null_msgs{en="{field} must not be null"}]
+    kind: u8,
+
+    #[elem(min(value = 10, msgs{en="element {field} must be at least {min}, found {actual}"}))]
+    prices: Vec<u32>,
+}
+
+fn errs(json: &str, lang: &str) -> Vec<ValidationErr> {
+    match Product::from_json_stack_errs_lang(json.as_bytes(), lang) {
+        Err(Errs::ValidationErrs(errs)) => errs,
+        res => panic!("expected validation errors, found: {:?}", res),
+    }
+}
+
+#[test]
+fn placeholders() {
+    let json = r#"{"name": "abcdefg", "weight": 2.25, "kind": 3, "prices": [10, 5]}"#;
+
+    assert_eq!(
+        errs(json, "en"),
+        vec![
+            ValidationErr::new(
+                From::from("name"),
+                Vec::new(),
+                "name must be at most 5 bytes (7 given)"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 5usize)
+            .with_param("actual", 7usize)
+            .with_param("unit", "bytes"),
+            ValidationErr::new(From::from("weight"), Vec::new(), "weight: 2.25 > 1.5")
+                .with_kind(ErrKind::Max)
+                .with_param("max", 1.5)
+                .with_param("exclusive", false)
+                .with_param("actual", 2.25),
+            ValidationErr::new(From::from("kind"), Vec::new(), "{kind} must be one of: 1, 2")
+                .with_kind(ErrKind::Enum)
+                .with_param("values", [1usize, 2usize])
+                .with_param("actual", 3usize),
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("prices")],
+                "element 1 must be at least 10, found 5"
+            )
+            .with_kind(ErrKind::Min)
+            .with_param("min", 10usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 5usize),
+        ]
+    );
+
+    assert_eq!(
+        errs(json, "ar")[0],
+        ValidationErr::new(From::from("name"), Vec::new(), "يجب ألا يزيد name عن 5 بايت")
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 5usize)
+            .with_param("actual", 7usize)
+            .with_param("unit", "bytes")
+    );
+}
+
+#[test]
+fn parsing_errs_placeholders() {
+    assert_eq!(
+        Product::from_json(r#"{"name": "a", "weight": 1, "kind": "x", "prices": []}"#.as_bytes()),
+        Err(from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("kind"),
+                Vec::new(),
+                "expected u8 but found string"
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "u8")
+            .with_param("found", "string"),
+        ))
+    );
+
+    assert_eq!(
+        Product::from_json(r#"{"name": "a", "weight": 1, "kind": null, "prices": []}"#.as_bytes()),
+        Err(from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("kind"),
+                Vec::new(),
+                "kind must not be null"
+            )
+            .with_kind(ErrKind::NotNull),
+        ))
+    );
+}
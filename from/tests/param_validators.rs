@@ -0,0 +1,371 @@
+use from::{from, lang, Errs, FromJson, ParamValidator, Path, Validate, ValidationErr, Validator};
+
+struct MinAge(u8);
+
+impl ParamValidator<u8> for MinAge {
+    fn none(&self, val: &u8, path: &Path) -> Result<(), ValidationErr> {
+        if *val < self.0 {
+            return Err(ValidationErr::new(
+                From::from("age"),
+                path.clone(),
+                &format!("must be at least {}", self.0),
+            ));
+        };
+
+        Ok(())
+    }
+
+    fn lang(&self, val: &u8, path: &Path, lang: &str) -> Result<(), ValidationErr> {
+        match (self.none(val, path), lang::select(lang, &["ar"])) {
+            (Err(mut e), Some("ar")) => {
+                e.msg = format!("يجب ألا يقل عن {}", self.0);
+                Err(e)
+            }
+
+            (res, _) => res,
+        }
+    }
+}
+
+struct InList(&'static [&'static str]);
+
+impl ParamValidator<String> for InList {
+    fn none(&self, val: &String, path: &Path) -> Result<(), ValidationErr> {
+        if !self.0.contains(&val.as_str()) {
+            return Err(ValidationErr::new(
+                From::from("color"),
+                path.clone(),
+                "unknown color",
+            ));
+        };
+
+        Ok(())
+    }
+}
+
+struct NotBlack;
+
+impl Validator<String> for NotBlack {
+    fn none(val: &String, path: &Path) -> Result<(), ValidationErr> {
+        if val == "black" {
+            return Err(ValidationErr::new(
+                From::from("color"),
+                path.clone(),
+                "black is not allowed",
+            ));
+        };
+
+        Ok(())
+    }
+}
+
+enum Rule {
+    Max(i32),
+}
+
+impl ParamValidator<i32> for Rule {
+    fn none(&self, val: &i32, path: &Path) -> Result<(), ValidationErr> {
+        match self {
+            Rule::Max(max) if val > max => Err(ValidationErr::new(
+                From::from("score"),
+                path.clone(),
+                "score is too high",
+            )),
+
+            _ => Ok(()),
+        }
+    }
+}
+
+struct Len {
+    max: usize,
+}
+
+impl Len {
+    fn new(max: usize) -> Self {
+        Self { max }
+    }
+}
+
+impl ParamValidator<Vec<u8>> for Len {
+    fn none(&self, val: &Vec<u8>, path: &Path) -> Result<(), ValidationErr> {
+        if val.len() > self.max {
+            return Err(ValidationErr::new(
+                From::from("items"),
+                path.clone(),
+                "too many items",
+            ));
+        };
+
+        Ok(())
+    }
+}
+
+struct ItemsBelow {
+    age_factor: usize,
+}
+
+impl ParamValidator<Profile> for ItemsBelow {
+    fn none(&self, val: &Profile, path: &Path) -> Result<(), ValidationErr> {
+        if val.items.len() * self.age_factor > val.age as usize {
+            return Err(ValidationErr::new(
+                From::from("items"),
+                path.clone(),
+                "too many items for this age",
+            ));
+        };
+
+        Ok(())
+    }
+}
+
+static ALLOWED: [&str; 3] = ["red", "blue", "black"];
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+#[validators(ItemsBelow { age_factor: 10 })]
+struct Profile {
+    #[validators(MinAge(18))]
+    age: u8,
+
+    #[validators(NotBlack, InList(&ALLOWED))]
+    color: String,
+
+    #[validators(Rule::Max(10))]
+    score: Option<i32>,
+
+    #[validators(Len::new(2))]
+    items: Vec<u8>,
+}
+
+#[test]
+fn valid() {
+    let profile = Profile::from_json(
+        r#"{"age": 20, "color": "red", "score": 10, "items": [1, 2]}"#.as_bytes(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        profile,
+        Profile {
+            age: 20,
+            color: String::from("red"),
+            score: Some(10),
+            items: vec![1, 2],
+        }
+    );
+
+    assert_eq!(profile.validate(), Ok(()));
+}
+
+#[test]
+fn invalid() {
+    assert_eq!(
+        Profile::from_json_lang(
+            r#"{"age": 17, "color": "red", "items": []}"#.as_bytes(),
+            "ar-EG"
+        ),
+        Err(from::Err::new_validation_err(
+            From::from("age"),
+            Vec::new(),
+            "يجب ألا يقل عن 18"
+        ))
+    );
+
+    assert_eq!(
+        Profile::from_json_stack_errs(
+            r#"{"age": 17, "color": "black", "score": 11, "items": [1, 2, 3]}"#.as_bytes()
+        ),
+        Err(Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("age"), Vec::new(), "must be at least 18"),
+            ValidationErr::new(From::from("color"), Vec::new(), "black is not allowed"),
+            ValidationErr::new(From::from("score"), Vec::new(), "score is too high"),
+            ValidationErr::new(From::from("items"), Vec::new(), "too many items"),
+        ]))
+    );
+
+    assert_eq!(
+        Profile::from_json(r#"{"age": 19, "color": "green", "items": []}"#.as_bytes()),
+        Err(from::Err::new_validation_err(
+            From::from("color"),
+            Vec::new(),
+            "unknown color"
+        ))
+    );
+
+    // struct-level parameterised validator
+    assert_eq!(
+        Profile::from_json(r#"{"age": 19, "color": "red", "items": [1, 2]}"#.as_bytes()),
+        Err(from::Err::new_validation_err(
+            From::from("items"),
+            Vec::new(),
+            "too many items for this age"
+        ))
+    );
+
+    let mut profile = Profile {
+        age: 30,
+        color: String::from("blue"),
+        score: Some(11),
+        items: Vec::new(),
+    };
+
+    assert_eq!(
+        profile.validate(),
+        Err(ValidationErr::new(
+            From::from("score"),
+            Vec::new(),
+            "score is too high"
+        ))
+    );
+
+    profile.score = None;
+    profile.age = 5;
+
+    assert_eq!(
+        profile.validate_stack_errs(),
+        Err(vec![ValidationErr::new(
+            From::from("age"),
+            Vec::new(),
+            "must be at least 18"
+        )])
+    );
+}
+
+struct Prefix(String);
+
+impl ParamValidator<String> for Prefix {
+    fn none(&self, val: &String, path: &Path) -> Result<(), ValidationErr> {
+        if !val.starts_with(&self.0) {
+            return Err(ValidationErr::new(
+                From::from("code"),
+                path.clone(),
+                &format!("must start with '{}'", self.0),
+            ));
+        };
+
+        Ok(())
+    }
+}
+
+struct OneOf<T>(Vec<T>);
+
+impl<T: PartialEq> ParamValidator<T> for OneOf<T> {
+    fn none(&self, val: &T, path: &Path) -> Result<(), ValidationErr> {
+        if !self.0.contains(val) {
+            return Err(ValidationErr::new(
+                From::from("level"),
+                path.clone(),
+                "unknown level",
+            ));
+        };
+
+        Ok(())
+    }
+}
+
+// non-constant arguments are evaluated on every call
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Ticket {
+    #[validators(Prefix(String::from("TK-")))]
+    code: String,
+
+    #[validators(OneOf(vec![1, 2, 3]))]
+    level: u8,
+}
+
+#[test]
+fn evaluated_per_call() {
+    assert_eq!(
+        Ticket::from_json(r#"{"code": "TK-1", "level": 2}"#.as_bytes()),
+        Ok(Ticket {
+            code: String::from("TK-1"),
+            level: 2,
+        })
+    );
+
+    assert_eq!(
+        Ticket::from_json_stack_errs(r#"{"code": "AB-1", "level": 4}"#.as_bytes()),
+        Err(Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("code"), Vec::new(), "must start with 'TK-'"),
+            ValidationErr::new(From::from("level"), Vec::new(), "unknown level"),
+        ]))
+    );
+}
+
+struct MinVal<T>(T);
+
+impl<T: PartialOrd + std::fmt::Display> ParamValidator<T> for MinVal<T> {
+    fn none(&self, val: &T, path: &Path) -> Result<(), ValidationErr> {
+        if *val < self.0 {
+            return Err(ValidationErr::new(
+                From::from("value"),
+                path.clone(),
+                &format!("must be at least {}", self.0),
+            ));
+        };
+
+        Ok(())
+    }
+}
+
+// not 'Sync' ('*const' is not)
+struct Step(std::marker::PhantomData<*const u8>, u32);
+
+impl ParamValidator<u32> for Step {
+    fn none(&self, val: &u32, path: &Path) -> Result<(), ValidationErr> {
+        if val % self.1 != 0 {
+            return Err(ValidationErr::new(
+                From::from("stock"),
+                path.clone(),
+                &format!("must be a multiple of {}", self.1),
+            ));
+        };
+
+        Ok(())
+    }
+}
+
+// the type of the instance is inferred
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Stock {
+    #[validators(MinVal(5u32), Step(std::marker::PhantomData, 5))]
+    stock: u32,
+
+    #[validators(MinVal::<f64>(0.5))]
+    ratio: f64,
+}
+
+#[test]
+fn generic_and_not_sync() {
+    assert_eq!(
+        Stock::from_json(r#"{"stock": 10, "ratio": 0.5}"#.as_bytes()),
+        Ok(Stock {
+            stock: 10,
+            ratio: 0.5,
+        })
+    );
+
+    assert_eq!(
+        Stock::from_json_stack_errs(r#"{"stock": 3, "ratio": 0.25}"#.as_bytes()),
+        Err(Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("value"), Vec::new(), "must be at least 5"),
+            ValidationErr::new(From::from("value"), Vec::new(), "must be at least 0.5"),
+        ]))
+    );
+
+    assert_eq!(
+        Stock {
+            stock: 7,
+            ratio: 1.0,
+        }
+        .validate(),
+        Err(ValidationErr::new(
+            From::from("stock"),
+            Vec::new(),
+            "must be a multiple of 5"
+        ))
+    );
+}
@@ -0,0 +1,283 @@
+use from::{from, ErrKind, Errs, FromJson, Null, OptionNull, ValidationErr};
+
+// the derives must follow '#[from]' to be applied to the patch
+#[from(json, patch = UpdatePerson)]
+#[derive(Debug, Clone, PartialEq)]
+struct Person {
+    #[min_len(value = 3)]
+    name: String,
+
+    #[max(value = 120)]
+    age: Option<u8>,
+
+    nickname: Null<String>,
+
+    bio: OptionNull<String>,
+
+    #[default = 0]
+    score: u32,
+
+    tags: Vec<String>,
+}
+
+fn person() -> Person {
+    Person {
+        name: String::from("Alice"),
+        age: Some(30),
+        nickname: Null::Some(String::from("Al")),
+        bio: OptionNull::Some(String::from("hi")),
+        score: 10,
+        tags: vec![String::from("a")],
+    }
+}
+
+#[test]
+fn empty_patch() {
+    let mut target = person();
+
+    UpdatePerson::from_json(b"{}").unwrap().apply_to(&mut target);
+
+    assert_eq!(target, person());
+}
+
+#[test]
+fn replace_and_clear() {
+    let mut target = person();
+
+    UpdatePerson::from_json(
+        r#"{
+            "name": "Bob",
+            "age": null,
+            "nickname": null,
+            "bio": null,
+            "score": 20
+        }"#
+        .as_bytes(),
+    )
+    .unwrap()
+    .apply_to(&mut target);
+
+    assert_eq!(
+        target,
+        Person {
+            name: String::from("Bob"),
+            age: None,
+            nickname: Null::Null,
+            bio: OptionNull::Null,
+            score: 20,
+            tags: vec![String::from("a")],
+        }
+    );
+
+    UpdatePerson::from_json(
+        r#"{"age": 31, "nickname": "B", "bio": "hello", "tags": []}"#.as_bytes(),
+    )
+    .unwrap()
+    .apply_to(&mut target);
+
+    assert_eq!(
+        target,
+        Person {
+            name: String::from("Bob"),
+            age: Some(31),
+            nickname: Null::Some(String::from("B")),
+            bio: OptionNull::Some(String::from("hello")),
+            score: 20,
+            tags: Vec::new(),
+        }
+    );
+}
+
+#[test]
+fn invalid_patch() {
+    assert_eq!(
+        UpdatePerson::from_json_stack_errs(
+            r#"{"name": null, "age": 121, "score": null}"#.as_bytes()
+        )
+        .err(),
+        Some(Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("name"), Vec::new(), "expected: string, found: null")
+                .with_kind(ErrKind::NotNull),
+            ValidationErr::new(
+                From::from("age"),
+                Vec::new(),
+                "number must be less than or equal to 120"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 120usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 121usize),
+            ValidationErr::new(From::from("score"), Vec::new(), "expected: u32, found: null")
+                .with_kind(ErrKind::NotNull),
+        ]))
+    );
+
+    assert_eq!(
+        UpdatePerson::from_json(r#"{"name": "Al"}"#.as_bytes()).err(),
+        Some(from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("name"),
+                Vec::new(),
+                "the string value must be at least 3 bytes long"
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 3usize)
+            .with_param("actual", 2usize)
+            .with_param("unit", "bytes"),
+        ))
+    );
+}
+
+#[test]
+fn explicit_null() {
+    let patch = UpdatePerson::from_json(r#"{"age": null, "bio": null}"#.as_bytes()).unwrap();
+
+    // null is kept apart from a missing field
+    assert_eq!(
+        patch.clone(),
+        UpdatePerson {
+            name: OptionNull::None,
+            age: OptionNull::Null,
+            nickname: OptionNull::None,
+            bio: OptionNull::Null,
+            score: OptionNull::None,
+            tags: OptionNull::None,
+        }
+    );
+
+    let mut target = person();
+    patch.apply_to(&mut target);
+
+    assert_eq!(
+        target,
+        Person {
+            age: None,
+            bio: OptionNull::Null,
+            ..person()
+        }
+    );
+}
+
+#[from(json, patch = UpdateAddress)]
+#[derive(Debug, Clone, PartialEq)]
+struct Address {
+    #[min_len(value = 2)]
+    city: String,
+
+    zip: Option<String>,
+}
+
+#[from(json, patch = UpdateProfile)]
+#[derive(Debug, Clone, PartialEq)]
+struct Profile {
+    #[patch = UpdateAddress]
+    home: Address,
+
+    #[patch = UpdateAddress]
+    work: Option<Address>,
+}
+
+fn address(city: &str, zip: Option<&str>) -> Address {
+    Address {
+        city: String::from(city),
+        zip: zip.map(String::from),
+    }
+}
+
+#[test]
+fn nested_patch() {
+    let patch = UpdateProfile::from_json(r#"{"home": {"city": "Giza"}}"#.as_bytes()).unwrap();
+
+    assert_eq!(
+        patch,
+        UpdateProfile {
+            home: OptionNull::Some(UpdateAddress {
+                city: OptionNull::Some(String::from("Giza")),
+                zip: OptionNull::None,
+            }),
+            work: OptionNull::None,
+        }
+    );
+
+    let mut target = Profile {
+        home: address("Cairo", Some("11511")),
+        work: None,
+    };
+
+    // the missing fields of the nested value are kept
+    patch.apply_to(&mut target);
+
+    assert_eq!(
+        target,
+        Profile {
+            home: address("Giza", Some("11511")),
+            work: None,
+        }
+    );
+
+    // no value to merge into and the patch misses 'city'
+    UpdateProfile::from_json(r#"{"work": {"zip": "21500"}}"#.as_bytes())
+        .unwrap()
+        .apply_to(&mut target);
+
+    assert_eq!(target.work, None);
+
+    UpdateProfile::from_json(r#"{"work": {"city": "Alexandria"}}"#.as_bytes())
+        .unwrap()
+        .apply_to(&mut target);
+
+    assert_eq!(target.work, Some(address("Alexandria", None)));
+
+    UpdateProfile::from_json(r#"{"home": {"zip": null}, "work": {"zip": "21500"}}"#.as_bytes())
+        .unwrap()
+        .apply_to(&mut target);
+
+    assert_eq!(
+        target,
+        Profile {
+            home: address("Giza", None),
+            work: Some(address("Alexandria", Some("21500"))),
+        }
+    );
+
+    UpdateProfile::from_json(r#"{"work": null}"#.as_bytes())
+        .unwrap()
+        .apply_to(&mut target);
+
+    assert_eq!(target.work, None);
+}
+
+#[test]
+fn invalid_nested_patch() {
+    assert_eq!(
+        UpdateProfile::from_json_stack_errs(
+            r#"{"home": null, "work": {"city": "A"}}"#.as_bytes()
+        )
+        .err(),
+        Some(Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("home"), Vec::new(), "expected: object, found: null")
+                .with_kind(ErrKind::NotNull),
+            ValidationErr::new(
+                From::from("city"),
+                vec![From::from("work")],
+                "the string value must be at least 2 bytes long"
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 2usize)
+            .with_param("actual", 1usize)
+            .with_param("unit", "bytes"),
+        ]))
+    );
+
+    assert_eq!(
+        UpdateProfile::from_json(r#"{"home": {"city": null}}"#.as_bytes()).err(),
+        Some(from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("city"),
+                vec![From::from("home")],
+                "expected: string, found: null"
+            )
+            .with_kind(ErrKind::NotNull),
+        ))
+    );
+}
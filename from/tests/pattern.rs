@@ -0,0 +1,181 @@
+#![cfg(feature = "regex")]
+
+use from::{from, ErrKind, FromJson, ValidationErr};
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Pattern {
+    #[trim]
+    #[pattern = "^[a-z0-9]+(-[a-z0-9]+)*$"]
+    field: String,
+}
+
+#[test]
+fn pattern_valid() {
+    assert_eq!(
+        Pattern::from_json(r#"{"field": " hello-world "}"#.as_bytes()).unwrap(),
+        Pattern {
+            field: String::from("hello-world"),
+        }
+    );
+
+    assert_eq!(
+        Pattern::from_json_lang(r#"{"field": "hello-world"}"#.as_bytes(), "en").unwrap(),
+        Pattern {
+            field: String::from("hello-world"),
+        }
+    );
+
+    assert_eq!(
+        Pattern::from_json_stack_errs(r#"{"field": "hello-world"}"#.as_bytes()).unwrap(),
+        Pattern {
+            field: String::from("hello-world"),
+        }
+    );
+
+    assert_eq!(
+        Pattern::from_json_stack_errs_lang(r#"{"field": "hello-world"}"#.as_bytes(), "en")
+            .unwrap(),
+        Pattern {
+            field: String::from("hello-world"),
+        }
+    );
+}
+
+#[test]
+fn pattern_invalid() {
+    assert_eq!(
+        Pattern::from_json(r#"{"field": "Hello World"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must match the pattern: ^[a-z0-9]+(-[a-z0-9]+)*$"
+            )
+            .with_kind(ErrKind::Pattern)
+            .with_param("pattern", "^[a-z0-9]+(-[a-z0-9]+)*$"),
+        )
+    );
+
+    assert_eq!(
+        Pattern::from_json_lang(r#"{"field": "Hello World"}"#.as_bytes(), "en").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must match the pattern: ^[a-z0-9]+(-[a-z0-9]+)*$"
+            )
+            .with_kind(ErrKind::Pattern)
+            .with_param("pattern", "^[a-z0-9]+(-[a-z0-9]+)*$"),
+        )
+    );
+
+    assert_eq!(
+        Pattern::from_json_stack_errs(r#"{"field": "Hello World"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must match the pattern: ^[a-z0-9]+(-[a-z0-9]+)*$"
+            )
+            .with_kind(ErrKind::Pattern)
+            .with_param("pattern", "^[a-z0-9]+(-[a-z0-9]+)*$"),
+        ])
+    );
+
+    assert_eq!(
+        Pattern::from_json_stack_errs_lang(r#"{"field": "Hello World"}"#.as_bytes(), "en")
+            .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must match the pattern: ^[a-z0-9]+(-[a-z0-9]+)*$"
+            )
+            .with_kind(ErrKind::Pattern)
+            .with_param("pattern", "^[a-z0-9]+(-[a-z0-9]+)*$"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct PatternOverwriteMsg {
+    #[pattern(value = "^\\+[0-9]{8,15}$", msgs{en="invalid phone number", ar="رقم هاتف غير صالح"})]
+    field: String,
+}
+
+#[test]
+fn pattern_overwrite_msg() {
+    assert_eq!(
+        PatternOverwriteMsg::from_json(r#"{"field": "0912"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid phone number")
+                .with_kind(ErrKind::Pattern)
+                .with_param("pattern", "^\\+[0-9]{8,15}$"),
+        )
+    );
+
+    assert_eq!(
+        PatternOverwriteMsg::from_json_lang(r#"{"field": "0912"}"#.as_bytes(), "ar").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "رقم هاتف غير صالح")
+                .with_kind(ErrKind::Pattern)
+                .with_param("pattern", "^\\+[0-9]{8,15}$"),
+        )
+    );
+
+    assert_eq!(
+        PatternOverwriteMsg::from_json_stack_errs(r#"{"field": "0912"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid phone number")
+                .with_kind(ErrKind::Pattern)
+                .with_param("pattern", "^\\+[0-9]{8,15}$"),
+        ])
+    );
+
+    assert_eq!(
+        PatternOverwriteMsg::from_json_stack_errs_lang(r#"{"field": "0912"}"#.as_bytes(), "ar")
+            .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "رقم هاتف غير صالح")
+                .with_kind(ErrKind::Pattern)
+                .with_param("pattern", "^\\+[0-9]{8,15}$"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct PatternElem {
+    #[elem(pattern = "^[A-Z]{3}-[0-9]{4}$")]
+    codes: Vec<String>,
+    #[pattern = "^[A-Z]{3}-[0-9]{4}$"]
+    main_code: String,
+}
+
+#[test]
+fn pattern_elem() {
+    assert_eq!(
+        PatternElem::from_json(r#"{"codes": ["ABC-1234", "AB-1"], "main_code": "XYZ-0000"}"#.as_bytes())
+            .unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("codes")],
+                "value must match the pattern: ^[A-Z]{3}-[0-9]{4}$"
+            )
+            .with_kind(ErrKind::Pattern)
+            .with_param("pattern", "^[A-Z]{3}-[0-9]{4}$"),
+        )
+    );
+
+    assert_eq!(
+        PatternElem::from_json(r#"{"codes": ["ABC-1234"], "main_code": "XYZ-0000"}"#.as_bytes())
+            .unwrap(),
+        PatternElem {
+            codes: vec![String::from("ABC-1234")],
+            main_code: String::from("XYZ-0000"),
+        }
+    );
+}
@@ -1,4 +1,4 @@
-use from::{from, FromJson, Null, OptionNull, Path, ValidationErr};
+use from::{from, ErrKind, FromJson, Null, OptionNull, Path, ValidationErr};
 
 #[derive(Debug, PartialEq)]
 #[from(json)]
@@ -202,6 +202,95 @@ fn sanitize_xss() {
     );
 }
 
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Canonical {
+    #[strip_control_chars]
+    #[collapse_whitespace]
+    #[lowercase]
+    #[r#enum(values = ["john doe", "jane doe"])]
+    field: String,
+}
+
+#[test]
+fn canonical() {
+    assert_eq!(
+        Canonical::from_json("{\"field\": \"  John \\t\\u0007 DOE \\n\"}".as_bytes()).unwrap(),
+        Canonical {
+            field: String::from("john doe"),
+        }
+    );
+
+    assert_eq!(
+        Canonical::from_json_lang("{\"field\": \" JANE   Doe\"}".as_bytes(), "ar").unwrap(),
+        Canonical {
+            field: String::from("jane doe"),
+        }
+    );
+
+    assert_eq!(
+        Canonical::from_json_stack_errs("{\"field\": \"JOHN\\u0000DOE\"}".as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must be one of: [\"john doe\", \"jane doe\"]",
+            )
+            .with_kind(ErrKind::Enum)
+            .with_param("values", ["john doe", "jane doe"])
+            .with_param("actual", "johndoe"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct SanitizersOrder {
+    #[uppercase]
+    #[sanitize_xss]
+    #[trim]
+    first: String,
+
+    #[collapse_whitespace]
+    #[uppercase]
+    #[max_len(value = 5)]
+    second: String,
+}
+
+#[test]
+fn sanitizers_order() {
+    assert_eq!(
+        SanitizersOrder::from_json(
+            "{\"first\": \" <b>x</b> \", \"second\": \" a  b c \"}".as_bytes()
+        )
+        .unwrap(),
+        SanitizersOrder {
+            first: String::from("&lt;B&gt;X&lt;/B&gt;"),
+            second: String::from("A B C"),
+        }
+    );
+}
+
+#[cfg(feature = "normalize")]
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Normalize {
+    #[normalize = "nfkc"]
+    #[max_len(value = 4)]
+    field: String,
+}
+
+#[cfg(feature = "normalize")]
+#[test]
+fn normalize() {
+    assert_eq!(
+        Normalize::from_json("{\"field\": \"\\u0041\\u030a\\ufb01\"}".as_bytes()).unwrap(),
+        Normalize {
+            field: String::from("\u{c5}fi"),
+        }
+    );
+}
+
 #[derive(Debug, PartialEq)]
 #[from(json)]
 struct MaxLen {
@@ -244,38 +333,62 @@ fn max_len_valid() {
 fn max_len_invalid() {
     assert_eq!(
         MaxLen::from_json(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "the string value must be no longer than 4 bytes",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be no longer than 4 bytes",
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 4usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "bytes"),
         )
     );
 
     assert_eq!(
         MaxLen::from_json_lang(r#"{"field": "value"}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "the string value must be no longer than 4 bytes",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be no longer than 4 bytes",
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 4usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "bytes"),
         )
     );
 
     assert_eq!(
         MaxLen::from_json_stack_errs(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "the string value must be no longer than 4 bytes",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be no longer than 4 bytes",
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 4usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "bytes"),
+        ])
     );
 
     assert_eq!(
         MaxLen::from_json_stack_errs_lang(r#"{"field": "value"}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "the string value must be no longer than 4 bytes",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be no longer than 4 bytes",
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 4usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "bytes"),
+        ])
     );
 }
 
@@ -322,23 +435,47 @@ fn max_len_overwrite_msg_valid() {
 fn max_len_overwrite_msg_invalid() {
     assert_eq!(
         MaxLenOverwriteMsg::from_json(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "too long",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "too long")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 4usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        )
     );
 
     assert_eq!(
         MaxLenOverwriteMsg::from_json_lang(r#"{"field": "value"}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "طويل جدا",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "طويل جدا")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 4usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        )
     );
 
     assert_eq!(
         MaxLenOverwriteMsg::from_json_stack_errs(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "too long",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "too long")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 4usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        ])
     );
 
     assert_eq!(
         MaxLenOverwriteMsg::from_json_stack_errs_lang(r#"{"field": "value"}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "too long",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "too long")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 4usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        ])
     );
 }
 
@@ -391,19 +528,37 @@ fn max_len_overwrite_msg_dflt_lang_valid() {
 fn max_len_overwrite_msg_dflt_lang_invalid() {
     assert_eq!(
         MaxLenOverwriteMsgDfltLang::from_json(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "طويل جدا",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "طويل جدا")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 4usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        )
     );
 
     assert_eq!(
         MaxLenOverwriteMsgDfltLang::from_json_lang(r#"{"field": "value"}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "too long",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "too long")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 4usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        )
     );
 
     assert_eq!(
         MaxLenOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": "value"}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "طويل جدا",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "طويل جدا")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 4usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        ])
     );
 
     assert_eq!(
@@ -412,7 +567,13 @@ fn max_len_overwrite_msg_dflt_lang_invalid() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "too long",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "too long")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 4usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        ])
     );
 }
 
@@ -458,38 +619,62 @@ fn min_len_valid() {
 fn min_len_invalid() {
     assert_eq!(
         MinLen::from_json(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "the string value must be at least 6 bytes long",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be at least 6 bytes long",
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 6usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "bytes"),
         )
     );
 
     assert_eq!(
         MinLen::from_json_lang(r#"{"field": "value"}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "the string value must be at least 6 bytes long",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be at least 6 bytes long",
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 6usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "bytes"),
         )
     );
 
     assert_eq!(
         MinLen::from_json_stack_errs(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "the string value must be at least 6 bytes long",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be at least 6 bytes long",
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 6usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "bytes"),
+        ])
     );
 
     assert_eq!(
         MinLen::from_json_stack_errs_lang(r#"{"field": "value"}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "the string value must be at least 6 bytes long",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be at least 6 bytes long",
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 6usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "bytes"),
+        ])
     );
 }
 
@@ -536,23 +721,47 @@ fn min_len_overwrite_msg_valid() {
 fn min_len_overwrite_msg_invalid() {
     assert_eq!(
         MinLenOverwriteMsg::from_json(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "too short",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "too short")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        )
     );
 
     assert_eq!(
         MinLenOverwriteMsg::from_json_lang(r#"{"field": "value"}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "قصير جدا",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "قصير جدا")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        )
     );
 
     assert_eq!(
         MinLenOverwriteMsg::from_json_stack_errs(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "too short",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "too short")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        ])
     );
 
     assert_eq!(
         MinLenOverwriteMsg::from_json_stack_errs_lang(r#"{"field": "value"}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "too short",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "too short")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        ])
     );
 }
 
@@ -605,19 +814,37 @@ fn min_len_overwrite_msg_dflt_lang_valid() {
 fn min_len_overwrite_msg_dflt_lang_invalid() {
     assert_eq!(
         MinLenOverwriteMsgDfltLang::from_json(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "قصير جدا",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "قصير جدا")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        )
     );
 
     assert_eq!(
         MinLenOverwriteMsgDfltLang::from_json_lang(r#"{"field": "value"}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "too short",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "too short")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        )
     );
 
     assert_eq!(
         MinLenOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": "value"}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "قصير جدا",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "قصير جدا")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        ])
     );
 
     assert_eq!(
@@ -626,7 +853,13 @@ fn min_len_overwrite_msg_dflt_lang_invalid() {
             "ar"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "قصير جدا",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "قصير جدا")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        ])
     );
 }
 
@@ -672,38 +905,62 @@ fn len_valid() {
 fn len_invalid() {
     assert_eq!(
         Len::from_json(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "string must be 6 bytes long",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "string must be 6 bytes long",
+            )
+            .with_kind(ErrKind::Len)
+            .with_param("len", 6usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "bytes"),
         )
     );
 
     assert_eq!(
         Len::from_json_lang(r#"{"field": "value"}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "string must be 6 bytes long",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "string must be 6 bytes long",
+            )
+            .with_kind(ErrKind::Len)
+            .with_param("len", 6usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "bytes"),
         )
     );
 
     assert_eq!(
         Len::from_json_stack_errs(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "string must be 6 bytes long",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "string must be 6 bytes long",
+            )
+            .with_kind(ErrKind::Len)
+            .with_param("len", 6usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "bytes"),
+        ])
     );
 
     assert_eq!(
         Len::from_json_stack_errs_lang(r#"{"field": "value"}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "string must be 6 bytes long",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "string must be 6 bytes long",
+            )
+            .with_kind(ErrKind::Len)
+            .with_param("len", 6usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "bytes"),
+        ])
     );
 }
 
@@ -750,23 +1007,47 @@ fn len_overwrite_msg_valid() {
 fn len_overwrite_msg_invalid() {
     assert_eq!(
         LenOverwriteMsg::from_json(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "invalid length",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid length")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        )
     );
 
     assert_eq!(
         LenOverwriteMsg::from_json_lang(r#"{"field": "value"}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "طول غير صالح",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "طول غير صالح")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        )
     );
 
     assert_eq!(
         LenOverwriteMsg::from_json_stack_errs(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "invalid length",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid length")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        ])
     );
 
     assert_eq!(
         LenOverwriteMsg::from_json_stack_errs_lang(r#"{"field": "value"}"#.as_bytes(), "ar")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "طول غير صالح",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "طول غير صالح")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        ])
     );
 }
 
@@ -819,19 +1100,37 @@ fn len_overwrite_msg_dflt_lang_valid() {
 fn len_overwrite_msg_dflt_lang_invalid() {
     assert_eq!(
         LenOverwriteMsgDfltLang::from_json(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "طول غير صالح",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "طول غير صالح")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        )
     );
 
     assert_eq!(
         LenOverwriteMsgDfltLang::from_json_lang(r#"{"field": "value"}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "invalid length",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid length")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        )
     );
 
     assert_eq!(
         LenOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": "value"}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "طول غير صالح",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "طول غير صالح")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        ])
     );
 
     assert_eq!(
@@ -840,286 +1139,1209 @@ fn len_overwrite_msg_dflt_lang_invalid() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "invalid length",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid length")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 6usize)
+                .with_param("actual", 5usize)
+                .with_param("unit", "bytes"),
+        ])
     );
 }
 
 #[derive(Debug, PartialEq)]
 #[from(json)]
-struct Enum {
-    #[r#enum(values = ["value1", "value2"])]
+struct MaxLenChars {
+    #[max_len(value = 4, unit = "chars")]
     field: String,
 }
 
 #[test]
-fn enum_valid() {
+fn max_len_chars_valid() {
     assert_eq!(
-        Enum::from_json("{\"field\": \"value1\"}".as_bytes()).unwrap(),
-        Enum {
-            field: String::from("value1"),
+        MaxLenChars::from_json(r#"{"field": "مرحب"}"#.as_bytes()).unwrap(),
+        MaxLenChars {
+            field: String::from("مرحب"),
         }
     );
 
     assert_eq!(
-        Enum::from_json_lang("{\"field\": \"value2\"}".as_bytes(), "en").unwrap(),
-        Enum {
-            field: String::from("value2"),
+        MaxLenChars::from_json_lang(r#"{"field": "مرحب"}"#.as_bytes(), "en").unwrap(),
+        MaxLenChars {
+            field: String::from("مرحب"),
         }
     );
 
     assert_eq!(
-        Enum::from_json_stack_errs("{\"field\": \"value2\"}".as_bytes()).unwrap(),
-        Enum {
-            field: String::from("value2"),
+        MaxLenChars::from_json_stack_errs(r#"{"field": "مرحب"}"#.as_bytes()).unwrap(),
+        MaxLenChars {
+            field: String::from("مرحب"),
         }
     );
 
     assert_eq!(
-        Enum::from_json_stack_errs_lang("{\"field\": \"value1\"}".as_bytes(), "en").unwrap(),
-        Enum {
-            field: String::from("value1"),
+        MaxLenChars::from_json_stack_errs_lang(r#"{"field": "مرحب"}"#.as_bytes(), "en").unwrap(),
+        MaxLenChars {
+            field: String::from("مرحب"),
         }
     );
 }
 
 #[test]
-fn enum_invalid() {
-    assert_eq!(
-        Enum::from_json(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "value must be one of: [\"value1\", \"value2\"]",
+fn max_len_chars_invalid() {
+    assert_eq!(
+        MaxLenChars::from_json(r#"{"field": "مرحبا"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be no longer than 4 characters",
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 4usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "chars"),
         )
     );
 
     assert_eq!(
-        Enum::from_json_lang(r#"{"field": "value"}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "value must be one of: [\"value1\", \"value2\"]",
+        MaxLenChars::from_json_lang(r#"{"field": "مرحبا"}"#.as_bytes(), "en").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be no longer than 4 characters",
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 4usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "chars"),
         )
     );
 
     assert_eq!(
-        Enum::from_json_stack_errs(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "value must be one of: [\"value1\", \"value2\"]",
-        )
+        MaxLenChars::from_json_stack_errs(r#"{"field": "مرحبا"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be no longer than 4 characters",
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 4usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "chars"),
+        ])
     );
 
     assert_eq!(
-        Enum::from_json_stack_errs_lang(r#"{"field": "value"}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "value must be one of: [\"value1\", \"value2\"]",
-        )
+        MaxLenChars::from_json_stack_errs_lang(r#"{"field": "مرحبا"}"#.as_bytes(), "en")
+            .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be no longer than 4 characters",
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 4usize)
+            .with_param("actual", 5usize)
+            .with_param("unit", "chars"),
+        ])
     );
 }
 
 #[derive(Debug, PartialEq)]
 #[from(json)]
-struct EnumOverwriteMsg {
-    #[r#enum(values = ["value1", "value2"], msgs{en="invalid value", ar="قيمة غير صالحة"})]
+struct MinLenUtf16 {
+    #[min_len(value = 3, unit = "utf16")]
     field: String,
 }
 
 #[test]
-fn enum_overwrite_msg_valid() {
+fn min_len_utf16_valid() {
     assert_eq!(
-        EnumOverwriteMsg::from_json("{\"field\": \"value2\"}".as_bytes()).unwrap(),
-        EnumOverwriteMsg {
-            field: String::from("value2"),
+        MinLenUtf16::from_json(r#"{"field": "😀a"}"#.as_bytes()).unwrap(),
+        MinLenUtf16 {
+            field: String::from("😀a"),
         }
     );
 
     assert_eq!(
-        EnumOverwriteMsg::from_json_lang("{\"field\": \"value1\"}".as_bytes(), "en").unwrap(),
-        EnumOverwriteMsg {
-            field: String::from("value1"),
+        MinLenUtf16::from_json_lang(r#"{"field": "😀a"}"#.as_bytes(), "en").unwrap(),
+        MinLenUtf16 {
+            field: String::from("😀a"),
         }
     );
 
     assert_eq!(
-        EnumOverwriteMsg::from_json_stack_errs("{\"field\": \"value2\"}".as_bytes()).unwrap(),
-        EnumOverwriteMsg {
-            field: String::from("value2"),
+        MinLenUtf16::from_json_stack_errs(r#"{"field": "😀a"}"#.as_bytes()).unwrap(),
+        MinLenUtf16 {
+            field: String::from("😀a"),
         }
     );
 
     assert_eq!(
-        EnumOverwriteMsg::from_json_stack_errs_lang("{\"field\": \"value1\"}".as_bytes(), "en")
-            .unwrap(),
-        EnumOverwriteMsg {
-            field: String::from("value1"),
+        MinLenUtf16::from_json_stack_errs_lang(r#"{"field": "😀a"}"#.as_bytes(), "en").unwrap(),
+        MinLenUtf16 {
+            field: String::from("😀a"),
         }
     );
 }
 
 #[test]
-fn enum_overwrite_msg_invalid() {
+fn min_len_utf16_invalid() {
+    assert_eq!(
+        MinLenUtf16::from_json(r#"{"field": "😀"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be at least 3 UTF-16 code units long",
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 3usize)
+            .with_param("actual", 2usize)
+            .with_param("unit", "utf16"),
+        )
+    );
+
     assert_eq!(
-        EnumOverwriteMsg::from_json(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "invalid value",)
+        MinLenUtf16::from_json_lang(r#"{"field": "😀"}"#.as_bytes(), "en").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be at least 3 UTF-16 code units long",
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 3usize)
+            .with_param("actual", 2usize)
+            .with_param("unit", "utf16"),
+        )
     );
 
     assert_eq!(
-        EnumOverwriteMsg::from_json_lang(r#"{"field": "value"}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "قيمة غير صالحة",)
+        MinLenUtf16::from_json_stack_errs(r#"{"field": "😀"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be at least 3 UTF-16 code units long",
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 3usize)
+            .with_param("actual", 2usize)
+            .with_param("unit", "utf16"),
+        ])
     );
 
     assert_eq!(
-        EnumOverwriteMsg::from_json_stack_errs(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "invalid value",)
+        MinLenUtf16::from_json_stack_errs_lang(r#"{"field": "😀"}"#.as_bytes(), "en").unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "the string value must be at least 3 UTF-16 code units long",
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 3usize)
+            .with_param("actual", 2usize)
+            .with_param("unit", "utf16"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct LenChars {
+    #[len(value = 1, unit = "chars")]
+    field: String,
+}
+
+#[test]
+fn len_chars() {
+    assert_eq!(
+        LenChars::from_json(r#"{"field": "ض"}"#.as_bytes()).unwrap(),
+        LenChars {
+            field: String::from("ض"),
+        }
     );
 
     assert_eq!(
-        EnumOverwriteMsg::from_json_stack_errs_lang(r#"{"field": "value"}"#.as_bytes(), "ar")
-            .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "قيمة غير صالحة",)
+        LenChars::from_json(r#"{"field": "ab"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "string must be 1 character long",
+            )
+            .with_kind(ErrKind::Len)
+            .with_param("len", 1usize)
+            .with_param("actual", 2usize)
+            .with_param("unit", "chars"),
+        )
+    );
+
+    assert_eq!(
+        LenChars::from_json_lang(r#"{"field": "ab"}"#.as_bytes(), "en").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "string must be 1 character long",
+            )
+            .with_kind(ErrKind::Len)
+            .with_param("len", 1usize)
+            .with_param("actual", 2usize)
+            .with_param("unit", "chars"),
+        )
+    );
+
+    assert_eq!(
+        LenChars::from_json_stack_errs(r#"{"field": "ab"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "string must be 1 character long",
+            )
+            .with_kind(ErrKind::Len)
+            .with_param("len", 1usize)
+            .with_param("actual", 2usize)
+            .with_param("unit", "chars"),
+        ])
+    );
+
+    assert_eq!(
+        LenChars::from_json_stack_errs_lang(r#"{"field": "ab"}"#.as_bytes(), "en").unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "string must be 1 character long",
+            )
+            .with_kind(ErrKind::Len)
+            .with_param("len", 1usize)
+            .with_param("actual", 2usize)
+            .with_param("unit", "chars"),
+        ])
     );
 }
 
 #[derive(Debug, PartialEq)]
 #[from(json)]
-#[dflt_lang = "ar"]
-struct EnumOverwriteMsgDfltLang {
-    #[r#enum(values = ["value1", "value2"], msgs{en="invalid value", ar="قيمة غير صالحة"})]
+struct StartsWith {
+    #[starts_with(value = "https://")]
     field: String,
 }
 
 #[test]
-fn enum_overwrite_msg_dflt_lang_valid() {
+fn starts_with_valid() {
     assert_eq!(
-        EnumOverwriteMsgDfltLang::from_json("{\"field\": \"value1\"}".as_bytes()).unwrap(),
-        EnumOverwriteMsgDfltLang {
-            field: String::from("value1"),
+        StartsWith::from_json(r#"{"field": "https://example.com"}"#.as_bytes()).unwrap(),
+        StartsWith {
+            field: String::from("https://example.com"),
         }
     );
 
     assert_eq!(
-        EnumOverwriteMsgDfltLang::from_json_lang("{\"field\": \"value2\"}".as_bytes(), "en")
-            .unwrap(),
-        EnumOverwriteMsgDfltLang {
-            field: String::from("value2"),
+        StartsWith::from_json_lang(r#"{"field": "https://example.com"}"#.as_bytes(), "en").unwrap(),
+        StartsWith {
+            field: String::from("https://example.com"),
         }
     );
 
     assert_eq!(
-        EnumOverwriteMsgDfltLang::from_json_stack_errs("{\"field\": \"value2\"}".as_bytes())
-            .unwrap(),
-        EnumOverwriteMsgDfltLang {
-            field: String::from("value2"),
+        StartsWith::from_json_stack_errs(r#"{"field": "https://example.com"}"#.as_bytes()).unwrap(),
+        StartsWith {
+            field: String::from("https://example.com"),
         }
     );
 
     assert_eq!(
-        EnumOverwriteMsgDfltLang::from_json_stack_errs_lang(
-            "{\"field\": \"value2\"}".as_bytes(),
-            "en"
-        )
-        .unwrap(),
-        EnumOverwriteMsgDfltLang {
-            field: String::from("value2"),
+        StartsWith::from_json_stack_errs_lang(r#"{"field": "https://example.com"}"#.as_bytes(), "en")
+            .unwrap(),
+        StartsWith {
+            field: String::from("https://example.com"),
         }
     );
 }
 
 #[test]
-fn enum_overwrite_msg_dflt_lang_invalid() {
-    assert_eq!(
-        EnumOverwriteMsgDfltLang::from_json(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "قيمة غير صالحة",)
+fn starts_with_invalid() {
+    assert_eq!(
+        StartsWith::from_json(r#"{"field": "http://example.com"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must start with: \"https://\"",
+            )
+            .with_kind(ErrKind::StartsWith)
+            .with_param("value", "https://"),
+        )
     );
 
     assert_eq!(
-        EnumOverwriteMsgDfltLang::from_json_lang(r#"{"field": "vawalue"}"#.as_bytes(), "en")
+        StartsWith::from_json_lang(r#"{"field": "http://example.com"}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "invalid value",)
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must start with: \"https://\"",
+            )
+            .with_kind(ErrKind::StartsWith)
+            .with_param("value", "https://"),
+        )
     );
 
     assert_eq!(
-        EnumOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": "valuqwre"}"#.as_bytes())
+        StartsWith::from_json_stack_errs(r#"{"field": "http://example.com"}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "قيمة غير صالحة",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must start with: \"https://\"",
+            )
+            .with_kind(ErrKind::StartsWith)
+            .with_param("value", "https://"),
+        ])
     );
 
     assert_eq!(
-        EnumOverwriteMsgDfltLang::from_json_stack_errs_lang(
-            r#"{"field": "vqwrqalue"}"#.as_bytes(),
-            "en"
-        )
-        .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "invalid value",)
+        StartsWith::from_json_stack_errs_lang(r#"{"field": "http://example.com"}"#.as_bytes(), "en")
+            .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must start with: \"https://\"",
+            )
+            .with_kind(ErrKind::StartsWith)
+            .with_param("value", "https://"),
+        ])
     );
 }
 
 #[derive(Debug, PartialEq)]
 #[from(json)]
-struct Required {
+struct Predicates {
+    #[ends_with = ".pdf"]
+    #[contains = "/"]
+    #[not_contains(value = "..", msgs{en="invalid path", ar="مسار غير صالح"})]
     field: String,
 }
 
 #[test]
-fn required() {
+fn predicates() {
     assert_eq!(
-        Required::from_json(r#"{"field1": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        Predicates::from_json(r#"{"field": "docs/cv.pdf"}"#.as_bytes()).unwrap(),
+        Predicates {
+            field: String::from("docs/cv.pdf"),
+        }
     );
 
     assert_eq!(
-        Required::from_json_lang(r#"{"field2": "value"}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        Predicates::from_json(r#"{"field": "docs/cv.doc"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must end with: \".pdf\"",
+            )
+            .with_kind(ErrKind::EndsWith)
+            .with_param("value", ".pdf"),
+        )
     );
 
     assert_eq!(
-        Required::from_json_stack_errs(r#"{"field4": "value"}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        Predicates::from_json_lang(r#"{"field": "cv.pdf"}"#.as_bytes(), "en").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "value must contain: \"/\"")
+                .with_kind(ErrKind::Contains)
+                .with_param("value", "/"),
+        )
     );
 
     assert_eq!(
-        Required::from_json_stack_errs_lang(r#"{"field7": "value"}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        Predicates::from_json_stack_errs(r#"{"field": "../cv.pdf"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid path")
+                .with_kind(ErrKind::NotContains)
+                .with_param("value", ".."),
+        ])
+    );
+
+    assert_eq!(
+        Predicates::from_json_stack_errs_lang(r#"{"field": "../cv.pdf"}"#.as_bytes(), "ar")
+            .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "مسار غير صالح")
+                .with_kind(ErrKind::NotContains)
+                .with_param("value", ".."),
+        ])
     );
 }
 
 #[derive(Debug, PartialEq)]
 #[from(json)]
-struct RequiredOverwriteMsg {
-    #[required_msgs{en = "value is required", ar="الحقل مطلوب"}]
+struct Charset {
+    #[charset(ascii_lowercase, ascii_digit, extra = "-_")]
     field: String,
 }
 
 #[test]
-fn required_overwrite_msg() {
+fn charset_valid() {
     assert_eq!(
-        RequiredOverwriteMsg::from_json(r#"{"field7": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        Charset::from_json(r#"{"field": "my-slug_2"}"#.as_bytes()).unwrap(),
+        Charset {
+            field: String::from("my-slug_2"),
+        }
     );
 
     assert_eq!(
-        RequiredOverwriteMsg::from_json_lang(r#"{"field9": "value"}"#.as_bytes(), "en")
-            .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        Charset::from_json_lang(r#"{"field": "my-slug_2"}"#.as_bytes(), "en").unwrap(),
+        Charset {
+            field: String::from("my-slug_2"),
+        }
     );
 
     assert_eq!(
-        RequiredOverwriteMsg::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        Charset::from_json_stack_errs(r#"{"field": "my-slug_2"}"#.as_bytes()).unwrap(),
+        Charset {
+            field: String::from("my-slug_2"),
+        }
     );
 
     assert_eq!(
-        RequiredOverwriteMsg::from_json_stack_errs_lang(r#"{  }"#.as_bytes(), "ar").unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        Charset::from_json_stack_errs_lang(r#"{"field": "my-slug_2"}"#.as_bytes(), "en").unwrap(),
+        Charset {
+            field: String::from("my-slug_2"),
+        }
     );
 }
 
-#[derive(Debug, PartialEq)]
-#[from(json)]
+#[test]
+fn charset_invalid() {
+    assert_eq!(
+        Charset::from_json(r#"{"field": "my slug"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "character ' ' is not allowed",
+            )
+            .with_kind(ErrKind::Charset)
+            .with_param("char", " "),
+        )
+    );
+
+    assert_eq!(
+        Charset::from_json_lang(r#"{"field": "My-slug"}"#.as_bytes(), "en").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "character 'M' is not allowed",
+            )
+            .with_kind(ErrKind::Charset)
+            .with_param("char", "M"),
+        )
+    );
+
+    assert_eq!(
+        Charset::from_json_stack_errs(r#"{"field": "my.slug"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "character '.' is not allowed",
+            )
+            .with_kind(ErrKind::Charset)
+            .with_param("char", "."),
+        ])
+    );
+
+    assert_eq!(
+        Charset::from_json_stack_errs_lang(r#"{"field": "slugé"}"#.as_bytes(), "en").unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "character 'é' is not allowed",
+            )
+            .with_kind(ErrKind::Charset)
+            .with_param("char", "é"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct CharsetOverwriteMsg {
+    #[charset(alphabetic, msgs{en="letters only", ar="حروف فقط"})]
+    field: String,
+}
+
+#[test]
+fn charset_overwrite_msg() {
+    assert_eq!(
+        CharsetOverwriteMsg::from_json(r#"{"field": "مرحبا"}"#.as_bytes()).unwrap(),
+        CharsetOverwriteMsg {
+            field: String::from("مرحبا"),
+        }
+    );
+
+    assert_eq!(
+        CharsetOverwriteMsg::from_json_lang(r#"{"field": "abc1"}"#.as_bytes(), "ar").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "حروف فقط")
+                .with_kind(ErrKind::Charset)
+                .with_param("char", "1"),
+        )
+    );
+
+    assert_eq!(
+        CharsetOverwriteMsg::from_json_stack_errs(r#"{"field": "abc1"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "letters only")
+                .with_kind(ErrKind::Charset)
+                .with_param("char", "1"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct NotEnum {
+    #[not_enum(values = ["admin", "root"])]
+    field: String,
+}
+
+#[test]
+fn not_enum() {
+    assert_eq!(
+        NotEnum::from_json(r#"{"field": "Admin"}"#.as_bytes()).unwrap(),
+        NotEnum {
+            field: String::from("Admin"),
+        }
+    );
+
+    assert_eq!(
+        NotEnum::from_json(r#"{"field": "admin"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value \"admin\" is not allowed",
+            )
+            .with_kind(ErrKind::NotEnum)
+            .with_param("values", ["admin", "root"])
+            .with_param("actual", "admin"),
+        )
+    );
+
+    assert_eq!(
+        NotEnum::from_json_lang(r#"{"field": "root"}"#.as_bytes(), "en").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value \"root\" is not allowed",
+            )
+            .with_kind(ErrKind::NotEnum)
+            .with_param("values", ["admin", "root"])
+            .with_param("actual", "root"),
+        )
+    );
+
+    assert_eq!(
+        NotEnum::from_json_stack_errs(r#"{"field": "root"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value \"root\" is not allowed",
+            )
+            .with_kind(ErrKind::NotEnum)
+            .with_param("values", ["admin", "root"])
+            .with_param("actual", "root"),
+        ])
+    );
+
+    assert_eq!(
+        NotEnum::from_json_stack_errs_lang(r#"{"field": "admin"}"#.as_bytes(), "en").unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value \"admin\" is not allowed",
+            )
+            .with_kind(ErrKind::NotEnum)
+            .with_param("values", ["admin", "root"])
+            .with_param("actual", "admin"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct NotEnumCaseInsensitive {
+    #[trim]
+    #[not_enum(
+        values = ["Admin", "root", "support"],
+        case_insensitive,
+        msgs{en="reserved username", ar="اسم مستخدم محجوز"},
+    )]
+    field: String,
+}
+
+#[test]
+fn not_enum_case_insensitive() {
+    assert_eq!(
+        NotEnumCaseInsensitive::from_json(r#"{"field": "john"}"#.as_bytes()).unwrap(),
+        NotEnumCaseInsensitive {
+            field: String::from("john"),
+        }
+    );
+
+    assert_eq!(
+        NotEnumCaseInsensitive::from_json(r#"{"field": " ROOT "}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "reserved username")
+                .with_kind(ErrKind::NotEnum)
+                .with_param("values", ["admin", "root", "support"])
+                .with_param("actual", "ROOT"),
+        )
+    );
+
+    assert_eq!(
+        NotEnumCaseInsensitive::from_json_lang(r#"{"field": "admin"}"#.as_bytes(), "ar")
+            .unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "اسم مستخدم محجوز")
+                .with_kind(ErrKind::NotEnum)
+                .with_param("values", ["admin", "root", "support"])
+                .with_param("actual", "admin"),
+        )
+    );
+
+    assert_eq!(
+        NotEnumCaseInsensitive::from_json_stack_errs(r#"{"field": "Support"}"#.as_bytes())
+            .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "reserved username")
+                .with_kind(ErrKind::NotEnum)
+                .with_param("values", ["admin", "root", "support"])
+                .with_param("actual", "Support"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Format {
+    #[format(email)]
+    field: String,
+}
+
+#[test]
+fn format_valid() {
+    assert_eq!(
+        Format::from_json(r#"{"field": "user@example.com"}"#.as_bytes()).unwrap(),
+        Format {
+            field: String::from("user@example.com"),
+        }
+    );
+
+    assert_eq!(
+        Format::from_json_lang(r#"{"field": "user@example.com"}"#.as_bytes(), "en").unwrap(),
+        Format {
+            field: String::from("user@example.com"),
+        }
+    );
+
+    assert_eq!(
+        Format::from_json_stack_errs(r#"{"field": "user@example.com"}"#.as_bytes()).unwrap(),
+        Format {
+            field: String::from("user@example.com"),
+        }
+    );
+
+    assert_eq!(
+        Format::from_json_stack_errs_lang(r#"{"field": "user@example.com"}"#.as_bytes(), "en")
+            .unwrap(),
+        Format {
+            field: String::from("user@example.com"),
+        }
+    );
+}
+
+#[test]
+fn format_invalid() {
+    assert_eq!(
+        Format::from_json(r#"{"field": "user@"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must be a valid email address",
+            )
+            .with_kind(ErrKind::Format)
+            .with_param("format", "email"),
+        )
+    );
+
+    assert_eq!(
+        Format::from_json_lang(r#"{"field": "user@"}"#.as_bytes(), "en").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must be a valid email address",
+            )
+            .with_kind(ErrKind::Format)
+            .with_param("format", "email"),
+        )
+    );
+
+    assert_eq!(
+        Format::from_json_stack_errs(r#"{"field": "user@"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must be a valid email address",
+            )
+            .with_kind(ErrKind::Format)
+            .with_param("format", "email"),
+        ])
+    );
+
+    assert_eq!(
+        Format::from_json_stack_errs_lang(r#"{"field": "user@"}"#.as_bytes(), "en").unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must be a valid email address",
+            )
+            .with_kind(ErrKind::Format)
+            .with_param("format", "email"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct FormatOverwriteMsg {
+    #[format(uuid, msgs{en="invalid id", ar="معرف غير صالح"})]
+    field: String,
+}
+
+#[test]
+fn format_overwrite_msg() {
+    assert_eq!(
+        FormatOverwriteMsg::from_json(r#"{"field": "1234"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid id")
+                .with_kind(ErrKind::Format)
+                .with_param("format", "uuid"),
+        )
+    );
+
+    assert_eq!(
+        FormatOverwriteMsg::from_json_lang(r#"{"field": "1234"}"#.as_bytes(), "ar").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "معرف غير صالح")
+                .with_kind(ErrKind::Format)
+                .with_param("format", "uuid"),
+        )
+    );
+
+    assert_eq!(
+        FormatOverwriteMsg::from_json_stack_errs(r#"{"field": "1234"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid id")
+                .with_kind(ErrKind::Format)
+                .with_param("format", "uuid"),
+        ])
+    );
+
+    assert_eq!(
+        FormatOverwriteMsg::from_json_stack_errs_lang(r#"{"field": "1234"}"#.as_bytes(), "ar")
+            .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "معرف غير صالح")
+                .with_kind(ErrKind::Format)
+                .with_param("format", "uuid"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Enum {
+    #[r#enum(values = ["value1", "value2"])]
+    field: String,
+}
+
+#[test]
+fn enum_valid() {
+    assert_eq!(
+        Enum::from_json("{\"field\": \"value1\"}".as_bytes()).unwrap(),
+        Enum {
+            field: String::from("value1"),
+        }
+    );
+
+    assert_eq!(
+        Enum::from_json_lang("{\"field\": \"value2\"}".as_bytes(), "en").unwrap(),
+        Enum {
+            field: String::from("value2"),
+        }
+    );
+
+    assert_eq!(
+        Enum::from_json_stack_errs("{\"field\": \"value2\"}".as_bytes()).unwrap(),
+        Enum {
+            field: String::from("value2"),
+        }
+    );
+
+    assert_eq!(
+        Enum::from_json_stack_errs_lang("{\"field\": \"value1\"}".as_bytes(), "en").unwrap(),
+        Enum {
+            field: String::from("value1"),
+        }
+    );
+}
+
+#[test]
+fn enum_invalid() {
+    assert_eq!(
+        Enum::from_json(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must be one of: [\"value1\", \"value2\"]",
+            )
+            .with_kind(ErrKind::Enum)
+            .with_param("values", ["value1", "value2"])
+            .with_param("actual", "value"),
+        )
+    );
+
+    assert_eq!(
+        Enum::from_json_lang(r#"{"field": "value"}"#.as_bytes(), "en").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must be one of: [\"value1\", \"value2\"]",
+            )
+            .with_kind(ErrKind::Enum)
+            .with_param("values", ["value1", "value2"])
+            .with_param("actual", "value"),
+        )
+    );
+
+    assert_eq!(
+        Enum::from_json_stack_errs(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must be one of: [\"value1\", \"value2\"]",
+            )
+            .with_kind(ErrKind::Enum)
+            .with_param("values", ["value1", "value2"])
+            .with_param("actual", "value"),
+        ])
+    );
+
+    assert_eq!(
+        Enum::from_json_stack_errs_lang(r#"{"field": "value"}"#.as_bytes(), "en").unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "value must be one of: [\"value1\", \"value2\"]",
+            )
+            .with_kind(ErrKind::Enum)
+            .with_param("values", ["value1", "value2"])
+            .with_param("actual", "value"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct EnumOverwriteMsg {
+    #[r#enum(values = ["value1", "value2"], msgs{en="invalid value", ar="قيمة غير صالحة"})]
+    field: String,
+}
+
+#[test]
+fn enum_overwrite_msg_valid() {
+    assert_eq!(
+        EnumOverwriteMsg::from_json("{\"field\": \"value2\"}".as_bytes()).unwrap(),
+        EnumOverwriteMsg {
+            field: String::from("value2"),
+        }
+    );
+
+    assert_eq!(
+        EnumOverwriteMsg::from_json_lang("{\"field\": \"value1\"}".as_bytes(), "en").unwrap(),
+        EnumOverwriteMsg {
+            field: String::from("value1"),
+        }
+    );
+
+    assert_eq!(
+        EnumOverwriteMsg::from_json_stack_errs("{\"field\": \"value2\"}".as_bytes()).unwrap(),
+        EnumOverwriteMsg {
+            field: String::from("value2"),
+        }
+    );
+
+    assert_eq!(
+        EnumOverwriteMsg::from_json_stack_errs_lang("{\"field\": \"value1\"}".as_bytes(), "en")
+            .unwrap(),
+        EnumOverwriteMsg {
+            field: String::from("value1"),
+        }
+    );
+}
+
+#[test]
+fn enum_overwrite_msg_invalid() {
+    assert_eq!(
+        EnumOverwriteMsg::from_json(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid value")
+                .with_kind(ErrKind::Enum)
+                .with_param("values", ["value1", "value2"])
+                .with_param("actual", "value"),
+        )
+    );
+
+    assert_eq!(
+        EnumOverwriteMsg::from_json_lang(r#"{"field": "value"}"#.as_bytes(), "ar").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "قيمة غير صالحة")
+                .with_kind(ErrKind::Enum)
+                .with_param("values", ["value1", "value2"])
+                .with_param("actual", "value"),
+        )
+    );
+
+    assert_eq!(
+        EnumOverwriteMsg::from_json_stack_errs(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid value")
+                .with_kind(ErrKind::Enum)
+                .with_param("values", ["value1", "value2"])
+                .with_param("actual", "value"),
+        ])
+    );
+
+    assert_eq!(
+        EnumOverwriteMsg::from_json_stack_errs_lang(r#"{"field": "value"}"#.as_bytes(), "ar")
+            .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "قيمة غير صالحة")
+                .with_kind(ErrKind::Enum)
+                .with_param("values", ["value1", "value2"])
+                .with_param("actual", "value"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+#[dflt_lang = "ar"]
+struct EnumOverwriteMsgDfltLang {
+    #[r#enum(values = ["value1", "value2"], msgs{en="invalid value", ar="قيمة غير صالحة"})]
+    field: String,
+}
+
+#[test]
+fn enum_overwrite_msg_dflt_lang_valid() {
+    assert_eq!(
+        EnumOverwriteMsgDfltLang::from_json("{\"field\": \"value1\"}".as_bytes()).unwrap(),
+        EnumOverwriteMsgDfltLang {
+            field: String::from("value1"),
+        }
+    );
+
+    assert_eq!(
+        EnumOverwriteMsgDfltLang::from_json_lang("{\"field\": \"value2\"}".as_bytes(), "en")
+            .unwrap(),
+        EnumOverwriteMsgDfltLang {
+            field: String::from("value2"),
+        }
+    );
+
+    assert_eq!(
+        EnumOverwriteMsgDfltLang::from_json_stack_errs("{\"field\": \"value2\"}".as_bytes())
+            .unwrap(),
+        EnumOverwriteMsgDfltLang {
+            field: String::from("value2"),
+        }
+    );
+
+    assert_eq!(
+        EnumOverwriteMsgDfltLang::from_json_stack_errs_lang(
+            "{\"field\": \"value2\"}".as_bytes(),
+            "en"
+        )
+        .unwrap(),
+        EnumOverwriteMsgDfltLang {
+            field: String::from("value2"),
+        }
+    );
+}
+
+#[test]
+fn enum_overwrite_msg_dflt_lang_invalid() {
+    assert_eq!(
+        EnumOverwriteMsgDfltLang::from_json(r#"{"field": "value"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "قيمة غير صالحة")
+                .with_kind(ErrKind::Enum)
+                .with_param("values", ["value1", "value2"])
+                .with_param("actual", "value"),
+        )
+    );
+
+    assert_eq!(
+        EnumOverwriteMsgDfltLang::from_json_lang(r#"{"field": "vawalue"}"#.as_bytes(), "en")
+            .unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid value")
+                .with_kind(ErrKind::Enum)
+                .with_param("values", ["value1", "value2"])
+                .with_param("actual", "vawalue"),
+        )
+    );
+
+    assert_eq!(
+        EnumOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": "valuqwre"}"#.as_bytes())
+            .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "قيمة غير صالحة")
+                .with_kind(ErrKind::Enum)
+                .with_param("values", ["value1", "value2"])
+                .with_param("actual", "valuqwre"),
+        ])
+    );
+
+    assert_eq!(
+        EnumOverwriteMsgDfltLang::from_json_stack_errs_lang(
+            r#"{"field": "vqwrqalue"}"#.as_bytes(),
+            "en"
+        )
+        .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid value")
+                .with_kind(ErrKind::Enum)
+                .with_param("values", ["value1", "value2"])
+                .with_param("actual", "vqwrqalue"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Required {
+    field: String,
+}
+
+#[test]
+fn required() {
+    assert_eq!(
+        Required::from_json(r#"{"field1": "value"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        )
+    );
+
+    assert_eq!(
+        Required::from_json_lang(r#"{"field2": "value"}"#.as_bytes(), "en").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        )
+    );
+
+    assert_eq!(
+        Required::from_json_stack_errs(r#"{"field4": "value"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        ])
+    );
+
+    assert_eq!(
+        Required::from_json_stack_errs_lang(r#"{"field7": "value"}"#.as_bytes(), "en").unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct RequiredOverwriteMsg {
+    #[required_msgs{en = "value is required", ar="الحقل مطلوب"}]
+    field: String,
+}
+
+#[test]
+fn required_overwrite_msg() {
+    assert_eq!(
+        RequiredOverwriteMsg::from_json(r#"{"field7": "value"}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        )
+    );
+
+    assert_eq!(
+        RequiredOverwriteMsg::from_json_lang(r#"{"field9": "value"}"#.as_bytes(), "en")
+            .unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        )
+    );
+
+    assert_eq!(
+        RequiredOverwriteMsg::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        ])
+    );
+
+    assert_eq!(
+        RequiredOverwriteMsg::from_json_stack_errs_lang(r#"{  }"#.as_bytes(), "ar").unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
 #[dflt_lang = "ar"]
 struct RequiredOverwriteMsgDfltLang {
     #[required_msgs{en = "value is required", ar="الحقل مطلوب"}]
@@ -1130,24 +2352,36 @@ struct RequiredOverwriteMsgDfltLang {
 fn required_overwrite_msg_dflt_lang() {
     assert_eq!(
         RequiredOverwriteMsgDfltLang::from_json(r#"{"field7": "value"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         RequiredOverwriteMsgDfltLang::from_json_lang(r#"{"field9": "value"}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         RequiredOverwriteMsgDfltLang::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        ])
     );
 
     assert_eq!(
         RequiredOverwriteMsgDfltLang::from_json_stack_errs_lang(r#"{  }"#.as_bytes(), "ar")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        ])
     );
 }
 
@@ -1161,82 +2395,127 @@ struct TypeMismatch {
 fn type_mismatch() {
     assert_eq!(
         TypeMismatch::from_json(r#"{"field": 25}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: number",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: number",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "number"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_lang(r#"{"field": 25}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: number",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: number",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "number"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs(r#"{"field": 25}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: number",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: number",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "number"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs_lang(r#"{"field": 25}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: number",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: number",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "number"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json(r#"{"field": true}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: boolean",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: boolean",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "boolean"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_lang(r#"{"field": false}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: boolean",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: boolean",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "boolean"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs(r#"{"field": true}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: boolean",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: boolean",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "boolean"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs_lang(r#"{"field": true}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: boolean",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: boolean",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "boolean"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json(r#"{"field": {this will be ignored}}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: object",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: object",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "object"),
         )
     );
 
@@ -1246,21 +2525,31 @@ fn type_mismatch() {
             "en"
         )
         .unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: object",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: object",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "object"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs(r#"{"field": {because it does not care}}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: object",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: object",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "object"),
+        ])
     );
 
     assert_eq!(
@@ -1269,19 +2558,29 @@ fn type_mismatch() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: object",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: object",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "object"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json(r#"{"field": [this will be ignored]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: array",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: array",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "array"),
         )
     );
 
@@ -1291,21 +2590,31 @@ fn type_mismatch() {
             "en"
         )
         .unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: array",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: array",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "array"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs(r#"{"field": [because it does not care]}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: array",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: array",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "array"),
+        ])
     );
 
     assert_eq!(
@@ -1314,11 +2623,16 @@ fn type_mismatch() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: array",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: array",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "string")
+            .with_param("found", "array"),
+        ])
     );
 }
 
@@ -1333,17 +2647,32 @@ struct TypeMismatchOverwriteMsg {
 fn type_mismatch_overwrite_msg() {
     assert_eq!(
         TypeMismatchOverwriteMsg::from_json(r#"{"field": false}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "invalid type",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid type")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "string")
+                .with_param("found", "boolean"),
+        )
     );
 
     assert_eq!(
         TypeMismatchOverwriteMsg::from_json_lang(r#"{"field": 25}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "نوع غير صالح",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "نوع غير صالح")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "string")
+                .with_param("found", "number"),
+        )
     );
 
     assert_eq!(
         TypeMismatchOverwriteMsg::from_json_stack_errs(r#"{"field": {}}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "invalid type",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid type")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "string")
+                .with_param("found", "object"),
+        ])
     );
 
     assert_eq!(
@@ -1352,7 +2681,12 @@ fn type_mismatch_overwrite_msg() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "invalid type",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid type")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "string")
+                .with_param("found", "array"),
+        ])
     );
 }
 
@@ -1368,19 +2702,34 @@ struct TypeMismatchOverwriteMsgDfltLang {
 fn type_mismatch_overwrite_msg_dflt_lang() {
     assert_eq!(
         TypeMismatchOverwriteMsgDfltLang::from_json(r#"{"field": false}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "نوع غير صالح",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "نوع غير صالح")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "string")
+                .with_param("found", "boolean"),
+        )
     );
 
     assert_eq!(
         TypeMismatchOverwriteMsgDfltLang::from_json_lang(r#"{"field": 25}"#.as_bytes(), "ar")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "نوع غير صالح",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "نوع غير صالح")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "string")
+                .with_param("found", "number"),
+        )
     );
 
     assert_eq!(
         TypeMismatchOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": {}}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "نوع غير صالح",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "نوع غير صالح")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "string")
+                .with_param("found", "object"),
+        ])
     );
 
     assert_eq!(
@@ -1389,7 +2738,12 @@ fn type_mismatch_overwrite_msg_dflt_lang() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "invalid type",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid type")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "string")
+                .with_param("found", "array"),
+        ])
     );
 }
 
@@ -1403,38 +2757,52 @@ struct NotNull {
 fn not_null() {
     assert_eq!(
         NotNull::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: null",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
         )
     );
 
+    // the built-in message is translated with 'locale-ar'
+    #[cfg(not(feature = "locale-ar"))]
     assert_eq!(
         NotNull::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: null",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
         )
     );
 
     assert_eq!(
         NotNull::from_json_stack_errs(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: null",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
+        ])
     );
 
     assert_eq!(
         NotNull::from_json_stack_errs_lang(r#"{"field": null}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: string, found: null",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: string, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
+        ])
     );
 }
 
@@ -1449,23 +2817,35 @@ struct NotNullOverwriteMsg {
 fn not_null_overwrite_msg() {
     assert_eq!(
         NotNullOverwriteMsg::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsg::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsg::from_json_stack_errs(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        ])
     );
 
     assert_eq!(
         NotNullOverwriteMsg::from_json_stack_errs_lang(r#"{"field": null}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        ])
     );
 }
 
@@ -1481,23 +2861,32 @@ struct NotNullOverwriteMsgDfltLang {
 fn not_null_overwrite_msg_dflt_lang() {
     assert_eq!(
         NotNullOverwriteMsgDfltLang::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsgDfltLang::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": null}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "القيمة الخالية غير مسموحة",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "القيمة الخالية غير مسموحة",
+            )
+            .with_kind(ErrKind::NotNull),
+        ])
     );
 
     assert_eq!(
@@ -1506,7 +2895,10 @@ fn not_null_overwrite_msg_dflt_lang() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        ])
     );
 }
 
@@ -1807,16 +3199,20 @@ fn multi_err() {
     assert_eq!(
         MultiErr::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
         from::Errs::ValidationErrs(vec![
-            ValidationErr::new(From::from("field1"), Vec::new(), "required field"),
-            ValidationErr::new(From::from("field2"), Vec::new(), "required field"),
+            ValidationErr::new(From::from("field1"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+            ValidationErr::new(From::from("field2"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
         ])
     );
 
     assert_eq!(
         MultiErr::from_json_stack_errs_lang(r#"{}"#.as_bytes(), "en").unwrap_err(),
         from::Errs::ValidationErrs(vec![
-            ValidationErr::new(From::from("field1"), Vec::new(), "required field"),
-            ValidationErr::new(From::from("field2"), Vec::new(), "required field"),
+            ValidationErr::new(From::from("field1"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+            ValidationErr::new(From::from("field2"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
         ])
     );
 }
@@ -0,0 +1,995 @@
+use from::{from, json::FromJsonValue, lang, ErrKind, FromJson, Path, ValidationErr};
+
+struct EndAfterStart;
+
+impl ::from::Validator<Period> for EndAfterStart {
+    fn none(val: &Period, path: &Path) -> Result<(), ValidationErr> {
+        if val.end <= val.start {
+            return Err(ValidationErr::new(
+                From::from("end"),
+                path.clone(),
+                "end must be after start",
+            ));
+        };
+
+        Ok(())
+    }
+
+    fn lang(val: &Period, path: &Path, lang: &str) -> Result<(), ValidationErr> {
+        if val.end <= val.start {
+            return Err(ValidationErr::new(
+                From::from("end"),
+                path.clone(),
+                match lang::select(lang, &["ar"]) {
+                    Some("ar") => "يجب أن تكون النهاية بعد البداية",
+                    _ => "end must be after start",
+                },
+            ));
+        };
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+#[validators(EndAfterStart)]
+struct Period {
+    start: u32,
+
+    #[max(value = 100)]
+    end: u32,
+}
+
+#[test]
+fn validators_valid() {
+    let json = r#"{"start": 5, "end": 10}"#.as_bytes();
+
+    let expected = Period { start: 5, end: 10 };
+
+    assert_eq!(Period::from_json(json).unwrap(), expected);
+
+    assert_eq!(Period::from_json_lang(json, "ar").unwrap(), expected);
+
+    assert_eq!(Period::from_json_stack_errs(json).unwrap(), expected);
+
+    assert_eq!(Period::from_json_stack_errs_lang(json, "ar").unwrap(), expected);
+}
+
+#[test]
+fn validators_invalid() {
+    let json = r#"{"start": 10, "end": 5}"#.as_bytes();
+
+    assert_eq!(
+        Period::from_json(json).unwrap_err(),
+        from::Err::new_validation_err(From::from("end"), Vec::new(), "end must be after start")
+    );
+
+    assert_eq!(
+        Period::from_json_lang(json, "ar").unwrap_err(),
+        from::Err::new_validation_err(
+            From::from("end"),
+            Vec::new(),
+            "يجب أن تكون النهاية بعد البداية"
+        )
+    );
+
+    // the validators receive the list of the languages as it is
+    assert_eq!(
+        Period::from_json_langs(json, &["fr", "ar-EG"]).unwrap_err(),
+        from::Err::new_validation_err(
+            From::from("end"),
+            Vec::new(),
+            "يجب أن تكون النهاية بعد البداية"
+        )
+    );
+
+    assert_eq!(
+        Period::from_json_stack_errs(json).unwrap_err(),
+        from::Errs::new_validation_err(From::from("end"), Vec::new(), "end must be after start")
+    );
+
+    // 'stack_errs_lang' is not overwritten, so it falls back to 'none'
+    assert_eq!(
+        Period::from_json_stack_errs_lang(json, "ar").unwrap_err(),
+        from::Errs::new_validation_err(From::from("end"), Vec::new(), "end must be after start")
+    );
+}
+
+#[test]
+fn validators_skipped_on_field_errs() {
+    let json = r#"{"start": 200, "end": 150}"#.as_bytes();
+
+    assert_eq!(
+        Period::from_json_stack_errs(json).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("end"),
+                Vec::new(),
+                "number must be less than or equal to 100"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 100usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 150usize),
+        ])
+    );
+}
+
+//
+//
+//
+
+struct EmailOrPhone;
+
+impl ::from::Validator<Contact> for EmailOrPhone {
+    fn none(val: &Contact, path: &Path) -> Result<(), ValidationErr> {
+        if val.email.is_none() && val.phone.is_none() {
+            return Err(ValidationErr::new(
+                From::from("email"),
+                path.clone(),
+                "either email or phone is required",
+            ));
+        };
+
+        Ok(())
+    }
+}
+
+struct NotReserved;
+
+impl ::from::Validator<Contact> for NotReserved {
+    fn none(val: &Contact, path: &Path) -> Result<(), ValidationErr> {
+        if val.name == "admin" {
+            return Err(ValidationErr::new(
+                From::from("name"),
+                path.clone(),
+                "reserved name",
+            ));
+        };
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+#[validators(EmailOrPhone, NotReserved)]
+struct Contact {
+    #[default = "admin"]
+    name: String,
+    email: Option<String>,
+    phone: Option<String>,
+}
+
+#[test]
+fn multiple_validators() {
+    assert_eq!(
+        Contact::from_json(r#"{"name": "x", "phone": "1"}"#.as_bytes()).unwrap(),
+        Contact {
+            name: String::from("x"),
+            email: None,
+            phone: Some(String::from("1")),
+        }
+    );
+
+    // empty object
+    assert_eq!(
+        Contact::from_json("{}".as_bytes()).unwrap_err(),
+        from::Err::new_validation_err(
+            From::from("email"),
+            Vec::new(),
+            "either email or phone is required"
+        )
+    );
+
+    assert_eq!(
+        Contact::from_json_stack_errs("{}".as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("email"),
+                Vec::new(),
+                "either email or phone is required"
+            ),
+            ValidationErr::new(From::from("name"), Vec::new(), "reserved name"),
+        ])
+    );
+
+    assert_eq!(
+        Contact::from_json_stack_errs_lang(r#"{"name": "admin"}"#.as_bytes(), "ar").unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("email"),
+                Vec::new(),
+                "either email or phone is required"
+            ),
+            ValidationErr::new(From::from("name"), Vec::new(), "reserved name"),
+        ])
+    );
+}
+
+//
+//
+//
+
+struct EndAfterStartAlways;
+
+impl ::from::Validator<PeriodAlways> for EndAfterStartAlways {
+    fn none(val: &PeriodAlways, path: &Path) -> Result<(), ValidationErr> {
+        if val.end <= val.start {
+            return Err(ValidationErr::new(
+                From::from("end"),
+                path.clone(),
+                "end must be after start",
+            ));
+        };
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+#[validators(EndAfterStartAlways)]
+#[always_validate]
+struct PeriodAlways {
+    #[max(value = 100)]
+    start: u32,
+    end: u32,
+    period: Period,
+}
+
+#[test]
+fn always_validate() {
+    assert_eq!(
+        PeriodAlways::from_json_stack_errs(
+            r#"{"start": 200, "end": 150, "period": {"start": 1, "end": 2}}"#.as_bytes()
+        )
+        .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("start"),
+                Vec::new(),
+                "number must be less than or equal to 100"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 100usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 200usize),
+            ValidationErr::new(From::from("end"), Vec::new(), "end must be after start"),
+        ])
+    );
+
+    // nested struct-level errors carry the field path
+    assert_eq!(
+        PeriodAlways::from_json_stack_errs(
+            r#"{"start": 200, "end": 150, "period": {"start": 2, "end": 1}}"#.as_bytes()
+        )
+        .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("start"),
+                Vec::new(),
+                "number must be less than or equal to 100"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 100usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 200usize),
+            ValidationErr::new(
+                From::from("end"),
+                vec![From::from("period")],
+                "end must be after start"
+            ),
+        ])
+    );
+
+    // not all required fields are present
+    assert_eq!(
+        PeriodAlways::from_json_stack_errs(r#"{"start": 200, "end": 150}"#.as_bytes())
+            .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("start"),
+                Vec::new(),
+                "number must be less than or equal to 100"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 100usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 200usize),
+            ValidationErr::new(From::from("period"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        ])
+    );
+}
+
+//
+//
+//
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct SignUp {
+    password: String,
+
+    #[eq_field = "password"]
+    confirm_password: String,
+}
+
+#[test]
+fn eq_field() {
+    assert_eq!(
+        SignUp::from_json(r#"{"password": "secret", "confirm_password": "secret"}"#.as_bytes())
+            .unwrap(),
+        SignUp {
+            password: String::from("secret"),
+            confirm_password: String::from("secret"),
+        }
+    );
+
+    let json = r#"{"password": "secret", "confirm_password": "Secret"}"#.as_bytes();
+
+    assert_eq!(
+        SignUp::from_json(json).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("confirm_password"),
+                Vec::new(),
+                "must be equal to 'password'"
+            )
+            .with_kind(ErrKind::EqField)
+            .with_param("other", "password"),
+        )
+    );
+
+    assert_eq!(
+        SignUp::from_json_lang(json, "it").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("confirm_password"),
+                Vec::new(),
+                "must be equal to 'password'"
+            )
+            .with_kind(ErrKind::EqField)
+            .with_param("other", "password"),
+        )
+    );
+
+    assert_eq!(
+        SignUp::from_json_stack_errs(json).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("confirm_password"),
+                Vec::new(),
+                "must be equal to 'password'"
+            )
+            .with_kind(ErrKind::EqField)
+            .with_param("other", "password"),
+        ])
+    );
+
+    assert_eq!(
+        SignUp::from_json_stack_errs_lang(json, "it").unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("confirm_password"),
+                Vec::new(),
+                "must be equal to 'password'"
+            )
+            .with_kind(ErrKind::EqField)
+            .with_param("other", "password"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct PriceRange {
+    min_price: Option<f64>,
+
+    #[gt_field(
+        field = "min_price",
+        msgs{
+            en = "max price must be greater than min price",
+            ar = "يجب أن يكون السعر الأعلى أكبر من السعر الأدنى",
+        },
+    )]
+    max_price: from::OptionNull<f64>,
+
+    #[lt_field = "max_qty"]
+    min_qty: from::Null<u32>,
+
+    max_qty: u32,
+}
+
+#[test]
+fn gt_lt_field() {
+    // not compared unless both values are present
+    assert_eq!(
+        PriceRange::from_json(r#"{"max_price": 5.0, "min_qty": null, "max_qty": 0}"#.as_bytes())
+            .unwrap(),
+        PriceRange {
+            min_price: None,
+            max_price: from::OptionNull::Some(5.0),
+            min_qty: from::Null::Null,
+            max_qty: 0,
+        }
+    );
+
+    let json = r#"{"min_price": 5.0, "max_price": 5.0, "min_qty": 2, "max_qty": 10}"#.as_bytes();
+
+    assert_eq!(
+        PriceRange::from_json(json).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("max_price"),
+                Vec::new(),
+                "max price must be greater than min price"
+            )
+            .with_kind(ErrKind::GtField)
+            .with_param("other", "min_price"),
+        )
+    );
+
+    assert_eq!(
+        PriceRange::from_json_lang(json, "ar").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("max_price"),
+                Vec::new(),
+                "يجب أن يكون السعر الأعلى أكبر من السعر الأدنى"
+            )
+            .with_kind(ErrKind::GtField)
+            .with_param("other", "min_price"),
+        )
+    );
+
+    let json = r#"{"min_price": 5.0, "max_price": 1.0, "min_qty": 10, "max_qty": 10}"#.as_bytes();
+
+    assert_eq!(
+        PriceRange::from_json_stack_errs(json).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("max_price"),
+                Vec::new(),
+                "max price must be greater than min price"
+            )
+            .with_kind(ErrKind::GtField)
+            .with_param("other", "min_price"),
+            ValidationErr::new(From::from("min_qty"), Vec::new(), "must be less than 'max_qty'")
+                .with_kind(ErrKind::LtField)
+                .with_param("other", "max_qty"),
+        ])
+    );
+
+    // the built-in message falls back to English without 'locale-ar'
+    #[cfg(not(feature = "locale-ar"))]
+    assert_eq!(
+        PriceRange::from_json_stack_errs_lang(json, "ar").unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("max_price"),
+                Vec::new(),
+                "يجب أن يكون السعر الأعلى أكبر من السعر الأدنى"
+            )
+            .with_kind(ErrKind::GtField)
+            .with_param("other", "min_price"),
+            ValidationErr::new(From::from("min_qty"), Vec::new(), "must be less than 'max_qty'")
+                .with_kind(ErrKind::LtField)
+                .with_param("other", "max_qty"),
+        ])
+    );
+}
+
+//
+//
+//
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Address {
+    city: String,
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Order {
+    delivery: String,
+
+    #[required_if(field = "delivery", eq = "ship")]
+    #[required_msgs{
+        en = "shipping address is required",
+        ar = "عنوان الشحن مطلوب",
+    }]
+    shipping_address: Option<Address>,
+
+    phone: Option<String>,
+
+    #[required_unless(field = "phone")]
+    email: from::OptionNull<String>,
+}
+
+#[test]
+fn required_if_unless() {
+    assert_eq!(
+        Order::from_json(r#"{"delivery": "pickup", "phone": "1"}"#.as_bytes()).unwrap(),
+        Order {
+            delivery: String::from("pickup"),
+            shipping_address: None,
+            phone: Some(String::from("1")),
+            email: from::OptionNull::None,
+        }
+    );
+
+    assert_eq!(
+        Order::from_json(r#"{"delivery": "pickup", "email": null}"#.as_bytes()).unwrap(),
+        Order {
+            delivery: String::from("pickup"),
+            shipping_address: None,
+            phone: None,
+            email: from::OptionNull::Null,
+        }
+    );
+
+    let json = r#"{"delivery": "ship", "phone": "1"}"#.as_bytes();
+
+    assert_eq!(
+        Order::from_json(json).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("shipping_address"),
+                Vec::new(),
+                "shipping address is required"
+            )
+            .with_kind(ErrKind::RequiredIf)
+            .with_param("other", "delivery"),
+        )
+    );
+
+    assert_eq!(
+        Order::from_json_lang(json, "ar").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("shipping_address"),
+                Vec::new(),
+                "عنوان الشحن مطلوب"
+            )
+            .with_kind(ErrKind::RequiredIf)
+            .with_param("other", "delivery"),
+        )
+    );
+
+    let json = r#"{"delivery": "ship"}"#.as_bytes();
+
+    assert_eq!(
+        Order::from_json_stack_errs(json).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("shipping_address"),
+                Vec::new(),
+                "shipping address is required"
+            )
+            .with_kind(ErrKind::RequiredIf)
+            .with_param("other", "delivery"),
+            ValidationErr::new(From::from("email"), Vec::new(), "required field")
+                .with_kind(ErrKind::RequiredUnless)
+                .with_param("other", "phone"),
+        ])
+    );
+
+    // empty object
+    assert_eq!(
+        Order::from_json_stack_errs_lang("{}".as_bytes(), "it").unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("delivery"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+            ValidationErr::new(From::from("email"), Vec::new(), "required field")
+                .with_kind(ErrKind::RequiredUnless)
+                .with_param("other", "phone"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Delivery {
+    #[max_len(value = 6)]
+    method: String,
+
+    #[required_unless(field = "method", eq = "pickup")]
+    address: Option<Address>,
+}
+
+#[test]
+fn required_unless_other_field_missing_or_invalid() {
+    assert_eq!(
+        Delivery::from_json_stack_errs(r#"{"method": "pickup"}"#.as_bytes()).unwrap(),
+        Delivery {
+            method: String::from("pickup"),
+            address: None,
+        }
+    );
+
+    assert_eq!(
+        Delivery::from_json_stack_errs(r#"{"method": "ship"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![ValidationErr::new(
+            From::from("address"),
+            Vec::new(),
+            "required field"
+        )
+        .with_kind(ErrKind::RequiredUnless)
+        .with_param("other", "method")])
+    );
+
+    // only the error of 'method', the condition is not evaluated
+    // against its placeholder value
+    assert_eq!(
+        Delivery::from_json_stack_errs(r#"{"note": "x"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![ValidationErr::new(
+            From::from("method"),
+            Vec::new(),
+            "required field"
+        )
+        .with_kind(ErrKind::Required)])
+    );
+
+    assert_eq!(
+        Delivery::from_json_stack_errs_lang(r#"{"method": "pickup-point"}"#.as_bytes(), "en")
+            .unwrap_err(),
+        from::Errs::ValidationErrs(vec![ValidationErr::new(
+            From::from("method"),
+            Vec::new(),
+            "the string value must be no longer than 6 bytes"
+        )
+        .with_kind(ErrKind::MaxLen)
+        .with_param("max", 6usize)
+        .with_param("actual", 12usize)
+        .with_param("unit", "bytes")])
+    );
+
+    assert_eq!(
+        Delivery::from_json_stack_errs("{}".as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![ValidationErr::new(
+            From::from("method"),
+            Vec::new(),
+            "required field"
+        )
+        .with_kind(ErrKind::Required)])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+#[exactly_one_of(card_token, iban)]
+#[at_most_one_of(
+    coupon,
+    gift_card,
+    msgs{
+        en = "only one discount can be applied",
+        ar = "يمكن تطبيق خصم واحد فقط",
+    },
+)]
+struct Payment {
+    card_token: Option<String>,
+    iban: from::OptionNull<String>,
+    coupon: from::Null<String>,
+    #[default = null]
+    gift_card: from::Null<String>,
+}
+
+#[test]
+fn one_of() {
+    assert_eq!(
+        Payment::from_json(r#"{"iban": "DE00", "coupon": null}"#.as_bytes()).unwrap(),
+        Payment {
+            card_token: None,
+            iban: from::OptionNull::Some(String::from("DE00")),
+            coupon: from::Null::Null,
+            gift_card: from::Null::Null,
+        }
+    );
+
+    // null counts as not provided
+    let json = r#"{"iban": null, "coupon": "A", "gift_card": "B"}"#.as_bytes();
+
+    assert_eq!(
+        Payment::from_json(json).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("card_token"),
+                Vec::new(),
+                "exactly one of 'card_token', 'iban' must be provided"
+            )
+            .with_kind(ErrKind::ExactlyOneOf)
+            .with_param("fields", ["card_token", "iban"]),
+        )
+    );
+
+    // the built-in message falls back to English without 'locale-ar'
+    #[cfg(not(feature = "locale-ar"))]
+    assert_eq!(
+        Payment::from_json_stack_errs_lang(json, "ar").unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("card_token"),
+                Vec::new(),
+                "exactly one of 'card_token', 'iban' must be provided"
+            )
+            .with_kind(ErrKind::ExactlyOneOf)
+            .with_param("fields", ["card_token", "iban"]),
+            ValidationErr::new(From::from("coupon"), Vec::new(), "يمكن تطبيق خصم واحد فقط")
+                .with_kind(ErrKind::AtMostOneOf)
+                .with_param("fields", ["coupon", "gift_card"]),
+        ])
+    );
+
+    assert_eq!(
+        Payment::from_json_stack_errs("{}".as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("coupon"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+            ValidationErr::new(
+                From::from("card_token"),
+                Vec::new(),
+                "exactly one of 'card_token', 'iban' must be provided"
+            )
+            .with_kind(ErrKind::ExactlyOneOf)
+            .with_param("fields", ["card_token", "iban"]),
+        ])
+    );
+
+    assert_eq!(
+        Payment::from_json_stack_errs(
+            r#"{"card_token": "t", "iban": "DE00", "coupon": "A"}"#.as_bytes()
+        )
+        .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("card_token"),
+                Vec::new(),
+                "exactly one of 'card_token', 'iban' must be provided"
+            )
+            .with_kind(ErrKind::ExactlyOneOf)
+            .with_param("fields", ["card_token", "iban"]),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+#[exactly_one_of(card_token, iban)]
+#[at_most_one_of(coupon, gift_card)]
+struct Checkout {
+    #[min_len(value = 4)]
+    card_token: Option<String>,
+
+    iban: Option<String>,
+
+    #[max_len(value = 3)]
+    coupon: Option<String>,
+
+    gift_card: Option<String>,
+}
+
+#[test]
+fn one_of_invalid_field() {
+    // only the error of 'card_token', it is not counted as missing
+    assert_eq!(
+        Checkout::from_json_stack_errs(r#"{"card_token": "t"}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![ValidationErr::new(
+            From::from("card_token"),
+            Vec::new(),
+            "the string value must be at least 4 bytes long"
+        )
+        .with_kind(ErrKind::MinLen)
+        .with_param("min", 4usize)
+        .with_param("actual", 1usize)
+        .with_param("unit", "bytes")])
+    );
+
+    assert_eq!(
+        Checkout::from_json_stack_errs(
+            r#"{"iban": 5, "coupon": "ABCD", "gift_card": "G"}"#.as_bytes()
+        )
+        .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("iban"), Vec::new(), "expected: string, found: number")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "string")
+                .with_param("found", "number"),
+            ValidationErr::new(
+                From::from("coupon"),
+                Vec::new(),
+                "the string value must be no longer than 3 bytes"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 3usize)
+            .with_param("actual", 4usize)
+            .with_param("unit", "bytes"),
+        ])
+    );
+
+    // the fields without errors are still counted
+    assert_eq!(
+        Checkout::from_json_stack_errs(
+            r#"{"card_token": "t", "coupon": "A", "gift_card": "G"}"#.as_bytes()
+        )
+        .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("card_token"),
+                Vec::new(),
+                "the string value must be at least 4 bytes long"
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 4usize)
+            .with_param("actual", 1usize)
+            .with_param("unit", "bytes"),
+            ValidationErr::new(
+                From::from("coupon"),
+                Vec::new(),
+                "at most one of 'coupon', 'gift_card' can be provided"
+            )
+            .with_kind(ErrKind::AtMostOneOf)
+            .with_param("fields", ["coupon", "gift_card"]),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Schedule {
+    period: Period,
+
+    history: Vec<Period>,
+
+    name: String,
+}
+
+#[test]
+fn nested_followed_by_fields() {
+    let json = r#"{
+        "period": {"start": 1, "end": 2},
+        "history": [{"start": 3, "end": 200}, {}, {"start": 5, "end": 6}],
+        "name": "weekly"
+    }"#
+    .as_bytes();
+
+    assert_eq!(
+        Schedule::from_json_stack_errs(json).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("end"),
+                vec![From::from("history"), From::from(0)],
+                "number must be less than or equal to 100"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 100usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 200usize),
+            ValidationErr::new(
+                From::from("start"),
+                vec![From::from("history"), From::from(1)],
+                "required field"
+            )
+            .with_kind(ErrKind::Required),
+            ValidationErr::new(
+                From::from("end"),
+                vec![From::from("history"), From::from(1)],
+                "required field"
+            )
+            .with_kind(ErrKind::Required),
+        ])
+    );
+
+    let json = r#"{"period": {"start": 1, "end": 2}, "history": [], "name": "weekly"}"#;
+
+    assert_eq!(
+        Schedule::from_json(json.as_bytes()).unwrap(),
+        Schedule {
+            period: Period { start: 1, end: 2 },
+            history: Vec::new(),
+            name: String::from("weekly"),
+        }
+    );
+}
+
+// forwards to the derived methods, so it leaves the index after the
+// closing '}' like any other value
+#[derive(Debug, PartialEq)]
+struct Slot(Period);
+
+impl FromJsonValue for Slot {
+    fn from_json_value(json: &[u8], idx: &mut usize, path: &Path) -> Result<Self, from::Err> {
+        Period::from_json_value(json, idx, path).map(Slot)
+    }
+
+    fn from_json_value_lang(
+        json: &[u8],
+        idx: &mut usize,
+        path: &Path,
+        lang: &str,
+    ) -> Result<Self, from::Err> {
+        Period::from_json_value_lang(json, idx, path, lang).map(Slot)
+    }
+
+    fn from_json_value_stack_errs(
+        json: &[u8],
+        idx: &mut usize,
+        path: &Path,
+    ) -> Result<Self, from::Errs> {
+        Period::from_json_value_stack_errs(json, idx, path).map(Slot)
+    }
+
+    fn from_json_value_stack_errs_lang(
+        json: &[u8],
+        idx: &mut usize,
+        path: &Path,
+        lang: &str,
+    ) -> Result<Self, from::Errs> {
+        Period::from_json_value_stack_errs_lang(json, idx, path, lang).map(Slot)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Agenda {
+    #[validate(skip)]
+    slot: Slot,
+
+    #[validate(skip)]
+    slots: Vec<Slot>,
+
+    name: String,
+}
+
+#[test]
+fn value_index_after_object() {
+    let json = r#"{"slot": {"start": 1, "end": 2}, "slots": [{"start": 3, "end": 4}, {"start": 5, "end": 6}], "name": "daily"}"#.as_bytes();
+
+    let expected = Agenda {
+        slot: Slot(Period { start: 1, end: 2 }),
+        slots: vec![
+            Slot(Period { start: 3, end: 4 }),
+            Slot(Period { start: 5, end: 6 }),
+        ],
+        name: String::from("daily"),
+    };
+
+    assert_eq!(Agenda::from_json(json).unwrap(), expected);
+    assert_eq!(Agenda::from_json_lang(json, "en").unwrap(), expected);
+    assert_eq!(Agenda::from_json_stack_errs(json).unwrap(), expected);
+    assert_eq!(Agenda::from_json_stack_errs_lang(json, "en").unwrap(), expected);
+
+    // the objects that fail their validation are skipped as a whole
+    let json = r#"{"slot": {"start": 2, "end": 1}, "slots": [{"start": 3, "end": 200}, {}], "name": "daily"}"#.as_bytes();
+
+    assert_eq!(
+        Agenda::from_json_stack_errs(json).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("end"), vec![From::from("slot")], "end must be after start"),
+            ValidationErr::new(
+                From::from("end"),
+                vec![From::from("slots"), From::from(0)],
+                "number must be less than or equal to 100"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 100usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 200usize),
+            ValidationErr::new(
+                From::from("start"),
+                vec![From::from("slots"), From::from(1)],
+                "required field"
+            )
+            .with_kind(ErrKind::Required),
+            ValidationErr::new(
+                From::from("end"),
+                vec![From::from("slots"), From::from(1)],
+                "required field"
+            )
+            .with_kind(ErrKind::Required),
+        ])
+    );
+}
@@ -0,0 +1,125 @@
+use from::{from, Err, ErrKind, Errs, FromJson, Messages, SyntaxErr, SyntaxErrKind};
+
+struct Catalog;
+
+impl Messages for Catalog {
+    fn template(_kind: ErrKind, _lang: &str) -> Option<&'static str> {
+        None
+    }
+
+    fn syntax_template(kind: &SyntaxErrKind, lang: &str) -> Option<&'static str> {
+        match (kind, lang) {
+            (SyntaxErrKind::UnexpectedToken { .. }, "ar") => {
+                Some("المتوقع {expected} عند {offset}، المستلم {found}")
+            }
+            (SyntaxErrKind::UnexpectedEnd, "ar") => Some("نهاية غير متوقعة"),
+            (SyntaxErrKind::UnexpectedEnd, "de") => Some("unerwartetes Ende"),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json, messages = Catalog)]
+struct Point {
+    x: i32,
+    label: String,
+}
+
+fn syntax_err(res: Result<Point, Err>) -> SyntaxErr {
+    match res {
+        Err(Err::SyntaxErr(err)) => err,
+        res => panic!("expected a syntax error, found: {:?}", res),
+    }
+}
+
+#[test]
+fn kinds() {
+    let err = syntax_err(Point::from_json(br#"{"x": 1 "label": ""}"#));
+    assert_eq!(
+        err,
+        SyntaxErr::unexpected_tokens(&["','", "'}'"], b"\"", &mut 8)
+    );
+    assert_eq!(err.msg, "expected: ',' or '}', found: \"");
+    assert_eq!(
+        err.kind,
+        SyntaxErrKind::UnexpectedToken {
+            expected: vec![String::from("','"), String::from("'}'")],
+            found: String::from("\""),
+        }
+    );
+
+    // the kind is compared too
+    assert_ne!(
+        err,
+        SyntaxErr::new("expected: ',' or '}', found: \"", &mut 8)
+    );
+
+    let err = syntax_err(Point::from_json(br#"{"x": 1, "label": "#));
+    assert_eq!(err.kind, SyntaxErrKind::UnexpectedEnd);
+
+    let err = syntax_err(Point::from_json(br#"{"x": 1, "label": "\q"}"#));
+    assert_eq!(err.kind, SyntaxErrKind::InvalidEscape);
+
+    let err = syntax_err(Point::from_json(br#"{"x": 1, "label": "\u00g1"}"#));
+    assert_eq!(err.kind, SyntaxErrKind::InvalidEscape);
+
+    let err = syntax_err(Point::from_json(b"{\"x\": 1, \"label\": \"\xff\"}"));
+    assert_eq!(err.kind, SyntaxErrKind::InvalidUtf8);
+
+    let err = syntax_err(Point::from_json(br#"{"x": @, "label": ""}"#));
+    assert_eq!(err.kind, SyntaxErrKind::InvalidNumber);
+}
+
+#[test]
+fn to_json() {
+    let err = syntax_err(Point::from_json(br#"{"x": 1 "label": ""}"#));
+    assert_eq!(
+        err.to_json(),
+        r#"{"msg":"expected: ',' or '}', found: \"","offset":8,"code":"unexpected_token","params":{"expected":["','","'}'"],"found":"\""}}"#
+    );
+
+    let err = syntax_err(Point::from_json(br#"{"x": 1, "label": "#));
+    assert_eq!(
+        err.to_json(),
+        r#"{"msg":"unexpected end of json input","offset":18,"code":"unexpected_end"}"#
+    );
+}
+
+#[test]
+fn localized() {
+    let json = br#"{"x": 1 "label": ""}"#;
+
+    assert_eq!(
+        syntax_err(Point::from_json_lang(json, "ar-EG")).msg,
+        // the expected alternatives are joined in the language
+        "المتوقع ',' أو '}' عند 8، المستلم \""
+    );
+
+    // the methods without 'lang' keep the built-in message
+    assert_eq!(
+        syntax_err(Point::from_json(json)).msg,
+        "expected: ',' or '}', found: \""
+    );
+
+    // 'it' has no template of the kind
+    assert_eq!(
+        syntax_err(Point::from_json_lang(json, "it")).msg,
+        "expected: ',' or '}', found: \""
+    );
+
+    let json = br#"{"x": 1, "label": "#;
+
+    assert_eq!(
+        Point::from_json_stack_errs_lang(json, "de,ar"),
+        Err(Errs::SyntaxErr(
+            SyntaxErr::new("unerwartetes Ende", &mut 18).with_kind(SyntaxErrKind::UnexpectedEnd)
+        ))
+    );
+
+    // the default language ends the negotiation
+    assert_eq!(
+        syntax_err(Point::from_json_langs(json, &["en", "ar"])).msg,
+        "unexpected end of json input"
+    );
+}
@@ -1,6 +1,20 @@
 mod boolean;
+mod ctx;
 mod custom;
+mod error_codes;
 mod floats;
+mod groups;
 mod ints;
+mod lang;
+mod locales;
+mod messages;
+mod msgs_placeholders;
+mod patch;
+mod param_validators;
+mod pattern;
 mod string;
+mod syntax_errs;
+mod structure;
+mod translations;
+mod validate;
 mod vec;
@@ -0,0 +1,97 @@
+#![cfg(all(feature = "locale-ar", feature = "locale-fr"))]
+
+use from::{from, Err, Errs, FromJson, SyntaxErr, SyntaxErrKind, ValidationErr};
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Order {
+    #[max_len(value = 5, unit = "chars")]
+    reference: String,
+
+    #[min(value = 1, exclusive)]
+    quantity: u8,
+
+    #[max(value = 10, msgs{fr="trop grand"})]
+    discount: u8,
+
+    #[min_len(value = 2)]
+    items: Vec<u32>,
+}
+
+fn msgs(json: &str, lang: &str) -> Vec<String> {
+    match Order::from_json_stack_errs_lang(json.as_bytes(), lang) {
+        Err(Errs::ValidationErrs(errs)) => errs.into_iter().map(|e: ValidationErr| e.msg).collect(),
+        res => panic!("expected validation errors, found: {:?}", res),
+    }
+}
+
+#[test]
+fn translations() {
+    let json = r#"{"reference": "abcdef", "quantity": 1, "discount": 20, "items": []}"#;
+
+    assert_eq!(
+        msgs(json, "fr-FR"),
+        vec![
+            "la chaîne ne doit pas dépasser 5 caractères",
+            "le nombre doit être supérieur à 1",
+            // the custom messages take precedence
+            "trop grand",
+            "le tableau doit contenir au moins 2 éléments",
+        ]
+    );
+
+    assert_eq!(
+        msgs(json, "ar"),
+        vec![
+            "الحد الأقصى لعدد أحرف النص: 5",
+            "يجب أن يكون الرقم أكبر من 1",
+            // a field with custom messages falls back to its own
+            // default message
+            "number must be less than or equal to 10",
+            "الحد الأدنى لعدد عناصر المصفوفة: 2",
+        ]
+    );
+
+    // the default language is not translated
+    assert_eq!(msgs(json, "en")[1], "number must be greater than 1");
+
+    assert_eq!(
+        msgs(r#"{"reference": 5, "quantity": null, "discount": 1, "items": [1, 2]}"#, "fr"),
+        vec!["attendu : string, reçu : number", "attendu : u8, reçu : null"]
+    );
+}
+
+#[test]
+fn syntax_errs() {
+    let json = r#"{"reference": "abc" "quantity": 2}"#.as_bytes();
+
+    assert_eq!(
+        Order::from_json_lang(json, "fr-CA"),
+        Err(Err::SyntaxErr(
+            SyntaxErr::new("attendu : ',' ou '}', reçu : \"", &mut 20).with_kind(
+                SyntaxErrKind::UnexpectedToken {
+                    expected: vec![String::from("','"), String::from("'}'")],
+                    found: String::from("\""),
+                }
+            )
+        ))
+    );
+
+    assert_eq!(
+        Order::from_json_stack_errs_lang(r#"{"reference": "ab"#.as_bytes(), "ar"),
+        Err(Errs::SyntaxErr(
+            SyntaxErr::new("نهاية غير متوقعة لمدخلات JSON", &mut 17)
+                .with_kind(SyntaxErrKind::UnexpectedEnd)
+        ))
+    );
+
+    // the methods without 'lang' are not translated
+    assert_eq!(
+        Order::from_json(json),
+        Err(Err::SyntaxErr(SyntaxErr::unexpected_tokens(
+            &["','", "'}'"],
+            b"\"",
+            &mut 20
+        )))
+    );
+}
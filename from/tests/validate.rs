@@ -0,0 +1,343 @@
+use from::{
+    from, json::FromJsonValue, Err, ErrKind, Errs, FromJson, Null, OptionNull, Path, Validate,
+    ValidationErr,
+};
+
+struct NotAdmin;
+
+impl ::from::Validator<String> for NotAdmin {
+    fn none(val: &String, path: &Path) -> Result<(), ValidationErr> {
+        if val == "admin" {
+            return Err(ValidationErr::new(
+                From::from("name"),
+                path.clone(),
+                "reserved name",
+            ));
+        };
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Address {
+    #[min_len(value = 2)]
+    city: String,
+
+    #[max(value = 99999)]
+    zip: Option<u32>,
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+#[at_most_one_of(email, phone)]
+struct User {
+    #[trim]
+    #[min_len(
+        value = 3,
+        msgs{
+            en = "name is too short",
+            ar = "الاسم قصير جدًا",
+        }
+    )]
+    #[validators(NotAdmin)]
+    name: String,
+
+    #[max(value = 120)]
+    age: u8,
+
+    email: Option<String>,
+
+    phone: Option<String>,
+
+    #[max_len(value = 2)]
+    #[elem(min(value = 1))]
+    scores: Vec<Null<u8>>,
+
+    address: Address,
+
+    others: Vec<Address>,
+}
+
+fn user() -> User {
+    User {
+        name: String::from("Alice"),
+        age: 30,
+        email: Some(String::from("alice@example.com")),
+        phone: None,
+        scores: vec![Null::Some(1), Null::Null],
+        address: Address {
+            city: String::from("Cairo"),
+            zip: Some(11511),
+        },
+        others: Vec::new(),
+    }
+}
+
+#[test]
+fn valid() {
+    let user = user();
+
+    assert_eq!(user.validate(), Ok(()));
+    assert_eq!(user.validate_lang("ar"), Ok(()));
+    assert_eq!(user.validate_stack_errs(), Ok(()));
+    assert_eq!(user.validate_stack_errs_lang("ar"), Ok(()));
+}
+
+#[test]
+fn invalid() {
+    let mut user = user();
+
+    // sanitizers are not applied
+    user.name = String::from(" ab ");
+    user.age = 121;
+    user.phone = Some(String::from("123"));
+    user.scores = vec![Null::Some(0), Null::Null, Null::Some(3)];
+    user.address.zip = Some(100000);
+    user.others.push(Address {
+        city: String::from("A"),
+        zip: None,
+    });
+
+    assert_eq!(
+        user.validate(),
+        Err(ValidationErr::new(
+            From::from("age"),
+            Vec::new(),
+            "number must be less than or equal to 120"
+        )
+        .with_kind(ErrKind::Max)
+        .with_param("max", 120usize)
+        .with_param("exclusive", false)
+        .with_param("actual", 121usize))
+    );
+
+    user.age = 120;
+
+    // the built-in messages fall back to English without 'locale-ar'
+    #[cfg(not(feature = "locale-ar"))]
+    assert_eq!(
+        user.validate_lang("ar"),
+        Err(ValidationErr::new(
+            From::from(0),
+            vec![From::from("scores")],
+            "number must be greater than or equal to 1"
+        )
+        .with_kind(ErrKind::Min)
+        .with_param("min", 1usize)
+        .with_param("exclusive", false)
+        .with_param("actual", 0usize))
+    );
+
+    user.name = String::from("ab");
+    user.age = 121;
+
+    #[cfg(not(feature = "locale-ar"))]
+    assert_eq!(
+        user.validate_stack_errs_lang("ar"),
+        Err(vec![
+            ValidationErr::new(From::from("name"), Vec::new(), "الاسم قصير جدًا")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 3usize)
+                .with_param("actual", 2usize)
+                .with_param("unit", "bytes"),
+            ValidationErr::new(
+                From::from("age"),
+                Vec::new(),
+                "number must be less than or equal to 120"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 120usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 121usize),
+            ValidationErr::new(
+                From::from(0),
+                vec![From::from("scores")],
+                "number must be greater than or equal to 1"
+            )
+            .with_kind(ErrKind::Min)
+            .with_param("min", 1usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 0usize),
+            ValidationErr::new(
+                From::from("scores"),
+                Vec::new(),
+                "array must not contains more than 2 elements"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 2usize)
+            .with_param("actual", 3usize),
+            ValidationErr::new(
+                From::from("zip"),
+                vec![From::from("address")],
+                "number must be less than or equal to 99999"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 99999usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 100000usize),
+            ValidationErr::new(
+                From::from("city"),
+                vec![From::from("others"), From::from(0)],
+                "the string value must be at least 2 bytes long"
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 2usize)
+            .with_param("actual", 1usize)
+            .with_param("unit", "bytes"),
+            ValidationErr::new(
+                From::from("email"),
+                Vec::new(),
+                "at most one of 'email', 'phone' can be provided"
+            )
+            .with_kind(ErrKind::AtMostOneOf)
+            .with_param("fields", ["email", "phone"]),
+        ])
+    );
+
+    user.name = String::from("admin");
+
+    assert_eq!(
+        user.validate_stack_errs().unwrap_err()[0],
+        ValidationErr::new(From::from("name"), Vec::new(), "reserved name")
+    );
+}
+
+#[test]
+fn nested_invalid() {
+    let mut user = user();
+
+    // the nested values are checked without any attribute
+    user.address.zip = Some(100000);
+
+    let err = ValidationErr::new(
+        From::from("zip"),
+        vec![From::from("address")],
+        "number must be less than or equal to 99999",
+    )
+    .with_kind(ErrKind::Max)
+    .with_param("max", 99999usize)
+    .with_param("exclusive", false)
+    .with_param("actual", 100000usize);
+
+    assert_eq!(user.validate(), Err(err.clone()));
+
+    user.address.zip = None;
+    user.others.push(Address {
+        city: String::from("Giza"),
+        zip: Some(100000),
+    });
+
+    assert_eq!(
+        user.validate_stack_errs(),
+        Err(vec![ValidationErr {
+            path: vec![From::from("others"), From::from(0)],
+            ..err
+        }])
+    );
+}
+
+// implements 'FromJsonValue' only, so the fields of this type opt out
+// of 'Validate' with 'validate(skip)'
+#[derive(Debug, PartialEq)]
+struct Cents(u32);
+
+impl Cents {
+    fn parse(json: &[u8], idx: &mut usize, path: &Path) -> Result<Self, Err> {
+        match from::json::u32::parse(json, idx) {
+            from::json::u32::ParseResult::Ok(val) => Ok(Cents(val)),
+            from::json::u32::ParseResult::SyntaxErr(e) => Err(Err::SyntaxErr(e)),
+            _ => Err(Err::new_validation_err(
+                From::from("price"),
+                path.clone(),
+                "invalid amount",
+            )),
+        }
+    }
+
+    fn parse_stack_errs(json: &[u8], idx: &mut usize, path: &Path) -> Result<Self, Errs> {
+        Self::parse(json, idx, path).map_err(|err| match err {
+            Err::SyntaxErr(e) => Errs::SyntaxErr(e),
+            Err::ValidationErr(e) => Errs::ValidationErrs(vec![e]),
+        })
+    }
+}
+
+impl FromJsonValue for Cents {
+    fn from_json_value(json: &[u8], idx: &mut usize, path: &Path) -> Result<Self, Err> {
+        Self::parse(json, idx, path)
+    }
+
+    fn from_json_value_lang(
+        json: &[u8],
+        idx: &mut usize,
+        path: &Path,
+        _lang: &str,
+    ) -> Result<Self, Err> {
+        Self::parse(json, idx, path)
+    }
+
+    fn from_json_value_stack_errs(json: &[u8], idx: &mut usize, path: &Path) -> Result<Self, Errs> {
+        Self::parse_stack_errs(json, idx, path)
+    }
+
+    fn from_json_value_stack_errs_lang(
+        json: &[u8],
+        idx: &mut usize,
+        path: &Path,
+        _lang: &str,
+    ) -> Result<Self, Errs> {
+        Self::parse_stack_errs(json, idx, path)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json, patch = UpdateOrder)]
+struct Order {
+    #[validate(skip)]
+    price: Cents,
+
+    #[validate(skip)]
+    prices: Vec<Cents>,
+
+    address: Option<Address>,
+}
+
+#[test]
+fn custom_skip_validate() {
+    let order = Order::from_json(r#"{"price": 0, "prices": [1, 2]}"#.as_bytes()).unwrap();
+
+    assert_eq!(
+        order,
+        Order {
+            price: Cents(0),
+            prices: vec![Cents(1), Cents(2)],
+            address: None,
+        }
+    );
+
+    assert_eq!(order.validate(), Ok(()));
+
+    let update = UpdateOrder {
+        price: OptionNull::Some(Cents(1)),
+        prices: OptionNull::None,
+        address: OptionNull::Some(Address {
+            city: String::from("A"),
+            zip: None,
+        }),
+    };
+
+    assert_eq!(
+        update.validate_stack_errs(),
+        Err(vec![ValidationErr::new(
+            From::from("city"),
+            vec![From::from("address")],
+            "the string value must be at least 2 bytes long"
+        )
+        .with_kind(ErrKind::MinLen)
+        .with_param("min", 2usize)
+        .with_param("actual", 1usize)
+        .with_param("unit", "bytes")])
+    );
+}
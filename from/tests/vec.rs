@@ -1,4 +1,4 @@
-use from::{from, FromJson, Null, OptionNull, Path, ValidationErr};
+use from::{from, ErrKind, FromJson, Null, OptionNull, Path, ValidationErr};
 
 #[derive(Debug, PartialEq)]
 #[from(json)]
@@ -79,39 +79,59 @@ fn max_len_valid() {
 fn max_len_invalid() {
     assert_eq!(
         MaxLen::from_json(r#"{"field": [true, true, false]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "array must not contains more than 2 elements",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must not contains more than 2 elements",
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 2usize)
+            .with_param("actual", 3usize),
         )
     );
 
     assert_eq!(
         MaxLen::from_json_lang(r#"{"field": [true, true, false]}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "array must not contains more than 2 elements",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must not contains more than 2 elements",
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 2usize)
+            .with_param("actual", 3usize),
         )
     );
 
     assert_eq!(
         MaxLen::from_json_stack_errs(r#"{"field": [true, true, false]}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "array must not contains more than 2 elements",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must not contains more than 2 elements",
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 2usize)
+            .with_param("actual", 3usize),
+        ])
     );
 
     assert_eq!(
         MaxLen::from_json_stack_errs_lang(r#"{"field": [true, true, false]}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "array must not contains more than 2 elements",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must not contains more than 2 elements",
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 2usize)
+            .with_param("actual", 3usize),
+        ])
     );
 }
 
@@ -161,19 +181,34 @@ fn max_len_overwrite_msg_valid() {
 fn max_len_overwrite_msg_invalid() {
     assert_eq!(
         MaxLenOverwriteMsg::from_json(r#"{"field": [true, true, false]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "too many elements",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "too many elements")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 2usize)
+                .with_param("actual", 3usize),
+        )
     );
 
     assert_eq!(
         MaxLenOverwriteMsg::from_json_lang(r#"{"field": [true, true, false]}"#.as_bytes(), "ar")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "عناصر كثيرة جداً",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "عناصر كثيرة جداً")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 2usize)
+                .with_param("actual", 3usize),
+        )
     );
 
     assert_eq!(
         MaxLenOverwriteMsg::from_json_stack_errs(r#"{"field": [true, true, false]}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "too many elements",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "too many elements")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 2usize)
+                .with_param("actual", 3usize),
+        ])
     );
 
     assert_eq!(
@@ -182,7 +217,12 @@ fn max_len_overwrite_msg_invalid() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "too many elements",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "too many elements")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 2usize)
+                .with_param("actual", 3usize),
+        ])
     );
 }
 
@@ -236,7 +276,12 @@ fn max_len_overwrite_msg_dflt_lang_invalid() {
     assert_eq!(
         MaxLenOverwriteMsgDfltLang::from_json(r#"{"field": [true, true, false]}"#.as_bytes())
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "عناصر كثيرة جداً",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "عناصر كثيرة جداً")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 2usize)
+                .with_param("actual", 3usize),
+        )
     );
 
     assert_eq!(
@@ -245,7 +290,12 @@ fn max_len_overwrite_msg_dflt_lang_invalid() {
             "en"
         )
         .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "too many elements",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "too many elements")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 2usize)
+                .with_param("actual", 3usize),
+        )
     );
 
     assert_eq!(
@@ -253,7 +303,12 @@ fn max_len_overwrite_msg_dflt_lang_invalid() {
             r#"{"field": [true, true, false]}"#.as_bytes()
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "عناصر كثيرة جداً",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "عناصر كثيرة جداً")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 2usize)
+                .with_param("actual", 3usize),
+        ])
     );
 
     assert_eq!(
@@ -262,7 +317,12 @@ fn max_len_overwrite_msg_dflt_lang_invalid() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "too many elements",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "too many elements")
+                .with_kind(ErrKind::MaxLen)
+                .with_param("max", 2usize)
+                .with_param("actual", 3usize),
+        ])
     );
 }
 
@@ -300,38 +360,58 @@ fn min_len_valid() {
 fn min_len_invalid() {
     assert_eq!(
         MinLen::from_json(r#"{"field": [10]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "array must not contains less than 2 elements",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must not contains less than 2 elements",
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 2usize)
+            .with_param("actual", 1usize),
         )
     );
 
     assert_eq!(
         MinLen::from_json_lang(r#"{"field": [  ]}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "array must not contains less than 2 elements",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must not contains less than 2 elements",
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 2usize)
+            .with_param("actual", 0usize),
         )
     );
 
     assert_eq!(
         MinLen::from_json_stack_errs(r#"{"field": []}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "array must not contains less than 2 elements",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must not contains less than 2 elements",
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 2usize)
+            .with_param("actual", 0usize),
+        ])
     );
 
     assert_eq!(
         MinLen::from_json_stack_errs_lang(r#"{"field": [15]}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "array must not contains less than 2 elements",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must not contains less than 2 elements",
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 2usize)
+            .with_param("actual", 1usize),
+        ])
     );
 }
 
@@ -370,23 +450,43 @@ fn min_len_overwrite_msg_valid() {
 fn min_len_overwrite_msg_invalid() {
     assert_eq!(
         MinLenOverwriteMsg::from_json(r#"{"field": [43]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "too short",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "too short")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 2usize)
+                .with_param("actual", 1usize),
+        )
     );
 
     assert_eq!(
         MinLenOverwriteMsg::from_json_lang(r#"{"field": [    ]}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "قصير جدا",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "قصير جدا")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 2usize)
+                .with_param("actual", 0usize),
+        )
     );
 
     assert_eq!(
         MinLenOverwriteMsg::from_json_stack_errs("{\"field\": [\n\t ]}".as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "too short",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "too short")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 2usize)
+                .with_param("actual", 0usize),
+        ])
     );
 
     assert_eq!(
         MinLenOverwriteMsg::from_json_stack_errs_lang("{\"field\": [\n\t15\n\t]}".as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "too short",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "too short")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 2usize)
+                .with_param("actual", 1usize),
+        ])
     );
 }
 
@@ -429,25 +529,45 @@ fn min_len_overwrite_msg_dflt_lang_valid() {
 fn min_len_overwrite_msg_dflt_lang_invalid() {
     assert_eq!(
         MinLenOverwriteMsgDfltLang::from_json(r#"{"field": []}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "قصير جدا",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "قصير جدا")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 2usize)
+                .with_param("actual", 0usize),
+        )
     );
 
     assert_eq!(
         MinLenOverwriteMsgDfltLang::from_json_lang(r#"{"field": []}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "too short",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "too short")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 2usize)
+                .with_param("actual", 0usize),
+        )
     );
 
     assert_eq!(
         MinLenOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": []}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "قصير جدا",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "قصير جدا")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 2usize)
+                .with_param("actual", 0usize),
+        ])
     );
 
     assert_eq!(
         MinLenOverwriteMsgDfltLang::from_json_stack_errs_lang(r#"{"field": []}"#.as_bytes(), "ar")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "قصير جدا",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "قصير جدا")
+                .with_kind(ErrKind::MinLen)
+                .with_param("min", 2usize)
+                .with_param("actual", 0usize),
+        ])
     );
 }
 
@@ -493,38 +613,58 @@ fn len_valid() {
 fn len_invalid() {
     assert_eq!(
         Len::from_json(r#"{"field": [1.5, 2.3, 4.2]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "array must contains 2 elements",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must contains 2 elements",
+            )
+            .with_kind(ErrKind::Len)
+            .with_param("len", 2usize)
+            .with_param("actual", 3usize),
         )
     );
 
     assert_eq!(
         Len::from_json_lang(r#"{"field": [-7.3, 2.7, 33.7]}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "array must contains 2 elements",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must contains 2 elements",
+            )
+            .with_kind(ErrKind::Len)
+            .with_param("len", 2usize)
+            .with_param("actual", 3usize),
         )
     );
 
     assert_eq!(
         Len::from_json_stack_errs(r#"{"field": [8]}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "array must contains 2 elements",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must contains 2 elements",
+            )
+            .with_kind(ErrKind::Len)
+            .with_param("len", 2usize)
+            .with_param("actual", 1usize),
+        ])
     );
 
     assert_eq!(
         Len::from_json_stack_errs_lang(r#"{"field": []}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "array must contains 2 elements",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must contains 2 elements",
+            )
+            .with_kind(ErrKind::Len)
+            .with_param("len", 2usize)
+            .with_param("actual", 0usize),
+        ])
     );
 }
 
@@ -571,17 +711,32 @@ fn len_overwrite_msg_valid() {
 fn len_overwrite_msg_invalid() {
     assert_eq!(
         LenOverwriteMsg::from_json(r#"{"field": []}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "invalid length",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid length")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 2usize)
+                .with_param("actual", 0usize),
+        )
     );
 
     assert_eq!(
         LenOverwriteMsg::from_json_lang(r#"{"field": [5]}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "طول غير صالح",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "طول غير صالح")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 2usize)
+                .with_param("actual", 1usize),
+        )
     );
 
     assert_eq!(
         LenOverwriteMsg::from_json_stack_errs(r#"{"field": [7.0]}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "invalid length",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid length")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 2usize)
+                .with_param("actual", 1usize),
+        ])
     );
 
     assert_eq!(
@@ -590,7 +745,12 @@ fn len_overwrite_msg_invalid() {
             "ar"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "طول غير صالح",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "طول غير صالح")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 2usize)
+                .with_param("actual", 3usize),
+        ])
     );
 }
 
@@ -638,24 +798,360 @@ fn len_overwrite_msg_dflt_lang_valid() {
 fn len_overwrite_msg_dflt_lang_invalid() {
     assert_eq!(
         LenOverwriteMsgDfltLang::from_json(r#"{"field": []}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "طول غير صالح",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "طول غير صالح")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 2usize)
+                .with_param("actual", 0usize),
+        )
     );
 
     assert_eq!(
         LenOverwriteMsgDfltLang::from_json_lang(r#"{"field": [-8]}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "invalid length",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid length")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 2usize)
+                .with_param("actual", 1usize),
+        )
     );
 
     assert_eq!(
         LenOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": [-9e3]}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "طول غير صالح",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "طول غير صالح")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 2usize)
+                .with_param("actual", 1usize),
+        ])
     );
 
     assert_eq!(
         LenOverwriteMsgDfltLang::from_json_stack_errs_lang(r#"{"field": [-7e3]}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "invalid length",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid length")
+                .with_kind(ErrKind::Len)
+                .with_param("len", 2usize)
+                .with_param("actual", 1usize),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Contains {
+    #[contains(values = ["read", "write"])]
+    field: Vec<String>,
+}
+
+#[test]
+fn contains_valid() {
+    assert_eq!(
+        Contains::from_json(r#"{"field": ["write", "exec", "read"]}"#.as_bytes()).unwrap(),
+        Contains {
+            field: vec![
+                String::from("write"),
+                String::from("exec"),
+                String::from("read")
+            ],
+        }
+    );
+
+    assert_eq!(
+        Contains::from_json_stack_errs_lang(r#"{"field": ["read", "write"]}"#.as_bytes(), "en")
+            .unwrap(),
+        Contains {
+            field: vec![String::from("read"), String::from("write")],
+        }
+    );
+}
+
+#[test]
+fn contains_invalid() {
+    assert_eq!(
+        Contains::from_json(r#"{"field": ["read"]}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must contain: \"write\"",
+            )
+            .with_kind(ErrKind::Contains)
+            .with_param("missing", "write"),
+        )
+    );
+
+    assert_eq!(
+        Contains::from_json_lang(r#"{"field": ["write"]}"#.as_bytes(), "en").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must contain: \"read\"",
+            )
+            .with_kind(ErrKind::Contains)
+            .with_param("missing", "read"),
+        )
+    );
+
+    assert_eq!(
+        Contains::from_json_stack_errs(r#"{"field": []}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must contain: \"read\"",
+            )
+            .with_kind(ErrKind::Contains)
+            .with_param("missing", "read"),
+        ])
+    );
+
+    assert_eq!(
+        Contains::from_json_stack_errs_lang(r#"{"field": ["exec"]}"#.as_bytes(), "en").unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must contain: \"read\"",
+            )
+            .with_kind(ErrKind::Contains)
+            .with_param("missing", "read"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct ContainsOverwriteMsg {
+    #[contains(values = [1, 2], msgs{en="missing default ids", ar="المعرفات الافتراضية مفقودة"})]
+    field: Vec<u32>,
+}
+
+#[test]
+fn contains_overwrite_msg() {
+    assert_eq!(
+        ContainsOverwriteMsg::from_json(r#"{"field": [2, 7, 1]}"#.as_bytes()).unwrap(),
+        ContainsOverwriteMsg {
+            field: vec![2, 7, 1],
+        }
+    );
+
+    assert_eq!(
+        ContainsOverwriteMsg::from_json_lang(r#"{"field": [2, 7]}"#.as_bytes(), "ar").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "المعرفات الافتراضية مفقودة",
+            )
+            .with_kind(ErrKind::Contains)
+            .with_param("missing", 1usize),
+        )
+    );
+
+    assert_eq!(
+        ContainsOverwriteMsg::from_json_stack_errs(r#"{"field": [1]}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "missing default ids")
+                .with_kind(ErrKind::Contains)
+                .with_param("missing", 2usize),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Sorted {
+    #[sorted]
+    field: Vec<i32>,
+}
+
+#[test]
+fn sorted_valid() {
+    assert_eq!(
+        Sorted::from_json(r#"{"field": [-3, 1, 1, 5]}"#.as_bytes()).unwrap(),
+        Sorted {
+            field: vec![-3, 1, 1, 5],
+        }
+    );
+
+    assert_eq!(
+        Sorted::from_json_lang(r#"{"field": []}"#.as_bytes(), "en").unwrap(),
+        Sorted { field: vec![] }
+    );
+
+    assert_eq!(
+        Sorted::from_json_stack_errs(r#"{"field": [7]}"#.as_bytes()).unwrap(),
+        Sorted { field: vec![7] }
+    );
+
+    assert_eq!(
+        Sorted::from_json_stack_errs_lang(r#"{"field": [1, 2]}"#.as_bytes(), "en").unwrap(),
+        Sorted { field: vec![1, 2] }
+    );
+}
+
+#[test]
+fn sorted_invalid() {
+    assert_eq!(
+        Sorted::from_json(r#"{"field": [1, 3, 2]}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must be sorted in ascending order (element at index 2 is out of order)",
+            )
+            .with_kind(ErrKind::Sorted)
+            .with_param("index", 2usize),
+        )
+    );
+
+    assert_eq!(
+        Sorted::from_json_lang(r#"{"field": [2, 1]}"#.as_bytes(), "en").unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must be sorted in ascending order (element at index 1 is out of order)",
+            )
+            .with_kind(ErrKind::Sorted)
+            .with_param("index", 1usize),
+        )
+    );
+
+    assert_eq!(
+        Sorted::from_json_stack_errs(r#"{"field": [1, 2, 3, 0]}"#.as_bytes()).unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must be sorted in ascending order (element at index 3 is out of order)",
+            )
+            .with_kind(ErrKind::Sorted)
+            .with_param("index", 3usize),
+        ])
+    );
+
+    assert_eq!(
+        Sorted::from_json_stack_errs_lang(r#"{"field": [5, 4, 3]}"#.as_bytes(), "en").unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "array must be sorted in ascending order (element at index 1 is out of order)",
+            )
+            .with_kind(ErrKind::Sorted)
+            .with_param("index", 1usize),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct SortedDescOverwriteMsg {
+    #[sorted_desc(msgs{en="must be sorted from newest to oldest", ar="يجب الترتيب من الأحدث إلى الأقدم"})]
+    field: Vec<String>,
+}
+
+#[test]
+fn sorted_desc_overwrite_msg() {
+    assert_eq!(
+        SortedDescOverwriteMsg::from_json(r#"{"field": ["2024-12", "2024-01"]}"#.as_bytes()).unwrap(),
+        SortedDescOverwriteMsg {
+            field: vec![String::from("2024-12"), String::from("2024-01")],
+        }
+    );
+
+    assert_eq!(
+        SortedDescOverwriteMsg::from_json_lang(r#"{"field": ["2024-01", "2024-12"]}"#.as_bytes(), "ar")
+            .unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "يجب الترتيب من الأحدث إلى الأقدم",
+            )
+            .with_kind(ErrKind::SortedDesc)
+            .with_param("index", 1usize),
+        )
+    );
+
+    assert_eq!(
+        SortedDescOverwriteMsg::from_json_stack_errs(r#"{"field": ["a", "c"]}"#.as_bytes())
+            .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "must be sorted from newest to oldest",
+            )
+            .with_kind(ErrKind::SortedDesc)
+            .with_param("index", 1usize),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct Transforms {
+    #[dedup]
+    #[sort]
+    #[sorted]
+    #[max_len(value = 3)]
+    field1: Vec<u32>,
+
+    #[sort_desc]
+    #[dedup]
+    #[len(value = 2)]
+    field2: Option<Vec<f64>>,
+
+    #[elem{
+        dedup
+    }]
+    field3: Vec<Vec<String>>,
+}
+
+#[test]
+fn transforms() {
+    assert_eq!(
+        Transforms::from_json(
+            r#"{"field1": [3, 1, 3, 2, 1], "field2": [1.5, 2.5, 1.5], "field3": [["a", "b", "a"], []]}"#
+                .as_bytes()
+        )
+        .unwrap(),
+        Transforms {
+            field1: vec![1, 2, 3],
+            field2: Some(vec![2.5, 1.5]),
+            field3: vec![vec![String::from("a"), String::from("b")], vec![]],
+        }
+    );
+
+    assert_eq!(
+        Transforms::from_json_stack_errs(
+            r#"{"field1": [4, 1, 3, 2], "field2": [1.5], "field3": []}"#.as_bytes()
+        )
+        .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            from::ValidationErr::new(
+                From::from("field1"),
+                Vec::new(),
+                "array must not contains more than 3 elements"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 3usize)
+            .with_param("actual", 4usize),
+            from::ValidationErr::new(
+                From::from("field2"),
+                Vec::new(),
+                "array must contains 2 elements"
+            )
+            .with_kind(ErrKind::Len)
+            .with_param("len", 2usize)
+            .with_param("actual", 1usize),
+        ])
     );
 }
 
@@ -669,25 +1165,37 @@ struct Required {
 fn required() {
     assert_eq!(
         Required::from_json(r#"{"field1": [true, true, null]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         Required::from_json_lang(r#"{"field2": [true, true, false]}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         Required::from_json_stack_errs(r#"{"field4": [true, true, false]}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        ])
     );
 
     assert_eq!(
         Required::from_json_stack_errs_lang(r#"{"field7": [true, true, false]}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "required field",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+        ])
     );
 }
 
@@ -703,23 +1211,35 @@ fn required_overwrite_msg() {
     assert_eq!(
         RequiredOverwriteMsg::from_json(r#"{"field7": [true, true, false]}"#.as_bytes())
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         RequiredOverwriteMsg::from_json_lang(r#"{"field9": [true, true, false]}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         RequiredOverwriteMsg::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        ])
     );
 
     assert_eq!(
         RequiredOverwriteMsg::from_json_stack_errs_lang(r#"{  }"#.as_bytes(), "ar").unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        ])
     );
 }
 
@@ -736,7 +1256,10 @@ fn required_overwrite_msg_dflt_lang() {
     assert_eq!(
         RequiredOverwriteMsgDfltLang::from_json(r#"{"field7": [true, true, false]}"#.as_bytes())
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
@@ -745,18 +1268,27 @@ fn required_overwrite_msg_dflt_lang() {
             "en"
         )
         .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "value is required",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "value is required")
+                .with_kind(ErrKind::Required),
+        )
     );
 
     assert_eq!(
         RequiredOverwriteMsgDfltLang::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        ])
     );
 
     assert_eq!(
         RequiredOverwriteMsgDfltLang::from_json_stack_errs_lang(r#"{  }"#.as_bytes(), "ar")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "الحقل مطلوب",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "الحقل مطلوب")
+                .with_kind(ErrKind::Required),
+        ])
     );
 }
 
@@ -770,82 +1302,127 @@ struct TypeMismatch {
 fn type_mismatch() {
     assert_eq!(
         TypeMismatch::from_json(r#"{"field": 25}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: number",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: number",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "number"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_lang(r#"{"field": 25}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: number",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: number",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "number"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs(r#"{"field": 25}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: number",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: number",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "number"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs_lang(r#"{"field": 25}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: number",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: number",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "number"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json(r#"{"field": true}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: boolean",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: boolean",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "boolean"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_lang(r#"{"field": false}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: boolean",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: boolean",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "boolean"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs(r#"{"field": true}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: boolean",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: boolean",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "boolean"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs_lang(r#"{"field": true}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: boolean",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: boolean",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "boolean"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json(r#"{"field": {this will be ignored}}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: object",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: object",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "object"),
         )
     );
 
@@ -855,21 +1432,31 @@ fn type_mismatch() {
             "en"
         )
         .unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: object",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: object",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "object"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs(r#"{"field": {because it does not care}}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: object",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: object",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "object"),
+        ])
     );
 
     assert_eq!(
@@ -878,48 +1465,73 @@ fn type_mismatch() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: object",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: object",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "object"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json(r#"{"field": "hello"}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: string",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: string",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "string"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_lang(r#"{"field": ""}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: string",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: string",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "string"),
         )
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs(r#"{"field": "noop"}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: string",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: string",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "string"),
+        ])
     );
 
     assert_eq!(
         TypeMismatch::from_json_stack_errs_lang(r#"{"field": "hello\t"}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: string",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: string",
+            )
+            .with_kind(ErrKind::TypeMismatch)
+            .with_param("expected", "array")
+            .with_param("found", "string"),
+        ])
     );
 }
 
@@ -934,23 +1546,43 @@ struct TypeMismatchOverwriteMsg {
 fn type_mismatch_overwrite_msg() {
     assert_eq!(
         TypeMismatchOverwriteMsg::from_json(r#"{"field": false}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "invalid type",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid type")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "array")
+                .with_param("found", "boolean"),
+        )
     );
 
     assert_eq!(
         TypeMismatchOverwriteMsg::from_json_lang(r#"{"field": 25}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "نوع غير صالح",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "نوع غير صالح")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "array")
+                .with_param("found", "number"),
+        )
     );
 
     assert_eq!(
         TypeMismatchOverwriteMsg::from_json_stack_errs(r#"{"field": {}}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "invalid type",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid type")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "array")
+                .with_param("found", "object"),
+        ])
     );
 
     assert_eq!(
         TypeMismatchOverwriteMsg::from_json_stack_errs_lang(r#"{"field": ""}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "invalid type",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid type")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "array")
+                .with_param("found", "string"),
+        ])
     );
 }
 
@@ -966,19 +1598,34 @@ struct TypeMismatchOverwriteMsgDfltLang {
 fn type_mismatch_overwrite_msg_dflt_lang() {
     assert_eq!(
         TypeMismatchOverwriteMsgDfltLang::from_json(r#"{"field": false}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "نوع غير صالح",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "نوع غير صالح")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "array")
+                .with_param("found", "boolean"),
+        )
     );
 
     assert_eq!(
         TypeMismatchOverwriteMsgDfltLang::from_json_lang(r#"{"field": 25}"#.as_bytes(), "ar")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "نوع غير صالح",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "نوع غير صالح")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "array")
+                .with_param("found", "number"),
+        )
     );
 
     assert_eq!(
         TypeMismatchOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": {}}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "نوع غير صالح",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "نوع غير صالح")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "array")
+                .with_param("found", "object"),
+        ])
     );
 
     assert_eq!(
@@ -987,7 +1634,12 @@ fn type_mismatch_overwrite_msg_dflt_lang() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "invalid type",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "invalid type")
+                .with_kind(ErrKind::TypeMismatch)
+                .with_param("expected", "array")
+                .with_param("found", "string"),
+        ])
     );
 }
 
@@ -1001,38 +1653,52 @@ struct NotNull {
 fn not_null() {
     assert_eq!(
         NotNull::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: null",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
         )
     );
 
+    // the built-in message is translated with 'locale-ar'
+    #[cfg(not(feature = "locale-ar"))]
     assert_eq!(
         NotNull::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: null",
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
         )
     );
 
     assert_eq!(
         NotNull::from_json_stack_errs(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: null",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
+        ])
     );
 
     assert_eq!(
         NotNull::from_json_stack_errs_lang(r#"{"field": null}"#.as_bytes(), "en").unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "expected: array, found: null",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "expected: array, found: null",
+            )
+            .with_kind(ErrKind::NotNull),
+        ])
     );
 }
 
@@ -1047,23 +1713,35 @@ struct NotNullOverwriteMsg {
 fn not_null_overwrite_msg() {
     assert_eq!(
         NotNullOverwriteMsg::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsg::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar").unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsg::from_json_stack_errs(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        ])
     );
 
     assert_eq!(
         NotNullOverwriteMsg::from_json_stack_errs_lang(r#"{"field": null}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        ])
     );
 }
 
@@ -1079,23 +1757,32 @@ struct NotNullOverwriteMsgDfltLang {
 fn not_null_overwrite_msg_dflt_lang() {
     assert_eq!(
         NotNullOverwriteMsgDfltLang::from_json(r#"{"field": null}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsgDfltLang::from_json_lang(r#"{"field": null}"#.as_bytes(), "ar")
             .unwrap_err(),
-        from::Err::new_validation_err(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة",)
+        from::Err::ValidationErr(
+            ValidationErr::new(From::from("field"), Vec::new(), "القيمة الخالية غير مسموحة")
+                .with_kind(ErrKind::NotNull),
+        )
     );
 
     assert_eq!(
         NotNullOverwriteMsgDfltLang::from_json_stack_errs(r#"{"field": null}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from("field"),
-            Vec::new(),
-            "القيمة الخالية غير مسموحة",
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from("field"),
+                Vec::new(),
+                "القيمة الخالية غير مسموحة",
+            )
+            .with_kind(ErrKind::NotNull),
+        ])
     );
 
     assert_eq!(
@@ -1104,7 +1791,10 @@ fn not_null_overwrite_msg_dflt_lang() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(From::from("field"), Vec::new(), "null is not allowed",)
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(From::from("field"), Vec::new(), "null is not allowed")
+                .with_kind(ErrKind::NotNull),
+        ])
     );
 }
 
@@ -1418,16 +2108,20 @@ fn multi_err() {
     assert_eq!(
         MultiErr::from_json_stack_errs(r#"{}"#.as_bytes()).unwrap_err(),
         from::Errs::ValidationErrs(vec![
-            from::ValidationErr::new(From::from("field1"), Vec::new(), "required field"),
-            from::ValidationErr::new(From::from("field2"), Vec::new(), "required field"),
+            from::ValidationErr::new(From::from("field1"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+            from::ValidationErr::new(From::from("field2"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
         ])
     );
 
     assert_eq!(
         MultiErr::from_json_stack_errs_lang(r#"{}"#.as_bytes(), "en").unwrap_err(),
         from::Errs::ValidationErrs(vec![
-            from::ValidationErr::new(From::from("field1"), Vec::new(), "required field"),
-            from::ValidationErr::new(From::from("field2"), Vec::new(), "required field"),
+            from::ValidationErr::new(From::from("field1"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
+            from::ValidationErr::new(From::from("field2"), Vec::new(), "required field")
+                .with_kind(ErrKind::Required),
         ])
     );
 }
@@ -1447,31 +2141,49 @@ struct StringElem {
 fn string_elem() {
     assert_eq!(
         StringElem::from_json(r#"{"field": ["nice", "hello world!"]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from(1),
-            vec![From::from("field")],
-            "the string value must be no longer than 5 bytes"
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("field")],
+                "the string value must be no longer than 5 bytes"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 5usize)
+            .with_param("actual", 12usize)
+            .with_param("unit", "bytes"),
         )
     );
 
     assert_eq!(
         StringElem::from_json_lang(r#"{"field": ["nice", "hello world!"]}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Err::new_validation_err(
-            From::from(1),
-            vec![From::from("field")],
-            "the string value must be no longer than 5 bytes"
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("field")],
+                "the string value must be no longer than 5 bytes"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 5usize)
+            .with_param("actual", 12usize)
+            .with_param("unit", "bytes"),
         )
     );
 
     assert_eq!(
         StringElem::from_json_stack_errs(r#"{"field": ["nice", "hello world!"]}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from(1),
-            vec![From::from("field")],
-            "the string value must be no longer than 5 bytes"
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("field")],
+                "the string value must be no longer than 5 bytes"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 5usize)
+            .with_param("actual", 12usize)
+            .with_param("unit", "bytes"),
+        ])
     );
 
     assert_eq!(
@@ -1480,12 +2192,88 @@ fn string_elem() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from(1),
-            vec![From::from("field")],
-            "the string value must be no longer than 5 bytes"
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("field")],
+                "the string value must be no longer than 5 bytes"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 5usize)
+            .with_param("actual", 12usize)
+            .with_param("unit", "bytes"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct FormatElem {
+    #[elem{
+        format(ipv4)
+    }]
+    field: Vec<String>,
+}
+
+#[test]
+fn format_elem() {
+    assert_eq!(
+        FormatElem::from_json(r#"{"field": ["10.0.0.1", "10.0.0.256"]}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("field")],
+                "value must be a valid IPv4 address"
+            )
+            .with_kind(ErrKind::Format)
+            .with_param("format", "ipv4"),
         )
     );
+
+    assert_eq!(
+        FormatElem::from_json_stack_errs(r#"{"field": ["::1", "10.0.0.1"]}"#.as_bytes())
+            .unwrap_err(),
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from(0),
+                vec![From::from("field")],
+                "value must be a valid IPv4 address"
+            )
+            .with_kind(ErrKind::Format)
+            .with_param("format", "ipv4"),
+        ])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[from(json)]
+struct MultipleOfElem {
+    #[elem{
+        multiple_of(value = 6)
+    }]
+    field: Vec<u32>,
+}
+
+#[test]
+fn multiple_of_elem() {
+    assert_eq!(
+        MultipleOfElem::from_json(r#"{"field": [6, 12, 20]}"#.as_bytes()).unwrap_err(),
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(2),
+                vec![From::from("field")],
+                "number must be a multiple of 6"
+            )
+            .with_kind(ErrKind::MultipleOf)
+            .with_param("multiple_of", 6usize)
+            .with_param("actual", 20usize),
+        )
+    );
+
+    assert_eq!(
+        MultipleOfElem::from_json_stack_errs(r#"{"field": [0, 18]}"#.as_bytes()).unwrap(),
+        MultipleOfElem { field: vec![0, 18] }
+    );
 }
 
 #[derive(Debug, PartialEq)]
@@ -1503,39 +2291,63 @@ struct FloatElem {
 fn float_elem() {
     assert_eq!(
         FloatElem::from_json(r#"{"field": [4, 7.4]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from(1),
-            vec![From::from("field")],
-            "number must be less than or equal to 5"
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("field")],
+                "number must be less than or equal to 5"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 5.0)
+            .with_param("exclusive", false)
+            .with_param("actual", 7.400000095367432),
         )
     );
 
     assert_eq!(
         FloatElem::from_json_lang(r#"{"field": [4, 7.4]}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from(1),
-            vec![From::from("field")],
-            "number must be less than or equal to 5"
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("field")],
+                "number must be less than or equal to 5"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 5.0)
+            .with_param("exclusive", false)
+            .with_param("actual", 7.400000095367432),
         )
     );
 
     assert_eq!(
         FloatElem::from_json_stack_errs(r#"{"field": [4, 7.5]}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from(1),
-            vec![From::from("field")],
-            "number must be less than or equal to 5"
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("field")],
+                "number must be less than or equal to 5"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 5.0)
+            .with_param("exclusive", false)
+            .with_param("actual", 7.5),
+        ])
     );
 
     assert_eq!(
         FloatElem::from_json_stack_errs_lang(r#"{"field": [4, 7.5]}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from(1),
-            vec![From::from("field")],
-            "number must be less than or equal to 5"
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("field")],
+                "number must be less than or equal to 5"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 5.0)
+            .with_param("exclusive", false)
+            .with_param("actual", 7.5),
+        ])
     );
 }
 
@@ -1554,39 +2366,63 @@ struct IntElem {
 fn int_elem() {
     assert_eq!(
         IntElem::from_json(r#"{"field": [7, 5, 15]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from(2),
-            vec![From::from("field")],
-            "number must be less than or equal to 7"
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(2),
+                vec![From::from("field")],
+                "number must be less than or equal to 7"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 7usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 15usize),
         )
     );
 
     assert_eq!(
         IntElem::from_json_lang(r#"{"field": [20]}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from(0),
-            vec![From::from("field")],
-            "number must be less than or equal to 7"
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(0),
+                vec![From::from("field")],
+                "number must be less than or equal to 7"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 7usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 20usize),
         )
     );
 
     assert_eq!(
         IntElem::from_json_stack_errs(r#"{"field": [4, 3, 7, 15]}"#.as_bytes()).unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from(3),
-            vec![From::from("field")],
-            "number must be less than or equal to 7"
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from(3),
+                vec![From::from("field")],
+                "number must be less than or equal to 7"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 7usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 15usize),
+        ])
     );
 
     assert_eq!(
         IntElem::from_json_stack_errs_lang(r#"{"field": [1,2 ,3, 4 ,5 ,7, 10]}"#.as_bytes(), "en")
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from(6),
-            vec![From::from("field")],
-            "number must be less than or equal to 7"
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from(6),
+                vec![From::from("field")],
+                "number must be less than or equal to 7"
+            )
+            .with_kind(ErrKind::Max)
+            .with_param("max", 7usize)
+            .with_param("exclusive", false)
+            .with_param("actual", 10usize),
+        ])
     );
 }
 
@@ -1605,30 +2441,42 @@ struct BoolElem {
 fn bool_elem() {
     assert_eq!(
         BoolElem::from_json(r#"{"field": [true, true, false]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from(2),
-            vec![From::from("field")],
-            "value must be true"
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(2),
+                vec![From::from("field")],
+                "value must be true"
+            )
+            .with_kind(ErrKind::MustBe)
+            .with_param("value", true),
         )
     );
 
     assert_eq!(
         BoolElem::from_json_lang(r#"{"field": [false]}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from(0),
-            vec![From::from("field")],
-            "value must be true"
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(0),
+                vec![From::from("field")],
+                "value must be true"
+            )
+            .with_kind(ErrKind::MustBe)
+            .with_param("value", true),
         )
     );
 
     assert_eq!(
         BoolElem::from_json_stack_errs(r#"{"field": [true, true, true, false]}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from(3),
-            vec![From::from("field")],
-            "value must be true"
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from(3),
+                vec![From::from("field")],
+                "value must be true"
+            )
+            .with_kind(ErrKind::MustBe)
+            .with_param("value", true),
+        ])
     );
 
     assert_eq!(
@@ -1637,11 +2485,15 @@ fn bool_elem() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from(6),
-            vec![From::from("field")],
-            "value must be true"
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from(6),
+                vec![From::from("field")],
+                "value must be true"
+            )
+            .with_kind(ErrKind::MustBe)
+            .with_param("value", true),
+        ])
     );
 }
 
@@ -1664,30 +2516,45 @@ struct VecElem {
 fn vec_elem() {
     assert_eq!(
         VecElem::from_json(r#"{"field": [[]]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from(0),
-            vec![From::from("field")],
-            "array must not contains less than 1 element"
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(0),
+                vec![From::from("field")],
+                "array must not contains less than 1 element"
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 1usize)
+            .with_param("actual", 0usize),
         )
     );
 
     assert_eq!(
         VecElem::from_json_lang(r#"{"field": [[true], []]}"#.as_bytes(), "en").unwrap_err(),
-        from::Err::new_validation_err(
-            From::from(1),
-            vec![From::from("field")],
-            "array must not contains less than 1 element"
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("field")],
+                "array must not contains less than 1 element"
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 1usize)
+            .with_param("actual", 0usize),
         )
     );
 
     assert_eq!(
         VecElem::from_json_stack_errs(r#"{"field": [[true], [true], [true], []]}"#.as_bytes())
             .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from(3),
-            vec![From::from("field")],
-            "array must not contains less than 1 element"
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from(3),
+                vec![From::from("field")],
+                "array must not contains less than 1 element"
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 1usize)
+            .with_param("actual", 0usize),
+        ])
     );
 
     assert_eq!(
@@ -1696,28 +2563,41 @@ fn vec_elem() {
             "en"
         )
         .unwrap_err(),
-        from::Errs::new_validation_err(
-            From::from(6),
-            vec![From::from("field")],
-            "array must not contains less than 1 element"
-        )
+        from::Errs::ValidationErrs(vec![
+            ValidationErr::new(
+                From::from(6),
+                vec![From::from("field")],
+                "array must not contains less than 1 element"
+            )
+            .with_kind(ErrKind::MinLen)
+            .with_param("min", 1usize)
+            .with_param("actual", 0usize),
+        ])
     );
 
     assert_eq!(
         VecElem::from_json(r#"{"field": [[false]]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from(0),
-            vec![From::from("field"), From::from(0)],
-            "value must be true"
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(0),
+                vec![From::from("field"), From::from(0)],
+                "value must be true"
+            )
+            .with_kind(ErrKind::MustBe)
+            .with_param("value", true),
         )
     );
 
     assert_eq!(
         VecElem::from_json(r#"{"field": [[true], [true, false]]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from(1),
-            vec![From::from("field"), From::from(1)],
-            "value must be true"
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("field"), From::from(1)],
+                "value must be true"
+            )
+            .with_kind(ErrKind::MustBe)
+            .with_param("value", true),
         )
     );
 }
@@ -1735,10 +2615,15 @@ struct NullVecElem {
 fn null_vec_elem() {
     assert_eq!(
         NullVecElem::from_json(r#"{"field": [null, [1, 2]]}"#.as_bytes()).unwrap_err(),
-        from::Err::new_validation_err(
-            From::from(1),
-            vec![From::from("field")],
-            "array must not contains more than 1 element"
+        from::Err::ValidationErr(
+            ValidationErr::new(
+                From::from(1),
+                vec![From::from("field")],
+                "array must not contains more than 1 element"
+            )
+            .with_kind(ErrKind::MaxLen)
+            .with_param("max", 1usize)
+            .with_param("actual", 2usize),
         )
     );
 }
@@ -2,9 +2,15 @@ use proc_macro::TokenStream;
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::Ident;
+use syn::{ext::IdentExt, Attribute, Expr, Ident, Meta};
 
-use crate::{metas_holder::MetasHolder, utils};
+use crate::{
+    kind::{Kind, Type},
+    metas_holder::MetasHolder,
+    utils,
+};
+
+use super::FieldIdent;
 
 pub struct Check {
     pub check: TokenStream2,
@@ -18,6 +24,7 @@ impl Check {
 }
 
 pub struct MissingFieldCheck {
+    pub not_matching_indicator_ident: Ident,
     pub none: Check,
     pub lang: Check,
     pub stack_errs: Check,
@@ -43,10 +50,13 @@ impl MissingFieldCheck {
             &meta_name_values,
             quoted_field_ident,
             dflt_lang,
+            utils::msgs_attribute::code("required"),
             backup_msg,
         )?;
 
         Ok(Self {
+            not_matching_indicator_ident: not_matching_indicator_ident.clone(),
+
             none: Check::new(
                 quote! {
                     if #not_matching_indicator_ident {
@@ -93,3 +103,338 @@ impl MissingFieldCheck {
         })
     }
 }
+
+// a requirement that depends on the values of other fields, checked after
+// the unconditional ones
+#[derive(Clone)]
+pub struct ConditionalCheck {
+    pub none: TokenStream2,
+    pub lang: TokenStream2,
+    pub stack_errs: TokenStream2,
+    pub stack_errs_lang: TokenStream2,
+}
+
+impl ConditionalCheck {
+    fn new(cond: TokenStream2, single_msg_err: TokenStream2, multi_msgs_err: TokenStream2) -> Self {
+        Self::new_with_stack_errs_cond(cond.clone(), cond, single_msg_err, multi_msgs_err)
+    }
+
+    // the stack errors methods keep going after an error, so their
+    // condition may differ (e.g. guarded against placeholder values)
+    fn new_with_stack_errs_cond(
+        cond: TokenStream2,
+        stack_errs_cond: TokenStream2,
+        single_msg_err: TokenStream2,
+        multi_msgs_err: TokenStream2,
+    ) -> Self {
+        Self {
+            none: quote! {
+                if #cond {
+                    return Err(From::from(#single_msg_err));
+                };
+            },
+
+            lang: quote! {
+                if #cond {
+                    return Err(From::from(#multi_msgs_err));
+                };
+            },
+
+            stack_errs: quote! {
+                if #stack_errs_cond {
+                    errs.push(#single_msg_err);
+                };
+            },
+
+            stack_errs_lang: quote! {
+                if #stack_errs_cond {
+                    errs.push(#multi_msgs_err);
+                };
+            },
+        }
+    }
+
+    // 'required_if' and 'required_unless'
+    pub fn try_build_for_field(
+        attrs: &Vec<Attribute>,
+        field_ident: &FieldIdent,
+        kind: &Kind,
+        fields: &[(FieldIdent, Kind)],
+        dflt_lang: &str,
+    ) -> Result<Vec<Self>, TokenStream> {
+        let mut checks = Vec::new();
+
+        for (name, unless) in [("required_if", false), ("required_unless", true)] {
+            let metas = match attrs.parse_list_if_found(name)? {
+                Some(metas) => metas,
+                None => continue,
+            };
+
+            if !kind.option {
+                return Err(utils::compile_err(
+                    &field_ident.ident,
+                    format!(
+                        "'{}' can only be used with 'Option' and 'OptionNull' fields",
+                        name
+                    ),
+                ));
+            };
+
+            let (other, nv) =
+                metas.parse_value_or_err::<String>("field", "'field' NameValue attribute is required")?;
+
+            let (other_ident, other_kind) = find_field(&other, nv, fields)?;
+
+            if other_ident.ident.eq(&field_ident.ident) {
+                return Err(utils::compile_err(
+                    nv,
+                    "a field can not depend on itself",
+                ));
+            };
+
+            let other_var_name = &other_ident.var_name;
+
+            let other_cond = match metas.parse_value_if_found::<Expr>("eq")? {
+                Some((eq, eq_nv)) => {
+                    if let Type::Vec(..) | Type::Custom(_) = other_kind.ty {
+                        return Err(utils::compile_err(
+                            eq_nv,
+                            "'eq' is only supported for fields of type 'String', 'bool', integers and floats",
+                        ));
+                    };
+
+                    let pat = other_kind.some_pattern(quote! {__other});
+
+                    quote! {matches!(&#other_var_name, #pat if *__other == #eq)}
+                }
+
+                // the other field is present
+                None => {
+                    if !other_kind.option && !other_kind.null {
+                        return Err(utils::compile_err(
+                            nv,
+                            "without 'eq' the field must be of type 'Option', 'Null' or 'OptionNull'",
+                        ));
+                    };
+
+                    is_present(other_kind, other_var_name)
+                }
+            };
+
+            let var_name = &field_ident.var_name;
+
+            let missing = if kind.null {
+                quote! {matches!(#var_name, ::from::OptionNull::None)}
+            } else {
+                quote! {#var_name.is_none()}
+            };
+
+            let cond = if unless {
+                quote! {#missing && !#other_cond}
+            } else {
+                quote! {#missing && #other_cond}
+            };
+
+            let cond = utils::groups::guard(&metas, cond)?;
+
+            // when the other field was missing or invalid its variable
+            // still holds the placeholder value (e.g. 'String::new()'),
+            // so the condition is only evaluated if it has no error
+            let stack_errs_cond = quote! {
+                !::from::utils::has_err_at(&errs, path, #other) && #cond
+            };
+
+            let code = utils::msgs_attribute::code(name).param("other", &other);
+
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &attrs.parse_nvs_from_list_or_empty("required_msgs")?,
+                &field_ident.quoted,
+                dflt_lang,
+                code,
+                || quote! {String::from("required field")},
+            )?;
+
+            checks.push(Self::new_with_stack_errs_cond(
+                cond,
+                stack_errs_cond,
+                single_msg_err,
+                multi_msgs_err,
+            ));
+        }
+
+        // 'required(groups(..))', the field is required only when one of
+        // the groups is active
+        if let Some(metas) = attrs.parse_list_if_found("required")? {
+            if !kind.option {
+                return Err(utils::compile_err(
+                    &field_ident.ident,
+                    "'required' can only be used with 'Option' and 'OptionNull' fields",
+                ));
+            };
+
+            let active = match utils::groups::parse(&metas)? {
+                Some(active) => active,
+                None => {
+                    return Err(utils::compile_err(
+                        &metas,
+                        "'groups' attribute is required (e.g. 'required(groups(create))')",
+                    ))
+                }
+            };
+
+            let var_name = &field_ident.var_name;
+
+            let missing = if kind.null {
+                quote! {matches!(#var_name, ::from::OptionNull::None)}
+            } else {
+                quote! {#var_name.is_none()}
+            };
+
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &attrs.parse_nvs_from_list_or_empty("required_msgs")?,
+                &field_ident.quoted,
+                dflt_lang,
+                utils::msgs_attribute::code("required"),
+                || quote! {String::from("required field")},
+            )?;
+
+            checks.push(Self::new(
+                quote! {#active && #missing},
+                single_msg_err,
+                multi_msgs_err,
+            ));
+        };
+
+        Ok(checks)
+    }
+
+    // 'exactly_one_of' and 'at_most_one_of'
+    pub fn try_build_for_struct(
+        attrs: &Vec<Attribute>,
+        fields: &[(FieldIdent, Kind)],
+        dflt_lang: &str,
+    ) -> Result<Vec<Self>, TokenStream> {
+        let mut checks = Vec::new();
+
+        for (name, exactly) in [("exactly_one_of", true), ("at_most_one_of", false)] {
+            let metas = match attrs.parse_list_if_found(name)? {
+                Some(metas) => metas,
+                None => continue,
+            };
+
+            let mut names = Vec::new();
+            let mut fields_names = Vec::new();
+            let mut presents = Vec::new();
+            let mut errs_at = Vec::new();
+            let mut first = None;
+
+            for meta in &metas {
+                let path = match meta {
+                    Meta::Path(path) => path,
+                    _ => continue,
+                };
+
+                let field_name = match path.get_ident() {
+                    Some(ident) => ident.unraw().to_string(),
+                    None => return Err(utils::compile_err(path, "expected: field name")),
+                };
+
+                let (field_ident, kind) = find_field(&field_name, path, fields)?;
+
+                if !kind.option && !kind.null {
+                    return Err(utils::compile_err(
+                        path,
+                        format!(
+                            "'{}' can only be used with 'Option', 'Null' and 'OptionNull' fields",
+                            name
+                        ),
+                    ));
+                };
+
+                let is_present = is_present(kind, &field_ident.var_name);
+
+                presents.push(quote! {#is_present as usize});
+                errs_at.push(quote! {::from::utils::has_err_at(&errs, path, #field_name)});
+                names.push(format!("'{}'", field_name));
+                fields_names.push(field_name);
+                first.get_or_insert(&field_ident.quoted);
+            }
+
+            let first = match first {
+                Some(first) if names.len() > 1 => first,
+                _ => {
+                    return Err(utils::compile_err(
+                        &metas,
+                        "at least two fields are required",
+                    ))
+                }
+            };
+
+            let names = names.join(", ");
+
+            let (cond, msg) = if exactly {
+                (
+                    quote! {(#(#presents)+*) != 1},
+                    format!("exactly one of {} must be provided", names),
+                )
+            } else {
+                (
+                    quote! {(#(#presents)+*) > 1},
+                    format!("at most one of {} can be provided", names),
+                )
+            };
+
+            let code = utils::msgs_attribute::code(name).list_param("fields", &fields_names);
+
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &metas.parse_nvs_from_list_or_empty("msgs")?,
+                first,
+                dflt_lang,
+                code,
+                || quote! {String::from(#msg)},
+            )?;
+
+            // a field that is present but invalid is left as missing, so
+            // the fields are only counted if none of them has an error
+            let stack_errs_cond = quote! {
+                !(#(#errs_at)||*) && #cond
+            };
+
+            checks.push(Self::new_with_stack_errs_cond(
+                cond,
+                stack_errs_cond,
+                single_msg_err,
+                multi_msgs_err,
+            ));
+        }
+
+        Ok(checks)
+    }
+}
+
+pub fn find_field<'a, S: syn::spanned::Spanned>(
+    name: &str,
+    spanned: &S,
+    fields: &'a [(FieldIdent, Kind)],
+) -> Result<(&'a FieldIdent, &'a Kind), TokenStream> {
+    match fields.iter().find(|(f, _)| f.ident.unraw().eq(name)) {
+        Some((field_ident, kind)) => Ok((field_ident, kind)),
+
+        None => Err(utils::compile_err(
+            spanned,
+            format!("there is no field named '{}'", name),
+        )),
+    }
+}
+
+// provided and not null
+#[inline]
+fn is_present(kind: &Kind, var_name: &Ident) -> TokenStream2 {
+    if kind.option && !kind.null {
+        return quote! {#var_name.is_some()};
+    };
+
+    let pat = kind.some_pattern(quote! {_});
+
+    quote! {matches!(&#var_name, #pat)}
+}
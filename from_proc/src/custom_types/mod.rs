@@ -14,7 +14,7 @@ mod null_handling;
 pub use null_handling::NullHandling;
 
 mod missing_field_check;
-pub use missing_field_check::MissingFieldCheck;
+pub use missing_field_check::{find_field, ConditionalCheck, MissingFieldCheck};
 
 mod field_ident;
 pub use field_ident::FieldIdent;
@@ -12,21 +12,35 @@ pub struct NullHandling {
 }
 
 impl NullHandling {
+    // 'expected' is only a placeholder so the errors have no params
     #[inline]
-    pub fn try_build<H, F>(
+    fn code(exp: &str) -> utils::msgs_attribute::Code {
+        utils::msgs_attribute::code("not_null").placeholder("expected", exp)
+    }
+
+    #[inline]
+    fn dflt_msg(exp: &str) -> TokenStream2 {
+        let msg = format!("expected: {}, found: null", exp);
+        quote! { String::from(#msg) }
+    }
+
+    #[inline]
+    pub fn try_build<H: MetasHolder>(
         metas_holder: &H,
         prop_or_idx: &TokenStream2,
         dflt_lang: &str,
-        backup_msg: F,
-    ) -> Result<Self, TokenStream>
-    where
-        H: MetasHolder,
-        F: Fn() -> TokenStream2,
-    {
+        exp: &str,
+    ) -> Result<Self, TokenStream> {
         let meta_name_values = metas_holder.parse_nvs_from_list_or_empty("not_null_msgs")?;
 
         let (single_msg_err, multi_msgs_err) =
-            utils::msgs_attribute::process(&meta_name_values, prop_or_idx, dflt_lang, backup_msg)?;
+            utils::msgs_attribute::process(
+                &meta_name_values,
+                prop_or_idx,
+                dflt_lang,
+                Self::code(exp),
+                || -> TokenStream2 { Self::dflt_msg(exp) },
+            )?;
 
         Ok(Self {
             none: quote! {
@@ -55,9 +69,13 @@ impl NullHandling {
     }
 
     #[inline]
-    pub fn new_dflt(prop_or_idx: &TokenStream2, dflt_msg: TokenStream2) -> Self {
-        let (single_msg_err, multi_msgs_err) =
-            utils::msgs_attribute::construct_dflt(prop_or_idx, dflt_msg);
+    pub fn new_dflt(prop_or_idx: &TokenStream2, dflt_lang: &str, exp: &str) -> Self {
+        let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::construct_dflt(
+            prop_or_idx,
+            dflt_lang,
+            Self::code(exp),
+            Self::dflt_msg(exp),
+        );
 
         Self {
             none: quote! {
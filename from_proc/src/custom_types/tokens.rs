@@ -2,6 +2,7 @@ use proc_macro2::TokenStream as TokenStream2;
 
 use crate::utils::Append;
 
+#[derive(Clone)]
 pub struct Tokens(Vec<TokenStream2>);
 
 impl Tokens {
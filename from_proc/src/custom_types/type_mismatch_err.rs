@@ -12,6 +12,20 @@ pub struct TypeMismatchErr {
 }
 
 impl TypeMismatchErr {
+    // 'found' is the name of the received JSON type
+    #[inline]
+    fn code(exp: &str) -> utils::msgs_attribute::Code {
+        utils::msgs_attribute::code("type_mismatch")
+            .param("expected", exp)
+            .param("found", quote! {AsRef::<str>::as_ref(&found)})
+    }
+
+    #[inline]
+    fn dflt_msg(exp: &str) -> TokenStream2 {
+        let msg = format!("expected: {}, found: {{}}", exp);
+        quote! { format!(#msg, found) }
+    }
+
     pub fn try_build<H>(
         metas_holder: &H,
         prop_or_idx: &TokenStream2,
@@ -23,11 +37,13 @@ impl TypeMismatchErr {
     {
         let metas = metas_holder.parse_nvs_from_list_or_empty("type_mismatch_msgs")?;
 
-        let (single_msg_err, multi_msgs_err) =
-            utils::msgs_attribute::process(&metas, prop_or_idx, dflt_lang, || -> TokenStream2 {
-                let msg = format!("expected: {}, found: {{}}", exp);
-                quote! { format!(#msg, found) }
-            })?;
+        let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+            &metas,
+            prop_or_idx,
+            dflt_lang,
+            Self::code(exp),
+            || -> TokenStream2 { Self::dflt_msg(exp) },
+        )?;
 
         Ok(Self {
             none: quote! {
@@ -48,9 +64,13 @@ impl TypeMismatchErr {
     }
 
     #[inline]
-    pub fn new_dflt(prop_or_idx: &TokenStream2, dflt_msg: TokenStream2) -> Self {
-        let (single_msg_err, multi_msgs_err) =
-            utils::msgs_attribute::construct_dflt(prop_or_idx, dflt_msg);
+    pub fn new_dflt(prop_or_idx: &TokenStream2, dflt_lang: &str, exp: &str) -> Self {
+        let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::construct_dflt(
+            prop_or_idx,
+            dflt_lang,
+            Self::code(exp),
+            Self::dflt_msg(exp),
+        );
 
         Self {
             none: quote! {
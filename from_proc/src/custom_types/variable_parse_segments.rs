@@ -6,6 +6,7 @@ pub struct VariableParseSegments {
     pub field_var_defs: TokenStream2,
     pub field_parsing_arms: TokenStream2,
     pub missing_field_checks: TokenStream2,
+    pub conditional_checks: TokenStream2,
     pub field_assignments: TokenStream2,
 }
 
@@ -17,6 +18,7 @@ impl VariableParseSegments {
             field_var_defs: TokenStream2::new(),
             field_parsing_arms: TokenStream2::new(),
             missing_field_checks: TokenStream2::new(),
+            conditional_checks: TokenStream2::new(),
             field_assignments: TokenStream2::new(),
         }
     }
@@ -61,6 +63,12 @@ impl VariableParseSegments {
             .extend(::core::iter::once(missing_field_check));
     }
 
+    #[inline]
+    pub fn add_conditional_check(&mut self, conditional_check: TokenStream2) {
+        self.conditional_checks
+            .extend(::core::iter::once(conditional_check));
+    }
+
     #[inline]
     pub fn add_field_assignment_ref(&mut self, field_assignment: &TokenStream2) {
         self.field_assignments
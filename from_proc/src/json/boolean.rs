@@ -29,7 +29,9 @@ impl FromJsonValueImpl {
         )?;
 
         match (option, null) {
-            (true, true) => Ok(self.option_null_bool(field_ident, processing, type_mismatch_err)),
+            (true, true) => {
+                self.option_null_bool(field_ident, attrs, dflt_lang, processing, type_mismatch_err)
+            }
 
             (true, false) => self.option_not_null_bool(
                 field_ident,
@@ -53,13 +55,15 @@ impl FromJsonValueImpl {
     fn option_null_bool(
         &mut self,
         field_ident: custom_types::FieldIdent,
+        attrs: Vec<Attribute>,
+        dflt_lang: &str,
         processing: Processing,
         type_mismatch_err: custom_types::TypeMismatchErr,
-    ) {
+    ) -> Result<(), TokenStream> {
         let custom_types::FieldIdent {
             var_name,
             ident: field_ident,
-            quoted: _,
+            quoted: quoted_field_ident,
             byte_quoted: byte_quoted_field_ident,
         } = field_ident;
 
@@ -67,8 +71,13 @@ impl FromJsonValueImpl {
 
         let var_assignment = quote! {#var_name = ::from::OptionNull::Some(val);};
 
-        let null_handling =
-            custom_types::NullHandling::from_one(quote! {#var_name = ::from::OptionNull::Null;});
+        let null_handling = self.option_null_handling(
+            &attrs,
+            &quoted_field_ident,
+            dflt_lang,
+            "boolean",
+            quote! {#var_name = ::from::OptionNull::Null;},
+        )?;
 
         let field_parsing_arm = gen_bool_field_parsing_arm(
             byte_quoted_field_ident,
@@ -88,6 +97,8 @@ impl FromJsonValueImpl {
         self.add_field_assignment(quote! {
             #field_ident: #var_name,
         });
+
+        Ok(())
     }
 
     #[inline]
@@ -115,10 +126,7 @@ impl FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: boolean, found: null");
-                quote! {String::from(#msg)}
-            },
+            "boolean",
         )?;
 
         let field_parsing_arm = gen_bool_field_parsing_arm(
@@ -306,11 +314,7 @@ impl FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: boolean, found: null");
-
-                quote! {String::from(#msg)}
-            },
+            "boolean",
         )?;
 
         let field_parsing_arm = gen_bool_field_parsing_arm(
@@ -363,11 +367,7 @@ impl FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: boolean, found: null");
-
-                quote! {String::from(#msg)}
-            },
+            "boolean",
         )?;
 
         let field_parsing_arm = gen_bool_field_parsing_arm(
@@ -100,9 +100,15 @@ impl FromJsonValueImpl {
         let err_handling = ErrHandling::new();
 
         match (option, null) {
-            (true, true) => {
-                Ok(self.option_null_custom(field_ident, ty, processing, method_call, err_handling))
-            }
+            (true, true) => self.option_null_custom(
+                field_ident,
+                attrs,
+                ty,
+                dflt_lang,
+                processing,
+                method_call,
+                err_handling,
+            ),
 
             (true, false) => self.option_not_null_custom(
                 field_ident,
@@ -140,11 +146,13 @@ impl FromJsonValueImpl {
     fn option_null_custom(
         &mut self,
         field_ident: custom_types::FieldIdent,
+        attrs: Vec<Attribute>,
         ty: TokenStream2,
+        dflt_lang: &str,
         processing: Processing,
         method_call: MethodCall,
         err_handling: ErrHandling,
-    ) {
+    ) -> Result<(), TokenStream> {
         let custom_types::FieldIdent {
             var_name,
             ident: field_ident,
@@ -155,9 +163,13 @@ impl FromJsonValueImpl {
         let before = TokenStream2::new();
         let var_assignment = quote! {#var_name = ::from::OptionNull::<#ty>::Some(val);};
 
-        let null_handling = custom_types::NullHandling::from_one(
+        let null_handling = self.option_null_handling(
+            &attrs,
+            &quoted_field_ident,
+            dflt_lang,
+            "object",
             quote! {#var_name = ::from::OptionNull::<#ty>::Null;},
-        );
+        )?;
 
         let field_parsing_arm = gen_custom_field_parsing_arm(
             byte_quoted_field_ident,
@@ -179,6 +191,8 @@ impl FromJsonValueImpl {
         self.add_field_assignment(quote! {
             #field_ident: #var_name,
         });
+
+        Ok(())
     }
 
     #[inline]
@@ -206,9 +220,7 @@ impl FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                quote! {String::from("expected: object, found: null")}
-            },
+            "object",
         )?;
 
         let field_parsing_arm = gen_custom_field_parsing_arm(
@@ -417,9 +429,7 @@ impl FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                quote! {String::from("expected: object, found: null")}
-            },
+            "object",
         )?;
 
         let field_parsing_arm = gen_custom_field_parsing_arm(
@@ -442,6 +452,9 @@ impl FromJsonValueImpl {
 
         self.add_missing_field_check(missing_field_check);
 
+        // a present but invalid value leaves the variable empty
+        self.add_presence_check(quote! {#var_name.is_some()});
+
         self.add_field_assignment(quote! {
             #field_ident: unsafe { #var_name.unwrap_unchecked() },
         });
@@ -475,9 +488,7 @@ impl FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                quote! {String::from("expected: object, found: null")}
-            },
+            "object",
         )?;
 
         let field_parsing_arm = gen_custom_field_parsing_arm(
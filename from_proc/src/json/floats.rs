@@ -38,9 +38,13 @@ impl super::FromJsonValueImpl {
         )?;
 
         match (option, null) {
-            (true, true) => {
-                Ok(self.option_null_float::<F>(field_ident, processing, type_mismatch_err))
-            }
+            (true, true) => self.option_null_float::<F>(
+                field_ident,
+                attrs,
+                dflt_lang,
+                processing,
+                type_mismatch_err,
+            ),
 
             (true, false) => self.option_not_null_float::<F>(
                 field_ident,
@@ -72,13 +76,15 @@ impl super::FromJsonValueImpl {
     fn option_null_float<F: Floats>(
         &mut self,
         field_ident: custom_types::FieldIdent,
+        attrs: Vec<Attribute>,
+        dflt_lang: &str,
         processing: types::floats::Processing,
         type_mismatch_err: custom_types::TypeMismatchErr,
-    ) {
+    ) -> Result<(), TokenStream> {
         let custom_types::FieldIdent {
             var_name,
             ident: field_ident,
-            quoted: _quoted_field_ident,
+            quoted: quoted_field_ident,
             byte_quoted: byte_quoted_field_ident,
         } = field_ident;
 
@@ -86,8 +92,13 @@ impl super::FromJsonValueImpl {
 
         let var_assignment = quote! {#var_name = ::from::OptionNull::Some(val);};
 
-        let null_handling =
-            custom_types::NullHandling::from_one(quote! {#var_name = ::from::OptionNull::Null;});
+        let null_handling = self.option_null_handling(
+            &attrs,
+            &quoted_field_ident,
+            dflt_lang,
+            F::exp(),
+            quote! {#var_name = ::from::OptionNull::Null;},
+        )?;
 
         let ty = F::ty();
 
@@ -110,6 +121,8 @@ impl super::FromJsonValueImpl {
         self.add_field_assignment(quote! {
             #field_ident: #var_name,
         });
+
+        Ok(())
     }
 
     #[inline]
@@ -136,10 +149,7 @@ impl super::FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: {}, found: null", F::exp());
-                quote! {String::from(#msg)}
-            },
+            F::exp(),
         )?;
 
         let ty = F::ty();
@@ -341,11 +351,7 @@ impl super::FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: {}, found: null", F::exp());
-
-                quote! {String::from(#msg)}
-            },
+            F::exp(),
         )?;
 
         let ty = F::ty();
@@ -401,11 +407,7 @@ impl super::FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: {}, found: null", F::exp());
-
-                quote! {String::from(#msg)}
-            },
+            F::exp(),
         )?;
 
         let ty = F::ty();
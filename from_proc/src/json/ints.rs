@@ -45,13 +45,15 @@ impl FromJsonValueImpl {
             types::ints::TooSmallErr::try_build(&attrs, &field_ident.quoted, dflt_lang)?;
 
         match (option, null) {
-            (true, true) => Ok(self.option_null_int::<I>(
+            (true, true) => self.option_null_int::<I>(
                 field_ident,
+                attrs,
+                dflt_lang,
                 processing,
                 type_mismatch_err,
                 too_large_err,
                 too_small_err,
-            )),
+            ),
 
             (true, false) => self.option_not_null_int::<I>(
                 field_ident,
@@ -89,15 +91,17 @@ impl FromJsonValueImpl {
     fn option_null_int<I: Ints>(
         &mut self,
         field_ident: custom_types::FieldIdent,
+        attrs: Vec<Attribute>,
+        dflt_lang: &str,
         processing: types::ints::Processing,
         type_mismatch_err: custom_types::TypeMismatchErr,
         too_large_err: types::ints::TooLargeErr,
         too_small_err: types::ints::TooSmallErr,
-    ) {
+    ) -> Result<(), TokenStream> {
         let custom_types::FieldIdent {
             var_name,
             ident: field_ident,
-            quoted: _quoted_field_ident,
+            quoted: quoted_field_ident,
             byte_quoted: byte_quoted_field_ident,
         } = field_ident;
 
@@ -107,9 +111,13 @@ impl FromJsonValueImpl {
 
         let var_assignment = quote! {#var_name = ::from::OptionNull::Some(val);};
 
-        let null_handling = custom_types::NullHandling::from_one(
+        let null_handling = self.option_null_handling(
+            &attrs,
+            &quoted_field_ident,
+            dflt_lang,
+            I::expc(),
             quote! {#var_name = ::from::OptionNull::<#ty>::Null;},
-        );
+        )?;
 
         let field_parsing_arm = gen_int_field_parsing_arm::<I>(
             byte_quoted_field_ident,
@@ -131,6 +139,8 @@ impl FromJsonValueImpl {
         self.add_field_assignment(quote! {
             #field_ident: #var_name,
         });
+
+        Ok(())
     }
 
     #[inline]
@@ -159,10 +169,7 @@ impl FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: {}, found: null", I::expc());
-                quote! {String::from(#msg)}
-            },
+            I::expc(),
         )?;
 
         let field_parsing_arm = gen_int_field_parsing_arm::<I>(
@@ -382,11 +389,7 @@ impl FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: {}, found: null", I::expc());
-
-                quote! {String::from(#msg)}
-            },
+            I::expc(),
         )?;
 
         let field_parsing_arm = gen_int_field_parsing_arm::<I>(
@@ -446,11 +449,7 @@ impl FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: {}, found: null", I::expc());
-
-                quote! {String::from(#msg)}
-            },
+            I::expc(),
         )?;
 
         let field_parsing_arm = gen_int_field_parsing_arm::<I>(
@@ -116,7 +116,7 @@ impl FromJsonValue {
                         break;
                     };
 
-                    return Err(::from::Err::SyntaxErr(::from::SyntaxErr::unexpected_token("',' or '}'", &[byte], idx)));
+                    return Err(::from::Err::SyntaxErr(::from::SyntaxErr::unexpected_tokens(&["','", "'}'"], &[byte], idx)));
                 }
 
 
@@ -243,7 +243,7 @@ impl FromJsonValueLang {
                         break;
                     };
 
-                    return Err(::from::Err::SyntaxErr(::from::SyntaxErr::unexpected_token("',' or '}'", &[byte], idx)));
+                    return Err(::from::Err::SyntaxErr(::from::SyntaxErr::unexpected_tokens(&["','", "'}'"], &[byte], idx)));
                 }
 
 
@@ -376,7 +376,7 @@ impl FromJsonValueStackErrs {
                         break;
                     };
 
-                    return Err(::from::Errs::SyntaxErr(::from::SyntaxErr::unexpected_token("',' or '}'", &[byte], idx)));
+                    return Err(::from::Errs::SyntaxErr(::from::SyntaxErr::unexpected_tokens(&["','", "'}'"], &[byte], idx)));
                 }
 
 
@@ -512,7 +512,7 @@ impl FromJsonValueStackErrsLang {
                         break;
                     };
 
-                    return Err(::from::Errs::SyntaxErr(::from::SyntaxErr::unexpected_token("',' or '}'", &[byte], idx)));
+                    return Err(::from::Errs::SyntaxErr(::from::SyntaxErr::unexpected_tokens(&["','", "'}'"], &[byte], idx)));
                 }
 
 
@@ -1,8 +1,9 @@
+use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::{Generics, Ident};
+use syn::{Attribute, Generics, Ident};
 
-use crate::{custom_types, VariableParseSegments};
+use crate::{custom_types, types, Tokens, VariableParseSegments};
 
 // mod methods;
 // mod from_json_value_impl;
@@ -24,6 +25,11 @@ pub struct FromJsonValueImpl {
     lang: VariableParseSegments,
     stack_errs: VariableParseSegments,
     stack_errs_lang: VariableParseSegments,
+    // conditions under which 'Self' can be assembled despite field-level errors
+    presence_checks: Tokens,
+    // set for the 'OptionNull' fields of a patch whose original field
+    // can not be null: they can be missing but null is rejected
+    reject_null: bool,
 }
 
 impl FromJsonValueImpl {
@@ -34,9 +40,38 @@ impl FromJsonValueImpl {
             lang: VariableParseSegments::new(),
             stack_errs: VariableParseSegments::new(),
             stack_errs_lang: VariableParseSegments::new(),
+            presence_checks: Tokens::new(),
+            reject_null: false,
         }
     }
 
+    #[inline(always)]
+    pub fn set_reject_null(&mut self, reject_null: bool) {
+        self.reject_null = reject_null;
+    }
+
+    // the null handling of the 'OptionNull' fields
+    #[inline]
+    fn option_null_handling(
+        &self,
+        attrs: &Vec<Attribute>,
+        quoted_field_ident: &TokenStream2,
+        dflt_lang: &str,
+        exp: &str,
+        null_assignment: TokenStream2,
+    ) -> Result<custom_types::NullHandling, TokenStream> {
+        if self.reject_null {
+            custom_types::NullHandling::try_build(attrs, quoted_field_ident, dflt_lang, exp)
+        } else {
+            Ok(custom_types::NullHandling::from_one(null_assignment))
+        }
+    }
+
+    #[inline(always)]
+    pub fn add_presence_check(&mut self, presence_check: TokenStream2) {
+        self.presence_checks.push(presence_check);
+    }
+
     #[inline(always)]
     pub fn add_field_var_def(&mut self, field_var_def: TokenStream2) {
         self.none.add_field_var_def_ref(&field_var_def);
@@ -63,6 +98,9 @@ impl FromJsonValueImpl {
         &mut self,
         missing_field_check: custom_types::MissingFieldCheck,
     ) {
+        let not_matching_indicator_ident = missing_field_check.not_matching_indicator_ident;
+        self.add_presence_check(quote! {!#not_matching_indicator_ident});
+
         self.none
             .add_missing_field_check(missing_field_check.none.check);
         if self.none.required_field_errs.len() == 0 {
@@ -88,6 +126,16 @@ impl FromJsonValueImpl {
             .add_required_field_err(missing_field_check.stack_errs_lang.err);
     }
 
+    #[inline(always)]
+    pub fn add_conditional_check(&mut self, conditional_check: custom_types::ConditionalCheck) {
+        self.none.add_conditional_check(conditional_check.none);
+        self.lang.add_conditional_check(conditional_check.lang);
+        self.stack_errs
+            .add_conditional_check(conditional_check.stack_errs);
+        self.stack_errs_lang
+            .add_conditional_check(conditional_check.stack_errs_lang);
+    }
+
     #[inline(always)]
     pub fn add_field_assignment(&mut self, field_assignment: TokenStream2) {
         self.none.add_field_assignment_ref(&field_assignment);
@@ -97,16 +145,57 @@ impl FromJsonValueImpl {
     }
 
     #[inline]
-    pub fn construct(self, ident: &Ident, generics: &Generics) -> TokenStream2 {
-        let from_json_value_method = construct_from_json_value_method(self.none);
+    pub fn construct(
+        self,
+        ident: &Ident,
+        generics: &Generics,
+        struct_processing: types::structure::Processing,
+    ) -> TokenStream2 {
+        let (valdg, valdg_lang, valdg_stack_errs, valdg_stack_errs_lang, presence_check) =
+            if struct_processing.is_empty() {
+                (None, None, None, None, None)
+            } else {
+                // by default struct-level validation is skipped when
+                // field-level errors exist, with 'always_validate' it
+                // only needs all the required fields to be present
+                let presence_check = match (struct_processing.always, self.presence_checks.len()) {
+                    (false, _) => Some(quote! {errs.len() > 0}),
+                    (true, 0) => None,
+                    (true, _) => {
+                        let checks = self.presence_checks.join(quote! {&&});
+                        Some(quote! {!(#checks)})
+                    }
+                };
 
-        let from_json_value_lang_method = construct_from_json_value_lang_method(self.lang);
+                let (valdg, valdg_lang, valdg_stack_errs, valdg_stack_errs_lang) =
+                    struct_processing.into_parts();
 
-        let from_json_value_stack_errs_method =
-            construct_from_json_value_stack_errs_method(self.stack_errs);
+                (
+                    Some(valdg),
+                    Some(valdg_lang),
+                    Some(valdg_stack_errs),
+                    Some(valdg_stack_errs_lang),
+                    presence_check,
+                )
+            };
+
+        let from_json_value_method = construct_from_json_value_method(self.none, valdg);
+
+        let from_json_value_lang_method =
+            construct_from_json_value_lang_method(self.lang, valdg_lang);
+
+        let from_json_value_stack_errs_method = construct_from_json_value_stack_errs_method(
+            self.stack_errs,
+            valdg_stack_errs,
+            &presence_check,
+        );
 
         let from_json_value_stack_errs_lang_method =
-            construct_from_json_value_stack_errs_lang_method(self.stack_errs_lang);
+            construct_from_json_value_stack_errs_lang_method(
+                self.stack_errs_lang,
+                valdg_stack_errs_lang,
+                &presence_check,
+            );
 
         quote! {
             impl #generics ::from::json::FromJsonValue for #ident #generics {
@@ -123,28 +212,25 @@ impl FromJsonValueImpl {
 }
 
 #[inline]
-fn construct_from_json_value_method(var_segs: VariableParseSegments) -> TokenStream2 {
+fn construct_from_json_value_method(
+    var_segs: VariableParseSegments,
+    struct_valdg: Option<TokenStream2>,
+) -> TokenStream2 {
     let VariableParseSegments {
         required_field_errs,
         field_var_defs,
         field_parsing_arms,
         missing_field_checks,
+        conditional_checks,
         field_assignments,
     } = var_segs;
 
-    let empty_handling = if required_field_errs.len() > 0 {
-        let required_field_errs = required_field_errs.join(quote! {,});
-
-        quote! {
-            return Err(From::from(#required_field_errs));
-        }
-    } else {
-        quote! {
-            return Ok(Self {
-                #field_assignments
-            });
-        }
-    };
+    let (empty_handling, assembly) = instant_return_tail(
+        required_field_errs,
+        &field_assignments,
+        &conditional_checks,
+        struct_valdg,
+    );
 
     quote! {
         fn from_json_value(json: &[u8], idx: &mut usize, path: &::from::Path) -> Result<Self, ::from::Err> {
@@ -158,6 +244,7 @@ fn construct_from_json_value_method(var_segs: VariableParseSegments) -> TokenStr
             #field_var_defs
 
             if byte.eq(&b'}') {
+                *idx += 1;
                 #empty_handling
             };
 
@@ -192,46 +279,42 @@ fn construct_from_json_value_method(var_segs: VariableParseSegments) -> TokenStr
                 };
 
                 if byte == b'}' {
+                    *idx += 1;
                     break;
                 };
 
-                return Err(::from::Err::SyntaxErr(::from::SyntaxErr::unexpected_token("',' or '}'", &[byte], idx)));
+                return Err(::from::Err::SyntaxErr(::from::SyntaxErr::unexpected_tokens(&["','", "'}'"], &[byte], idx)));
             }
 
 
 
             #missing_field_checks
 
-            Ok(Self {
-                #field_assignments
-            })
+            #assembly
         }
     }
 }
 
 #[inline]
-fn construct_from_json_value_lang_method(var_segs: VariableParseSegments) -> TokenStream2 {
+fn construct_from_json_value_lang_method(
+    var_segs: VariableParseSegments,
+    struct_valdg: Option<TokenStream2>,
+) -> TokenStream2 {
     let VariableParseSegments {
         required_field_errs,
         field_var_defs,
         field_parsing_arms,
         missing_field_checks,
+        conditional_checks,
         field_assignments,
     } = var_segs;
 
-    let empty_handling = if required_field_errs.len() > 0 {
-        let required_field_errs = required_field_errs.join(quote! {,});
-
-        quote! {
-            return Err(From::from(#required_field_errs));
-        }
-    } else {
-        quote! {
-            return Ok(Self {
-                #field_assignments
-            });
-        }
-    };
+    let (empty_handling, assembly) = instant_return_tail(
+        required_field_errs,
+        &field_assignments,
+        &conditional_checks,
+        struct_valdg,
+    );
 
     quote! {
         fn from_json_value_lang(json: &[u8], idx: &mut usize, path: &::from::Path, lang: &str) -> Result<Self, ::from::Err>{
@@ -244,6 +327,7 @@ fn construct_from_json_value_lang_method(var_segs: VariableParseSegments) -> Tok
             #field_var_defs
 
             if byte.eq(&b'}') {
+                *idx += 1;
                 #empty_handling
             };
 
@@ -276,46 +360,44 @@ fn construct_from_json_value_lang_method(var_segs: VariableParseSegments) -> Tok
                 };
 
                 if byte == b'}' {
+                    *idx += 1;
                     break;
                 };
 
-                return Err(::from::Err::SyntaxErr(::from::SyntaxErr::unexpected_token("',' or '}'", &[byte], idx)));
+                return Err(::from::Err::SyntaxErr(::from::SyntaxErr::unexpected_tokens(&["','", "'}'"], &[byte], idx)));
             }
 
 
 
             #missing_field_checks
 
-            Ok(Self {
-                #field_assignments
-            })
+            #assembly
         }
     }
 }
 
 #[inline]
-fn construct_from_json_value_stack_errs_method(var_segs: VariableParseSegments) -> TokenStream2 {
+fn construct_from_json_value_stack_errs_method(
+    var_segs: VariableParseSegments,
+    struct_valdg: Option<TokenStream2>,
+    presence_check: &Option<TokenStream2>,
+) -> TokenStream2 {
     let VariableParseSegments {
         required_field_errs,
         field_var_defs,
         field_parsing_arms,
         missing_field_checks,
+        conditional_checks,
         field_assignments,
     } = var_segs;
 
-    let empty_handling = if required_field_errs.len() > 0 {
-        let required_field_errs = required_field_errs.join(quote! {,});
-
-        quote! {
-            return Err(::from::Errs::ValidationErrs(vec![#required_field_errs]));
-        }
-    } else {
-        quote! {
-            return Ok(Self {
-                #field_assignments
-            });
-        }
-    };
+    let (empty_handling, assembly) = stack_errs_tail(
+        required_field_errs,
+        &field_assignments,
+        &conditional_checks,
+        struct_valdg,
+        presence_check,
+    );
 
     quote! {
         fn from_json_value_stack_errs(json: &[u8], idx: &mut usize, path: &::from::Path) -> Result<Self, ::from::Errs>{
@@ -329,6 +411,7 @@ fn construct_from_json_value_stack_errs_method(var_segs: VariableParseSegments)
 
 
             if byte.eq(&b'}') {
+                *idx += 1;
                 #empty_handling
             };
 
@@ -363,22 +446,17 @@ fn construct_from_json_value_stack_errs_method(var_segs: VariableParseSegments)
                 };
 
                 if byte == b'}' {
+                    *idx += 1;
                     break;
                 };
 
-                return Err(::from::Errs::SyntaxErr(::from::SyntaxErr::unexpected_token("',' or '}'", &[byte], idx)));
+                return Err(::from::Errs::SyntaxErr(::from::SyntaxErr::unexpected_tokens(&["','", "'}'"], &[byte], idx)));
             }
 
 
             #missing_field_checks
 
-            if errs.len() > 0 {
-                return Err(::from::Errs::ValidationErrs(errs));
-            };
-
-            Ok(Self {
-                #field_assignments
-            })
+            #assembly
         }
     }
 }
@@ -386,28 +464,25 @@ fn construct_from_json_value_stack_errs_method(var_segs: VariableParseSegments)
 #[inline]
 fn construct_from_json_value_stack_errs_lang_method(
     var_segs: VariableParseSegments,
+    struct_valdg: Option<TokenStream2>,
+    presence_check: &Option<TokenStream2>,
 ) -> TokenStream2 {
     let VariableParseSegments {
         required_field_errs,
         field_var_defs,
         field_parsing_arms,
         missing_field_checks,
+        conditional_checks,
         field_assignments,
     } = var_segs;
 
-    let empty_handling = if required_field_errs.len() > 0 {
-        let required_field_errs = required_field_errs.join(quote! {,});
-
-        quote! {
-            return Err(::from::Errs::ValidationErrs(vec![#required_field_errs]));
-        }
-    } else {
-        quote! {
-            return Ok(Self {
-                #field_assignments
-            });
-        }
-    };
+    let (empty_handling, assembly) = stack_errs_tail(
+        required_field_errs,
+        &field_assignments,
+        &conditional_checks,
+        struct_valdg,
+        presence_check,
+    );
 
     quote! {
         fn from_json_value_stack_errs_lang(json: &[u8], idx: &mut usize, path: &::from::Path, lang: &str) -> Result<Self, ::from::Errs> {
@@ -421,6 +496,7 @@ fn construct_from_json_value_stack_errs_lang_method(
 
 
             if byte.eq(&b'}') {
+                *idx += 1;
                 #empty_handling
             };
 
@@ -455,22 +531,176 @@ fn construct_from_json_value_stack_errs_lang_method(
                 };
 
                 if byte == b'}' {
+                    *idx += 1;
                     break;
                 };
 
-                return Err(::from::Errs::SyntaxErr(::from::SyntaxErr::unexpected_token("',' or '}'", &[byte], idx)));
+                return Err(::from::Errs::SyntaxErr(::from::SyntaxErr::unexpected_tokens(&["','", "'}'"], &[byte], idx)));
             }
 
 
             #missing_field_checks
 
-            if errs.len() > 0 {
-                return Err(::from::Errs::ValidationErrs(errs));
-            };
-
-            Ok(Self {
-                #field_assignments
-            })
+            #assembly
         }
     }
 }
+
+// the tail of the instant return methods, shared between the empty object
+// handling ('early') and the end of the method
+#[inline]
+fn instant_return_tail(
+    required_field_errs: Tokens,
+    field_assignments: &TokenStream2,
+    conditional_checks: &TokenStream2,
+    struct_valdg: Option<TokenStream2>,
+) -> (TokenStream2, TokenStream2) {
+    let (assembly, val) = match struct_valdg {
+        Some(struct_valdg) => (
+            quote! {
+                let val = Self {
+                    #field_assignments
+                };
+
+                #struct_valdg
+            },
+            quote! {val},
+        ),
+
+        None => (
+            TokenStream2::new(),
+            quote! {
+                Self {
+                    #field_assignments
+                }
+            },
+        ),
+    };
+
+    let empty_handling = if required_field_errs.len() > 0 {
+        let required_field_errs = required_field_errs.join(quote! {,});
+
+        quote! {
+            return Err(From::from(#required_field_errs));
+        }
+    } else {
+        quote! {
+            #conditional_checks
+
+            #assembly
+
+            return Ok(#val);
+        }
+    };
+
+    (
+        empty_handling,
+        quote! {
+            #conditional_checks
+
+            #assembly
+
+            Ok(#val)
+        },
+    )
+}
+
+#[inline]
+fn stack_errs_tail(
+    required_field_errs: Tokens,
+    field_assignments: &TokenStream2,
+    conditional_checks: &TokenStream2,
+    struct_valdg: Option<TokenStream2>,
+    presence_check: &Option<TokenStream2>,
+) -> (TokenStream2, TokenStream2) {
+    let errs_check = quote! {
+        if errs.len() > 0 {
+            return Err(::from::Errs::ValidationErrs(errs));
+        };
+    };
+
+    let no_struct_valdg = struct_valdg.is_none();
+
+    let (assembly, val) = match struct_valdg {
+        Some(struct_valdg) => {
+            let presence_check = presence_check.as_ref().map(|presence_check| {
+                quote! {
+                    if #presence_check {
+                        return Err(::from::Errs::ValidationErrs(errs));
+                    };
+                }
+            });
+
+            (
+                quote! {
+                    #presence_check
+
+                    let val = Self {
+                        #field_assignments
+                    };
+
+                    #struct_valdg
+
+                    #errs_check
+                },
+                quote! {val},
+            )
+        }
+
+        None => (
+            errs_check,
+            quote! {
+                Self {
+                    #field_assignments
+                }
+            },
+        ),
+    };
+
+    let empty_handling = match (required_field_errs.len() > 0, conditional_checks.is_empty()) {
+        (true, true) => {
+            let required_field_errs = required_field_errs.join(quote! {,});
+
+            quote! {
+                return Err(::from::Errs::ValidationErrs(vec![#required_field_errs]));
+            }
+        }
+
+        (true, false) => {
+            let required_field_errs = required_field_errs.join(quote! {,});
+
+            quote! {
+                let mut errs = vec![#required_field_errs];
+
+                #conditional_checks
+
+                return Err(::from::Errs::ValidationErrs(errs));
+            }
+        }
+
+        (false, true) if no_struct_valdg => quote! {
+            return Ok(#val);
+        },
+
+        (false, _) => quote! {
+            let mut errs = Vec::<::from::ValidationErr>::new();
+
+            #conditional_checks
+
+            #assembly
+
+            return Ok(#val);
+        },
+    };
+
+    (
+        empty_handling,
+        quote! {
+            #conditional_checks
+
+            #assembly
+
+            Ok(#val)
+        },
+    )
+}
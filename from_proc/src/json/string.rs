@@ -27,7 +27,13 @@ impl FromJsonValueImpl {
         )?;
 
         match (option, null) {
-            (true, true) => Ok(self.option_null_string(field_ident, processing, type_mismatch_err)),
+            (true, true) => self.option_null_string(
+                field_ident,
+                attrs,
+                dflt_lang,
+                processing,
+                type_mismatch_err,
+            ),
 
             (true, false) => self.option_not_null_string(
                 field_ident,
@@ -55,13 +61,15 @@ impl FromJsonValueImpl {
     fn option_null_string(
         &mut self,
         field_ident: custom_types::FieldIdent,
+        attrs: Vec<Attribute>,
+        dflt_lang: &str,
         processing: types::string::Processing,
         type_mismatch_err: custom_types::TypeMismatchErr,
-    ) {
+    ) -> Result<(), TokenStream> {
         let custom_types::FieldIdent {
             var_name,
             ident: field_ident,
-            quoted: _quoted_field_ident,
+            quoted: quoted_field_ident,
             byte_quoted: byte_quoted_field_ident,
         } = field_ident;
 
@@ -69,8 +77,13 @@ impl FromJsonValueImpl {
 
         let var_assignment = quote! {#var_name = ::from::OptionNull::Some(val);};
 
-        let null_handling =
-            custom_types::NullHandling::from_one(quote! {#var_name = ::from::OptionNull::Null;});
+        let null_handling = self.option_null_handling(
+            &attrs,
+            &quoted_field_ident,
+            dflt_lang,
+            "string",
+            quote! {#var_name = ::from::OptionNull::Null;},
+        )?;
 
         let field_parsing_arm = gen_string_field_parsing_arm(
             byte_quoted_field_ident,
@@ -92,6 +105,8 @@ impl FromJsonValueImpl {
         self.add_field_assignment(quote! {
             #field_ident: #var_name,
         });
+
+        Ok(())
     }
 
     //
@@ -122,9 +137,7 @@ impl FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &&dflt_lang,
-            || -> TokenStream2 {
-                quote! {String::from("expected: string, found: null")}
-            },
+            "string",
         )?;
 
         let field_parsing_arm = gen_string_field_parsing_arm(
@@ -320,9 +333,7 @@ impl FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &&dflt_lang,
-            || -> TokenStream2 {
-                quote! {String::from("expected: string, found: null")}
-            },
+            "string",
         )?;
 
         let field_parsing_arm = gen_string_field_parsing_arm(
@@ -371,9 +382,7 @@ impl FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &&dflt_lang,
-            || -> TokenStream2 {
-                quote! {String::from("expected: string, found: null")}
-            },
+            "string",
         )?;
 
         let before = TokenStream2::new();
@@ -47,10 +47,7 @@ impl super::ElemParsing {
             None => {
                 processing = types::bool::Processing::new();
 
-                type_mismatch_err = custom_types::TypeMismatchErr::new_dflt(
-                    &idx_var,
-                    quote! {format!("expected: boolean, found: {}", found)},
-                );
+                type_mismatch_err = custom_types::TypeMismatchErr::new_dflt(&idx_var, dflt_lang, "boolean");
             }
         };
 
@@ -86,9 +83,7 @@ impl super::ElemParsing {
                     &metas_holder,
                     &idx_var,
                     &dflt_lang,
-                    || -> TokenStream2 {
-                        quote! {String::from("expected: boolean, found: null")}
-                    },
+                    "boolean",
                 )?;
 
                 type_mismatch_err = custom_types::TypeMismatchErr::try_build(
@@ -104,13 +99,11 @@ impl super::ElemParsing {
 
                 null_handling = custom_types::NullHandling::new_dflt(
                     &idx_var,
-                    quote! {String::from("expected: boolean, found: null")},
+                    dflt_lang,
+                    "boolean",
                 );
 
-                type_mismatch_err = custom_types::TypeMismatchErr::new_dflt(
-                    &idx_var,
-                    quote! {format!("expected: boolean, found: {}", found)},
-                );
+                type_mismatch_err = custom_types::TypeMismatchErr::new_dflt(&idx_var, dflt_lang, "boolean");
             }
         };
 
@@ -82,19 +82,14 @@ impl super::ElemParsing {
                     &metas_holder,
                     &idx_var,
                     &dflt_lang,
-                    || -> TokenStream2 {
-                        quote! {String::from("expected: object, found: null")}
-                    },
+                    "object",
                 )?;
             }
 
             None => {
                 processing = Processing::new();
 
-                null_handling = NullHandling::new_dflt(
-                    &idx_var,
-                    quote! {String::from("expected: object, found: null")},
-                );
+                null_handling = NullHandling::new_dflt(&idx_var, dflt_lang, "object");
             }
         };
 
@@ -184,7 +179,6 @@ fn custom_field_parsing_match_temp(
                 Ok(val) => {
                     #valdg
                     #elem_push
-                    *idx += 1;
                 },
 
                 Err(e) => {
@@ -58,10 +58,7 @@ impl super::ElemParsing {
             None => {
                 processing = Processing::new();
 
-                let msg = format!("expected: {}, found: {{}}", expc);
-
-                type_mismatch_err =
-                    TypeMismatchErr::new_dflt(&idx_var, quote! {format!(#msg, found)});
+                type_mismatch_err = TypeMismatchErr::new_dflt(&idx_var, dflt_lang, expc);
             }
         };
 
@@ -97,20 +94,12 @@ impl super::ElemParsing {
         let null_handling;
 
         let expc = F::exp();
-        let null_msg = format!("expected: {}, found: null", expc);
 
         match metas_holder {
             Some(metas_holder) => {
                 processing = Processing::try_build::<H, F>(&metas_holder, &idx_var, dflt_lang)?;
 
-                null_handling = NullHandling::try_build(
-                    &metas_holder,
-                    &idx_var,
-                    &dflt_lang,
-                    || -> TokenStream2 {
-                        quote! {String::from(#null_msg)}
-                    },
-                )?;
+                null_handling = NullHandling::try_build(&metas_holder, &idx_var, &dflt_lang, expc)?;
 
                 type_mismatch_err =
                     TypeMismatchErr::try_build(&metas_holder, &idx_var, &dflt_lang, expc)?;
@@ -119,12 +108,9 @@ impl super::ElemParsing {
             None => {
                 processing = Processing::new();
 
-                null_handling = NullHandling::new_dflt(&idx_var, quote! {String::from(#null_msg)});
+                null_handling = NullHandling::new_dflt(&idx_var, dflt_lang, expc);
 
-                let msg = format!("expected: {}, found: {{}}", expc);
-
-                type_mismatch_err =
-                    TypeMismatchErr::new_dflt(&idx_var, quote! {format!(#msg, found)});
+                type_mismatch_err = TypeMismatchErr::new_dflt(&idx_var, dflt_lang, expc);
             }
         };
 
@@ -63,13 +63,11 @@ impl super::ElemParsing {
             None => {
                 processing = Processing::new();
 
-                let msg = format!("expected: {}, found: {{}}", expc);
-                type_mismatch_err =
-                    TypeMismatchErr::new_dflt(&idx_var, quote! {format!(#msg, found)});
+                type_mismatch_err = TypeMismatchErr::new_dflt(&idx_var, dflt_lang, expc);
 
-                too_large_err = TooLargeErr::new_dflt(&idx_var);
+                too_large_err = TooLargeErr::new_dflt(&idx_var, dflt_lang);
 
-                too_small_err = TooSmallErr::new_dflt(&idx_var);
+                too_small_err = TooSmallErr::new_dflt(&idx_var, dflt_lang);
             }
         };
 
@@ -110,20 +108,11 @@ impl super::ElemParsing {
 
         let expc = I::expc();
 
-        let null_msg = format!("expected: {}, found: null", expc);
-
         match metas_holder {
             Some(metas_holder) => {
                 processing = Processing::try_build::<H, I>(&metas_holder, &idx_var, dflt_lang)?;
 
-                null_handling = NullHandling::try_build(
-                    &metas_holder,
-                    &idx_var,
-                    &dflt_lang,
-                    || -> TokenStream2 {
-                        quote! {String::from(#null_msg)}
-                    },
-                )?;
+                null_handling = NullHandling::try_build(&metas_holder, &idx_var, &dflt_lang, expc)?;
 
                 type_mismatch_err =
                     TypeMismatchErr::try_build(&metas_holder, &idx_var, &dflt_lang, &expc)?;
@@ -136,16 +125,13 @@ impl super::ElemParsing {
             None => {
                 processing = Processing::new();
 
-                null_handling = NullHandling::new_dflt(&idx_var, quote! {String::from(#null_msg)});
+                null_handling = NullHandling::new_dflt(&idx_var, dflt_lang, expc);
 
-                let msg = format!("expected: {}, found: {{}}", expc);
-
-                type_mismatch_err =
-                    TypeMismatchErr::new_dflt(&idx_var, quote! {format!(#msg, found)});
+                type_mismatch_err = TypeMismatchErr::new_dflt(&idx_var, dflt_lang, expc);
 
-                too_large_err = TooLargeErr::new_dflt(&idx_var);
+                too_large_err = TooLargeErr::new_dflt(&idx_var, dflt_lang);
 
-                too_small_err = TooSmallErr::new_dflt(&idx_var);
+                too_small_err = TooSmallErr::new_dflt(&idx_var, dflt_lang);
             }
         };
 
@@ -50,10 +50,7 @@ impl super::ElemParsing {
             None => {
                 processing = Processing::new();
 
-                type_mismatch_err = TypeMismatchErr::new_dflt(
-                    &idx_var,
-                    quote! {format!("expected: string, found: {}", found)},
-                );
+                type_mismatch_err = TypeMismatchErr::new_dflt(&idx_var, dflt_lang, "string");
             }
         };
 
@@ -92,9 +89,7 @@ impl super::ElemParsing {
                     &metas_holder,
                     &idx_var,
                     &dflt_lang,
-                    || -> TokenStream2 {
-                        quote! {String::from("expected: string, found: null")}
-                    },
+                    "string",
                 )?;
 
                 type_mismatch_err =
@@ -104,15 +99,9 @@ impl super::ElemParsing {
             None => {
                 processing = Processing::new();
 
-                null_handling = NullHandling::new_dflt(
-                    &idx_var,
-                    quote! {String::from("expected: string, found: null")},
-                );
+                null_handling = NullHandling::new_dflt(&idx_var, dflt_lang, "string");
 
-                type_mismatch_err = TypeMismatchErr::new_dflt(
-                    &idx_var,
-                    quote! {format!("expected: string, found: {}", found)},
-                );
+                type_mismatch_err = TypeMismatchErr::new_dflt(&idx_var, dflt_lang, "string");
             }
         };
 
@@ -69,10 +69,7 @@ impl super::ElemParsing {
             None => {
                 processing = Processing::new();
 
-                type_mismatch_err = TypeMismatchErr::new_dflt(
-                    &idx_var,
-                    quote! {format!("expected: array, found: {}", found)},
-                );
+                type_mismatch_err = TypeMismatchErr::new_dflt(&idx_var, dflt_lang, "array");
 
                 elem_parsing = Self::try_build::<H>(&sub_vec_var, quote! {i}, None, dflt_lang, of)?;
             }
@@ -138,26 +135,18 @@ impl super::ElemParsing {
                     &metas_holder,
                     &idx_var,
                     &dflt_lang,
-                    || -> TokenStream2 {
-                        quote! {String::from("expected: array, found: null")}
-                    },
+                    "array",
                 )?;
             }
 
             None => {
                 processing = Processing::new();
 
-                type_mismatch_err = TypeMismatchErr::new_dflt(
-                    &idx_var,
-                    quote! {format!("expected: array, found: {}", found)},
-                );
+                type_mismatch_err = TypeMismatchErr::new_dflt(&idx_var, dflt_lang, "array");
 
                 elem_parsing = Self::try_build::<H>(&sub_vec_var, quote! {i}, None, dflt_lang, of)?;
 
-                null_handling = NullHandling::new_dflt(
-                    &idx_var,
-                    quote! {String::from("expected: array, found: null")},
-                );
+                null_handling = NullHandling::new_dflt(&idx_var, dflt_lang, "array");
             }
         };
 
@@ -188,13 +177,22 @@ impl super::ElemParsing {
         null_handling: NullHandling,
         type_mismatch_err: TypeMismatchErr,
     ) -> Self {
+        // the transforms are applied before the constraints
+        let Processing {
+            sanitizing,
+            valdg,
+            valdg_lang,
+            valdg_stack_errs,
+            valdg_stack_errs_lang,
+        } = processing;
+
         Self {
             none: vec_field_match_parse_temp(
                 //&elem_push_empty,
                 &idx_var,
                 &sub_vec_var,
                 elem_parsing.none,
-                processing.valdg,
+                quote! {#sanitizing #valdg},
                 &elem_push,
                 null_handling.none,
                 type_mismatch_err.none,
@@ -205,7 +203,7 @@ impl super::ElemParsing {
                 &idx_var,
                 &sub_vec_var,
                 elem_parsing.lang,
-                processing.valdg_lang,
+                quote! {#sanitizing #valdg_lang},
                 &elem_push,
                 null_handling.lang,
                 type_mismatch_err.lang,
@@ -216,7 +214,7 @@ impl super::ElemParsing {
                 &idx_var,
                 &sub_vec_var,
                 elem_parsing.stack_errs,
-                processing.valdg_stack_errs,
+                quote! {#sanitizing #valdg_stack_errs},
                 &elem_push,
                 null_handling.stack_errs,
                 type_mismatch_err.stack_errs,
@@ -227,7 +225,7 @@ impl super::ElemParsing {
                 &idx_var,
                 &sub_vec_var,
                 elem_parsing.stack_errs_lang,
-                processing.valdg_stack_errs_lang,
+                quote! {#sanitizing #valdg_stack_errs_lang},
                 &elem_push,
                 null_handling.stack_errs_lang,
                 type_mismatch_err.stack_errs_lang,
@@ -285,7 +283,7 @@ fn vec_field_match_parse_temp(
                                 break;
                             },
 
-                            _=> return Err(From::from(::from::SyntaxErr::unexpected_token("',' or ']'", &[byte], idx))),
+                            _=> return Err(From::from(::from::SyntaxErr::unexpected_tokens(&["','", "']'"], &[byte], idx))),
                         }
 
                     }
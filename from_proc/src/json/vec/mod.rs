@@ -30,10 +30,13 @@ impl super::FromJsonValueImpl {
             of,
         )?;
 
+        // the constraints and transforms are applied to the parsed
+        // 'vec' before it is assigned to the field variable (which
+        // may be wrapped by 'Option', 'Null' or 'OptionNull')
         let processing = Processing::try_build(
             &attrs,
             &ty,
-            &field_ident.var_name,
+            &format_ident!("vec"),
             &field_ident.quoted,
             dflt_lang,
         )?;
@@ -46,13 +49,15 @@ impl super::FromJsonValueImpl {
         )?;
 
         match (option, null) {
-            (true, true) => Ok(self.option_null_vec(
+            (true, true) => self.option_null_vec(
                 ty,
                 field_ident,
+                attrs,
+                dflt_lang,
                 elem_parsing,
                 processing,
                 type_mismatch_err,
-            )),
+            ),
 
             (true, false) => self.option_not_null_vec(
                 ty,
@@ -91,10 +96,12 @@ impl super::FromJsonValueImpl {
         &mut self,
         ty: TokenStream2,
         field_ident: custom_types::FieldIdent,
+        attrs: Vec<Attribute>,
+        dflt_lang: &str,
         elem_parsing: ElemParsing,
         processing: Processing,
         type_mismatch_err: custom_types::TypeMismatchErr,
-    ) {
+    ) -> Result<(), TokenStream> {
         let custom_types::FieldIdent {
             ident: field_ident,
             quoted: quoted_field_ident,
@@ -102,15 +109,18 @@ impl super::FromJsonValueImpl {
             var_name,
         } = field_ident;
 
-        let null_handling = custom_types::NullHandling::from_one(
+        let null_handling = self.option_null_handling(
+            &attrs,
+            &quoted_field_ident,
+            dflt_lang,
+            "array",
             quote! {#var_name = ::from::OptionNull::<#ty>::Null;},
-        );
+        )?;
 
         let field_parsing_arm = vec_field_parsing_arm(
             byte_quoted_field_ident,
             quoted_field_ident,
             TokenStream2::new(),
-            quote! {#var_name = ::from::OptionNull::<#ty>::Some(Vec::new());},
             elem_parsing,
             processing,
             quote! {
@@ -129,6 +139,8 @@ impl super::FromJsonValueImpl {
         self.add_field_assignment(quote! {
             #field_ident: #var_name,
         });
+
+        Ok(())
     }
 
     #[inline]
@@ -154,16 +166,13 @@ impl super::FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                quote! {String::from("expected: array, found: null")}
-            },
+            "array",
         )?;
 
         let field_parsing_arm = vec_field_parsing_arm(
             byte_quoted_field_ident,
             quoted_field_ident,
             TokenStream2::new(),
-            quote! {#var_name = Option::<#ty>::Some(Vec::new());},
             elem_parsing,
             processing,
             quote! {
@@ -226,7 +235,6 @@ impl super::FromJsonValueImpl {
             byte_quoted_field_ident,
             quoted_field_ident,
             before,
-            quote! {#var_name = ::from::Null::<#ty>::Some(Vec::new());},
             elem_parsing,
             processing,
             quote! {
@@ -289,16 +297,13 @@ impl super::FromJsonValueImpl {
             &attrs,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                quote! {String::from("expected: array, found: null")}
-            },
+            "array",
         )?;
 
         let field_parsing_arm = vec_field_parsing_arm(
             byte_quoted_field_ident,
             quoted_field_ident,
             before,
-            quote! {#var_name = Vec::new();},
             elem_parsing,
             processing,
             quote! {
@@ -330,7 +335,6 @@ fn vec_field_parsing_arm(
     byte_quoted_field_ident: TokenStream2,
     quoted_field_ident: TokenStream2,
     before: TokenStream2,
-    var_assignment_empty: TokenStream2,
     elem_parsing: ElemParsing,
     processing: Processing,
     var_assignment: TokenStream2,
@@ -342,8 +346,8 @@ fn vec_field_parsing_arm(
             &byte_quoted_field_ident,
             &quoted_field_ident,
             &before,
-            &var_assignment_empty,
             elem_parsing.none,
+            &processing.sanitizing,
             processing.valdg,
             &var_assignment,
             null_handling.none,
@@ -354,8 +358,8 @@ fn vec_field_parsing_arm(
             &byte_quoted_field_ident,
             &quoted_field_ident,
             &before,
-            &var_assignment_empty,
             elem_parsing.lang,
+            &processing.sanitizing,
             processing.valdg_lang,
             &var_assignment,
             null_handling.lang,
@@ -366,8 +370,8 @@ fn vec_field_parsing_arm(
             &byte_quoted_field_ident,
             &quoted_field_ident,
             &before,
-            &var_assignment_empty,
             elem_parsing.stack_errs,
+            &processing.sanitizing,
             processing.valdg_stack_errs,
             &var_assignment,
             null_handling.stack_errs,
@@ -378,8 +382,8 @@ fn vec_field_parsing_arm(
             &byte_quoted_field_ident,
             &quoted_field_ident,
             &before,
-            &var_assignment_empty,
             elem_parsing.stack_errs_lang,
+            &processing.sanitizing,
             processing.valdg_stack_errs_lang,
             &var_assignment,
             null_handling.stack_errs_lang,
@@ -393,8 +397,8 @@ fn vec_field_parsing_arm_temp(
     byte_quoted_field_ident: &TokenStream2,
     quoted_field_ident: &TokenStream2,
     before: &TokenStream2,
-    var_assignment_empty: &TokenStream2,
     elem_parsing: TokenStream2,
+    sanitizing: &TokenStream2,
     valdg: TokenStream2,
     var_assignment: &TokenStream2,
     null_handling: TokenStream2,
@@ -410,9 +414,10 @@ fn vec_field_parsing_arm_temp(
                     ::from::json::utils::skip_whitespaces(json, idx);
                     byte = ::from::json::utils::get_or_unexpected_end(json, idx)?;
 
+                    let mut vec = Vec::new();
+
                     if byte == b']' {
                         *idx += 1;
-                        #var_assignment_empty
                     } else {
                         let mut path = path.clone();
                         path.push(From::from(#quoted_field_ident));
@@ -420,7 +425,6 @@ fn vec_field_parsing_arm_temp(
                         // not 'Path'
                         let path = &path;
 
-                        let mut vec = Vec::new();
                         let mut i = 0usize;
                         loop {
 
@@ -442,15 +446,15 @@ fn vec_field_parsing_arm_temp(
                                     break;
                                 },
 
-                                _=> return Err(From::from(::from::SyntaxErr::unexpected_token("',' or ']'", &[byte], idx))),
+                                _=> return Err(From::from(::from::SyntaxErr::unexpected_tokens(&["','", "']'"], &[byte], idx))),
                             }
 
                         }
-
-                        #var_assignment
                     };
 
+                    #sanitizing
                     #valdg
+                    #var_assignment
 
                 },
 
@@ -2,7 +2,7 @@ use proc_macro::TokenStream;
 
 use proc_macro2::TokenStream as TokenStream2;
 
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 use syn::{Ident, Type as SynType};
 
 mod utils;
@@ -39,6 +39,32 @@ impl Kind {
             _ => Self::from_sgs(&path),
         }
     }
+
+    // a pattern matching only the values that are present (not missing
+    // nor null), binding the inner value to 'binding'
+    pub fn some_pattern(&self, binding: TokenStream2) -> TokenStream2 {
+        match (self.option, self.null) {
+            (true, true) => quote! {::from::OptionNull::Some(#binding)},
+            (true, false) => quote! {Some(#binding)},
+            (false, true) => quote! {::from::Null::Some(#binding)},
+            (false, false) => binding,
+        }
+    }
+
+    // the type wrapped by 'Option', 'Null' or 'OptionNull' (the type
+    // itself if it is not wrapped)
+    pub fn inner_ty<'a>(&self, ty: &'a SynType) -> Result<&'a SynType, TokenStream> {
+        if !self.option && !self.null {
+            return Ok(ty);
+        };
+
+        let path = &type_path_or_err(ty)?.path;
+
+        // 'from_ty' already checked the last segment is the wrapper
+        let sg = &path.segments[path.segments.len() - 1];
+
+        type_or_err(&angle_bracketed_or_err(&sg.arguments)?.args[0])
+    }
 }
 
 pub enum Type {
@@ -5,8 +5,8 @@ use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 
 use syn::{
-    parse, parse_macro_input, punctuated::Punctuated, Data, DeriveInput, Fields, FieldsNamed,
-    Generics, Ident, Path, Token,
+    parse, parse_macro_input, punctuated::Punctuated, Attribute, Data, DeriveInput, Expr, ExprLit,
+    Fields, FieldsNamed, Generics, Ident, Lit, LitStr, Meta, Path, Token,
 };
 
 //
@@ -49,16 +49,46 @@ use kind::{Kind, Type};
 
 mod types;
 
+//
+//
+//
+
+mod patch;
+
+//
+//
+//
+
+mod validate;
+use validate::ValidateImpl;
+
 #[proc_macro_attribute]
 pub fn from(attribute: TokenStream, input: TokenStream) -> TokenStream {
-    let opts = match get_from_opts(attribute) {
+    let (opts, patch_ident, messages, locales) = match get_from_opts(attribute) {
         Ok(opts) => opts,
         Err(ts) => return ts,
     };
 
-    match _from(parse_macro_input!(input as DeriveInput), opts) {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // discard the leftovers of a previous failed expansion
+    utils::statics::take();
+
+    utils::msgs_attribute::set_catalog(messages);
+
+    if let Err(ts) = utils::locales::load(locales.as_ref()) {
+        return ts;
+    };
+
+    match _from(input, opts, patch_ident) {
         Ok(ts) => ts,
-        Err(ts) => ts,
+
+        // the leftovers can not outlive the expansion (they would be
+        // dropped with the thread, after the compiler is gone)
+        Err(ts) => {
+            utils::statics::take();
+            ts
+        }
     }
 }
 
@@ -70,21 +100,74 @@ pub fn from(attribute: TokenStream, input: TokenStream) -> TokenStream {
 //
 //
 
-fn get_from_opts(attribute: TokenStream) -> Result<u8, TokenStream> {
-    let paths =
-        match parse::Parser::parse(Punctuated::<Path, Token![,]>::parse_terminated, attribute) {
+type FromOpts = (u8, Option<Ident>, Option<Path>, Option<LitStr>);
+
+fn get_from_opts(attribute: TokenStream) -> Result<FromOpts, TokenStream> {
+    let metas =
+        match parse::Parser::parse(Punctuated::<Meta, Token![,]>::parse_terminated, attribute) {
             Ok(attrs) => attrs,
             Err(e) => return Err(TokenStream::from(e.to_compile_error())),
         };
 
     let mut from = 0b0000_0000u8;
+    let mut patch_ident = None;
+    let mut messages = None;
+    let mut locales = None;
 
-    for path in &paths {
-        if path.is_ident("json") {
+    for meta in &metas {
+        if meta.path().is_ident("json") {
             from |= 0b0000_0001;
             continue;
         };
 
+        if let Meta::NameValue(nv) = meta {
+            if nv.path.is_ident("patch") {
+                patch_ident = match &nv.value {
+                    Expr::Path(expr) => expr.path.get_ident().cloned(),
+                    _ => None,
+                };
+
+                if patch_ident.is_none() {
+                    return Err(utils::compile_err(
+                        &nv.value,
+                        "expected: struct name (e.g. 'patch = UpdatePerson')",
+                    ));
+                };
+
+                continue;
+            };
+
+            if nv.path.is_ident("messages") {
+                messages = match &nv.value {
+                    Expr::Path(expr) if expr.qself.is_none() => Some(expr.path.clone()),
+                    _ => {
+                        return Err(utils::compile_err(
+                            &nv.value,
+                            "expected: catalog type (e.g. 'messages = MyCatalog')",
+                        ))
+                    }
+                };
+
+                continue;
+            };
+
+            if nv.path.is_ident("locales") {
+                locales = match &nv.value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit), ..
+                    }) => Some(lit.clone()),
+                    _ => {
+                        return Err(utils::compile_err(
+                            &nv.value,
+                            "expected: directory of the locale files (e.g. 'locales = \"locales/\"')",
+                        ))
+                    }
+                };
+
+                continue;
+            };
+        };
+
         /* if path.is_ident("protobuf") {
             from |= 0b0000_0010;
         }; */
@@ -92,11 +175,11 @@ fn get_from_opts(attribute: TokenStream) -> Result<u8, TokenStream> {
 
     match from {
         0 => Err(utils::compile_err(
-            &paths,
+            &metas,
             "at least one of the following must be selected:\n - json\n - protobuf",
         )),
 
-        _ => Ok(from),
+        _ => Ok((from, patch_ident, messages, locales)),
     }
 }
 
@@ -108,7 +191,11 @@ fn get_from_opts(attribute: TokenStream) -> Result<u8, TokenStream> {
 //
 //
 
-fn _from(input: DeriveInput, opts: u8) -> Result<TokenStream, TokenStream> {
+fn _from(
+    input: DeriveInput,
+    opts: u8,
+    patch_ident: Option<Ident>,
+) -> Result<TokenStream, TokenStream> {
     let data_struct = match input.data {
         Data::Struct(s) => s,
 
@@ -145,13 +232,65 @@ fn _from(input: DeriveInput, opts: u8) -> Result<TokenStream, TokenStream> {
     let struct_ident = input.ident;
     let generics = input.generics;
 
+    utils::locales::set_struct_name(struct_ident.to_string());
+
+    let vis = input.vis;
+
+    // only the derives placed after '#[from]' are visible here, they are
+    // applied to the struct and its patch
+    let derives = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .collect::<Vec<_>>();
+
     let struct_fields;
-    let impls;
+    let mut impls;
+    let mut patch_def = TokenStream2::new();
 
     match opts {
         0b01 => {
-            (struct_fields, impls) =
-                build_struct_and_from_json_impl(&struct_ident, &generics, fields, &dflt_lang)?;
+            let patch = match patch_ident {
+                Some(patch_ident) => Some((patch_ident, patch::build(&fields)?)),
+                None => None,
+            };
+
+            (struct_fields, impls) = build_struct_and_from_json_impl(
+                &struct_ident,
+                &generics,
+                fields,
+                &input.attrs,
+                &Vec::new(),
+                &dflt_lang,
+            )?;
+
+            if let Some((patch_ident, patch)) = patch {
+                // the struct-level attributes validate the original struct
+                // so they are not applied to the patch
+                let (patch_struct_fields, patch_impls) = build_struct_and_from_json_impl(
+                    &patch_ident,
+                    &generics,
+                    patch.fields,
+                    &Vec::new(),
+                    &patch.not_null_fields,
+                    &dflt_lang,
+                )?;
+
+                patch_def = quote! {
+                    #(#derives)*
+                    #vis struct #patch_ident #generics{#patch_struct_fields}
+                };
+
+                impls.append(patch_impls);
+                impls.append(patch::impl_methods(
+                    &vis,
+                    &struct_ident,
+                    &patch_ident,
+                    &generics,
+                    patch.assignments,
+                    patch.field_values,
+                ));
+            };
         }
 
         _ => {
@@ -169,11 +308,28 @@ fn _from(input: DeriveInput, opts: u8) -> Result<TokenStream, TokenStream> {
     //
     //
 
-    let vis = input.vis;
+    utils::locales::check(&struct_ident)?;
 
-    let mut struct_def = quote! {#vis struct #struct_ident #generics{#struct_fields}};
+    let mut struct_def = quote! {
+        #(#derives)*
+        #vis struct #struct_ident #generics{#struct_fields}
+    };
+
+    struct_def.append(patch_def);
 
-    struct_def.append(impls);
+    let statics = utils::statics::take();
+
+    if statics.is_empty() {
+        struct_def.append(impls);
+    } else {
+        // the statics are only visible to the impls
+        struct_def.append(quote! {
+            const _: () = {
+                #statics
+                #impls
+            };
+        });
+    };
 
     Ok(struct_def.into())
 }
@@ -182,10 +338,28 @@ fn build_struct_and_from_json_impl(
     struct_ident: &Ident,
     generics: &Generics,
     fields: FieldsNamed,
+    struct_attrs: &Vec<Attribute>,
+    not_null_fields: &[Ident],
     dflt_lang: &str,
 ) -> Result<(TokenStream2, TokenStream2), TokenStream> {
     let mut struct_fields = TokenStream2::new();
     let mut json_impl = FromJsonValueImpl::new();
+    let mut validate_impl = ValidateImpl::new();
+
+    // needed ahead to check the fields referenced by other fields
+    let mut fields_kinds = Vec::with_capacity(fields.named.len());
+    for field in &fields.named {
+        fields_kinds.push((FieldIdent::new(field), Kind::from_ty(&field.ty)?));
+    }
+
+    let mut struct_processing = types::structure::Processing::try_build(struct_attrs)?;
+
+    for conditional_check in
+        ConditionalCheck::try_build_for_struct(struct_attrs, &fields_kinds, dflt_lang)?
+    {
+        validate_impl.add_conditional_check(conditional_check.clone());
+        json_impl.add_conditional_check(conditional_check);
+    }
 
     let mut kind;
     let mut field_ident;
@@ -204,8 +378,31 @@ fn build_struct_and_from_json_impl(
 
         field_ident = FieldIdent::new(&field);
 
+        json_impl.set_reject_null(not_null_fields.contains(&field_ident.ident));
+
         struct_fields.append(quote! {#field,});
 
+        struct_processing.add_field_comparisons(
+            &attrs,
+            &field_ident,
+            &kind,
+            &fields_kinds,
+            dflt_lang,
+        )?;
+
+        for conditional_check in ConditionalCheck::try_build_for_field(
+            &attrs,
+            &field_ident,
+            &kind,
+            &fields_kinds,
+            dflt_lang,
+        )? {
+            validate_impl.add_conditional_check(conditional_check.clone());
+            json_impl.add_conditional_check(conditional_check);
+        }
+
+        validate_impl.add_field(&attrs, &field_ident, &kind, dflt_lang)?;
+
         match kind.ty {
             Type::I8 => json_impl.add_int_field::<i8>(
                 field_ident,
@@ -352,10 +549,23 @@ fn build_struct_and_from_json_impl(
         };
     }
 
-    let mut json_impl = json_impl.construct(struct_ident, generics);
+    let mut json_impl = json_impl.construct(struct_ident, generics, struct_processing.clone());
+
+    json_impl.append(validate_impl.construct(struct_ident, generics, struct_processing));
+
+    let syntax_template = match utils::msgs_attribute::catalog() {
+        Some(catalog) => {
+            quote! {|kind, lang| <#catalog as ::from::Messages>::syntax_template(kind, lang)}
+        }
+        None => quote! {|_, _| None},
+    };
 
     json_impl.append(quote! {
-        impl ::from::json::FromJson for #struct_ident #generics {}
+        impl ::from::json::FromJson for #struct_ident #generics {
+            fn syntax_err_lang(err: &mut ::from::SyntaxErr, lang: &str) {
+                err.localize(lang, #dflt_lang, #syntax_template);
+            }
+        }
     });
 
     Ok((struct_fields, json_impl))
@@ -1,12 +1,13 @@
 use proc_macro::TokenStream;
 
-use syn::{ext::IdentExt, punctuated::Punctuated, Attribute, Meta, MetaNameValue, Path, Token};
+use syn::{ext::IdentExt, punctuated::Punctuated, Attribute, Expr, Meta, MetaNameValue, Path, Token};
 
 use crate::utils;
 
 pub type Metas = Punctuated<Meta, Token![,]>;
 pub type MetaNameValues = Punctuated<MetaNameValue, Token![,]>;
 pub type MetaPaths = Punctuated<Path, Token![,]>;
+pub type MetaExprs = Punctuated<Expr, Token![,]>;
 
 pub mod value;
 
@@ -19,6 +20,9 @@ pub trait MetasHolder {
     fn parse_paths_from_list_if_found(&self, ident: &str)
         -> Result<Option<MetaPaths>, TokenStream>;
 
+    fn parse_exprs_from_list_if_found(&self, ident: &str)
+        -> Result<Option<MetaExprs>, TokenStream>;
+
     //
     // name_value
     fn parse_value_if_found<'a, T>(
@@ -39,6 +43,10 @@ pub trait MetasHolder {
     //
     // path
     fn contains_ident(&self, ident: &str) -> bool;
+
+    //
+    // all metas in declaration order
+    fn metas(&self) -> Vec<&Meta>;
 }
 
 impl MetasHolder for Vec<Attribute> {
@@ -110,6 +118,31 @@ impl MetasHolder for Vec<Attribute> {
         Ok(None)
     }
 
+    fn parse_exprs_from_list_if_found(
+        &self,
+        ident: &str,
+    ) -> Result<Option<MetaExprs>, TokenStream> {
+        for attr in self {
+            let list = match &attr.meta {
+                Meta::List(list) => list,
+                _ => continue,
+            };
+
+            match list.path.get_ident() {
+                Some(i) if i.unraw().eq(ident) => {
+                    return Ok(Some(
+                        list.parse_args_with(MetaExprs::parse_terminated)
+                            .map_err(|e| utils::spanned_compile_err(e.span(), e))?,
+                    ));
+                }
+
+                _ => continue,
+            }
+        }
+
+        Ok(None)
+    }
+
     fn parse_value_if_found<'a, T>(
         &'a self,
         name: &str,
@@ -186,6 +219,11 @@ impl MetasHolder for Vec<Attribute> {
 
         false
     }
+
+    #[inline]
+    fn metas(&self) -> Vec<&Meta> {
+        self.iter().map(|attr| &attr.meta).collect()
+    }
 }
 
 impl MetasHolder for Metas {
@@ -257,6 +295,31 @@ impl MetasHolder for Metas {
         Ok(None)
     }
 
+    fn parse_exprs_from_list_if_found(
+        &self,
+        ident: &str,
+    ) -> Result<Option<MetaExprs>, TokenStream> {
+        for meta in self {
+            let list = match meta {
+                Meta::List(list) => list,
+                _ => continue,
+            };
+
+            match list.path.get_ident() {
+                Some(i) if i.unraw().eq(ident) => {
+                    return Ok(Some(
+                        list.parse_args_with(MetaExprs::parse_terminated)
+                            .map_err(|e| utils::spanned_compile_err(e.span(), e))?,
+                    ));
+                }
+
+                _ => continue,
+            }
+        }
+
+        Ok(None)
+    }
+
     fn parse_value_if_found<'a, T>(
         &'a self,
         name: &str,
@@ -330,4 +393,9 @@ impl MetasHolder for Metas {
 
         false
     }
+
+    #[inline]
+    fn metas(&self) -> Vec<&Meta> {
+        self.iter().collect()
+    }
 }
@@ -1,5 +1,5 @@
 use proc_macro::TokenStream;
-use syn::{Expr, ExprLit, Lit, Path};
+use syn::{Expr, ExprArray, ExprLit, Lit, Path};
 
 use crate::{utils, Null};
 
@@ -450,6 +450,25 @@ where
     }
 }
 
+impl FromExpr for ExprArray {
+    fn from_expr(expr: &Expr) -> Result<Self, TokenStream> {
+        const ERR: &str = "expected: array";
+
+        match expr {
+            Expr::Array(arr) => Ok(arr.clone()),
+
+            _ => Err(utils::compile_err(&expr, ERR)),
+        }
+    }
+}
+
+// any expression, left to be checked by the compiler
+impl FromExpr for Expr {
+    fn from_expr(expr: &Expr) -> Result<Self, TokenStream> {
+        Ok(expr.clone())
+    }
+}
+
 impl FromExpr for Path {
     fn from_expr(expr: &Expr) -> Result<Self, TokenStream> {
         const ERR: &str = "expected: Path";
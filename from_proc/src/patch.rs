@@ -0,0 +1,235 @@
+use proc_macro::TokenStream;
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_quote, FieldsNamed, Generics, Ident, Path, Visibility};
+
+use crate::{
+    kind::{Kind, Type},
+    metas_holder::MetasHolder,
+    utils::{self, Append},
+};
+
+// attributes that do not make sense in a patch since a missing field
+// means "keep the current value"
+const DISCARDED_ATTRS: [&str; 5] = [
+    "default",
+    "required",
+    "required_if",
+    "required_unless",
+    "patch",
+];
+
+pub struct Patch {
+    pub fields: FieldsNamed,
+    // the fields that are neither optional nor nullable in the original
+    // struct, the patch rejects null for them
+    pub not_null_fields: Vec<Ident>,
+    // the body of 'apply_to'
+    pub assignments: TokenStream2,
+    // the fields of the value built by 'into_value'
+    pub field_values: TokenStream2,
+}
+
+// 'patch = UpdatePerson' option: a companion struct whose fields are all
+// wrapped by 'OptionNull' (missing = keep), null clears the optional and
+// nullable fields and is rejected by the parser for the others. The fields
+// with '#[patch = UpdateAddress]' hold the patch of the nested struct and
+// are merged into the current value instead of replacing it.
+pub fn build(fields: &FieldsNamed) -> Result<Patch, TokenStream> {
+    let mut patch_fields = fields.clone();
+    let mut not_null_fields = Vec::new();
+    let mut assignments = TokenStream2::new();
+    let mut field_values = TokenStream2::new();
+
+    for field in patch_fields.named.iter_mut() {
+        let kind = Kind::from_ty(&field.ty)?;
+
+        let nested = match field.attrs.parse_value_if_found::<Path>("patch")? {
+            Some((nested, nv)) => match kind.ty {
+                Type::Custom(_) => Some(nested),
+                _ => {
+                    return Err(utils::compile_err(
+                        nv,
+                        "only the fields of custom types can be patched",
+                    ))
+                }
+            },
+            None => None,
+        };
+
+        let inner = match &nested {
+            Some(nested) => parse_quote! {#nested},
+            None => kind.inner_ty(&field.ty)?.clone(),
+        };
+
+        field.attrs.retain(|attr| {
+            !DISCARDED_ATTRS
+                .iter()
+                .any(|name| attr.path().is_ident(name))
+        });
+
+        let ident = field.ident.as_ref().unwrap();
+
+        if !kind.option && !kind.null {
+            not_null_fields.push(ident.clone());
+        };
+
+        // the values are moved out of the patch
+        assignments.append(match (&nested, kind.option, kind.null) {
+            (None, false, false) => quote! {
+                if let ::from::OptionNull::Some(val) = self.#ident {
+                    target.#ident = val;
+                };
+            },
+
+            (None, true, false) => quote! {
+                match self.#ident {
+                    ::from::OptionNull::Some(val) => target.#ident = Some(val),
+                    ::from::OptionNull::Null => target.#ident = None,
+                    ::from::OptionNull::None => {}
+                };
+            },
+
+            (None, false, true) => quote! {
+                match self.#ident {
+                    ::from::OptionNull::Some(val) => target.#ident = ::from::Null::Some(val),
+                    ::from::OptionNull::Null => target.#ident = ::from::Null::Null,
+                    ::from::OptionNull::None => {}
+                };
+            },
+
+            (None, true, true) => quote! {
+                match self.#ident {
+                    ::from::OptionNull::None => {}
+                    val => target.#ident = val,
+                };
+            },
+
+            // a nested patch is merged into the current value, when there
+            // is no value it is built from the patch (if it is complete)
+            (Some(_), false, false) => quote! {
+                if let ::from::OptionNull::Some(patch) = self.#ident {
+                    patch.apply_to(&mut target.#ident);
+                };
+            },
+
+            (Some(_), true, false) => quote! {
+                match self.#ident {
+                    ::from::OptionNull::Some(patch) => match &mut target.#ident {
+                        Some(val) => patch.apply_to(val),
+                        None => target.#ident = patch.into_value(),
+                    },
+                    ::from::OptionNull::Null => target.#ident = None,
+                    ::from::OptionNull::None => {}
+                };
+            },
+
+            (Some(_), false, true) => quote! {
+                match self.#ident {
+                    ::from::OptionNull::Some(patch) => match &mut target.#ident {
+                        ::from::Null::Some(val) => patch.apply_to(val),
+                        ::from::Null::Null => {
+                            if let Some(val) = patch.into_value() {
+                                target.#ident = ::from::Null::Some(val);
+                            };
+                        }
+                    },
+                    ::from::OptionNull::Null => target.#ident = ::from::Null::Null,
+                    ::from::OptionNull::None => {}
+                };
+            },
+
+            (Some(_), true, true) => quote! {
+                match self.#ident {
+                    ::from::OptionNull::Some(patch) => match &mut target.#ident {
+                        ::from::OptionNull::Some(val) => patch.apply_to(val),
+                        _ => {
+                            if let Some(val) = patch.into_value() {
+                                target.#ident = ::from::OptionNull::Some(val);
+                            };
+                        }
+                    },
+                    ::from::OptionNull::Null => target.#ident = ::from::OptionNull::Null,
+                    ::from::OptionNull::None => {}
+                };
+            },
+        });
+
+        let val = match nested {
+            Some(_) => quote! {val.into_value()?},
+            None => quote! {val},
+        };
+
+        // a value can only be built when every field that is not optional
+        // in the original struct is present
+        field_values.append(match (kind.option, kind.null) {
+            (false, false) => quote! {
+                #ident: match self.#ident {
+                    ::from::OptionNull::Some(val) => #val,
+                    _ => return None,
+                },
+            },
+
+            (true, false) => quote! {
+                #ident: match self.#ident {
+                    ::from::OptionNull::Some(val) => Some(#val),
+                    _ => None,
+                },
+            },
+
+            (false, true) => quote! {
+                #ident: match self.#ident {
+                    ::from::OptionNull::Some(val) => ::from::Null::Some(#val),
+                    ::from::OptionNull::Null => ::from::Null::Null,
+                    ::from::OptionNull::None => return None,
+                },
+            },
+
+            (true, true) => quote! {
+                #ident: match self.#ident {
+                    ::from::OptionNull::Some(val) => ::from::OptionNull::Some(#val),
+                    ::from::OptionNull::Null => ::from::OptionNull::Null,
+                    ::from::OptionNull::None => ::from::OptionNull::None,
+                },
+            },
+        });
+
+        field.ty = parse_quote! {::from::OptionNull<#inner>};
+    }
+
+    Ok(Patch {
+        fields: patch_fields,
+        not_null_fields,
+        assignments,
+        field_values,
+    })
+}
+
+pub fn impl_methods(
+    vis: &Visibility,
+    struct_ident: &Ident,
+    patch_ident: &Ident,
+    generics: &Generics,
+    assignments: TokenStream2,
+    field_values: TokenStream2,
+) -> TokenStream2 {
+    quote! {
+        impl #generics #patch_ident #generics {
+            /// Merges the patch into `target`: missing fields are kept,
+            /// null fields are cleared, nested patches are merged and the
+            /// other fields are replaced.
+            #vis fn apply_to(self, target: &mut #struct_ident #generics) {
+                #assignments
+            }
+
+            /// Builds a new value from the patch, `None` if a field that is
+            /// not optional in the original struct is missing.
+            #vis fn into_value(self) -> Option<#struct_ident #generics> {
+                Some(#struct_ident {
+                    #field_values
+                })
+            }
+        }
+    }
+}
@@ -42,10 +42,13 @@ impl Processing {
 
             let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
 
+            let code = utils::msgs_attribute::code("must_be").param("value", value);
+
             let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                 &meta_name_values,
                 &prop_or_idx,
                 dflt_lang,
+                code,
                 || -> TokenStream2 {
                     let msg = format!("value must be {}", value);
 
@@ -53,55 +56,46 @@ impl Processing {
                 },
             )?;
 
+            let cond = utils::groups::guard(&metas, quote! {val != #value})?;
+
             valdg.push(quote! {
-                if val != #value {
+                if #cond {
                     return Err(From::from(#single_msg_err));
                 }
             });
 
             valdg_lang.push(quote! {
-                if val != #value {
+                if #cond {
                     return Err(From::from(#multi_msgs_err));
                 }
             });
 
             valdg_stack_errs.push(quote! {
-                if val != #value {
+                if #cond {
                     errs.push(#single_msg_err);
                 }
             });
 
             valdg_stack_errs_lang.push(quote! {
-                if val != #value {
+                if #cond {
                     errs.push(#multi_msgs_err);
                 }
             });
         };
 
-        if let Some(paths) = metas_holder.parse_paths_from_list_if_found("validators")? {
-            for path in paths {
-                valdg.push(quote! {
-                    if let Err(e) = <#path as ::from::Validator<bool>>::none(&val, &path) {
-                        return Err(From::from(e));
-                    }
-                });
-                valdg_lang.push(quote! {
-                    if let Err(e) = <#path as ::from::Validator<bool>>::lang(&val, &path, lang) {
-                        return Err(From::from(e));
-                    }
-                });
-                valdg_stack_errs.push(quote! {
-                    if let Err(mut errs2) = <#path as ::from::Validator<bool>>::stack_errs(&val, &path) {
-                        errs.append(&mut errs2);
-                    }
-                });
-                valdg_stack_errs_lang.push(quote! {
-                    if let Err(mut errs2) = <#path as ::from::Validator<bool>>::stack_errs_lang(&val, &path, lang) {
-                        errs.append(&mut errs2);
-                    }
-                });
-            }
-        };
+        for validator in utils::validators::parse(metas_holder)? {
+            let calls = validator.calls(
+                &quote! {bool},
+                &quote! {&val},
+                &quote! {&path},
+                Some(prop_or_idx),
+            );
+
+            valdg.push(calls.none);
+            valdg_lang.push(calls.lang);
+            valdg_stack_errs.push(calls.stack_errs);
+            valdg_stack_errs_lang.push(calls.stack_errs_lang);
+        }
 
         let r#else = quote! {else};
         let semi_colon = quote! {;};
@@ -264,10 +258,7 @@ impl JsonSegVars {
             &metas_holder,
             &prop_or_idx,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: boolean, found: null");
-                quote! {String::from(#msg)}
-            },
+            "boolean",
         )?;
 
         Ok(Self {
@@ -423,11 +414,7 @@ impl JsonSegVars {
             &metas_holder,
             &prop_or_idx,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: boolean, found: null");
-
-                quote! {String::from(#msg)}
-            },
+            "boolean",
         )?;
 
         Ok(Self {
@@ -475,11 +462,7 @@ impl JsonSegVars {
             &metas_holder,
             &prop_or_idx,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: boolean, found: null");
-
-                quote! {String::from(#msg)}
-            },
+            "boolean",
         )?;
 
         Ok(Self {
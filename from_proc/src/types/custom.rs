@@ -3,7 +3,7 @@ use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 
-use crate::{metas_holder::MetasHolder, Tokens};
+use crate::{metas_holder::MetasHolder, utils, Tokens};
 pub struct Processing {
     pub valdg: TokenStream2,                 // instant return - dflt message
     pub valdg_lang: TokenStream2,            // instant return - match lang
@@ -35,30 +35,14 @@ impl Processing {
         let mut valdg_stack_errs = Tokens::new();
         let mut valdg_stack_errs_lang = Tokens::new();
 
-        if let Some(paths) = metas_holder.parse_paths_from_list_if_found("validators")? {
-            for path in paths {
-                valdg.push(quote! {
-                    if let Err(e) = <#path as ::from::Validator<#ty>>::none(&val, &path) {
-                        return Err(From::from(e));
-                    }
-                });
-                valdg_lang.push(quote! {
-                    if let Err(e) = <#path as ::from::Validator<#ty>>::lang(&val, &path, lang) {
-                        return Err(From::from(e));
-                    }
-                });
-                valdg_stack_errs.push(quote! {
-                    if let Err(mut errs2) = <#path as ::from::Validator<#ty>>::stack_errs(&val, &path) {
-                        errs.append(&mut errs2);
-                    }
-                });
-                valdg_stack_errs_lang.push(quote! {
-                    if let Err(mut errs2) = <#path as ::from::Validator<#ty>>::stack_errs_lang(&val, &path, lang) {
-                        errs.append(&mut errs2);
-                    }
-                });
-            }
-        };
+        for validator in utils::validators::parse(metas_holder)? {
+            let calls = validator.calls(ty, &quote! {&val}, &quote! {&path}, None);
+
+            valdg.push(calls.none);
+            valdg_lang.push(calls.lang);
+            valdg_stack_errs.push(calls.stack_errs);
+            valdg_stack_errs_lang.push(calls.stack_errs_lang);
+        }
 
         let r#else = quote! {else};
         let semi_colon = quote! {;};
@@ -311,15 +295,7 @@ impl JsonSegVars {
             &metas_holder,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = unsafe {
-                    format!(
-                        "expected: {}, found: null",
-                        ty.to_string().split("::").last().unwrap_unchecked()
-                    )
-                };
-                quote! {String::from(#msg)}
-            },
+            unsafe { ty.to_string().split("::").last().unwrap_unchecked() },
         )?;
 
         Ok(Self {
@@ -484,16 +460,7 @@ impl JsonSegVars {
             &metas_holder,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = unsafe {
-                    format!(
-                        "expected: {}, found: null",
-                        ty.to_string().split("::").last().unwrap_unchecked()
-                    )
-                };
-
-                quote! {String::from(#msg)}
-            },
+            unsafe { ty.to_string().split("::").last().unwrap_unchecked() },
         )?;
 
         Ok(Self {
@@ -541,16 +508,7 @@ impl JsonSegVars {
             &metas_holder,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = unsafe {
-                    format!(
-                        "expected: {}, found: null",
-                        ty.to_string().split("::").last().unwrap_unchecked()
-                    )
-                };
-
-                quote! {String::from(#msg)}
-            },
+            unsafe { ty.to_string().split("::").last().unwrap_unchecked() },
         )?;
 
         Ok(Self {
@@ -9,8 +9,9 @@ use crate::{
     utils, Tokens,
 };
 
-pub trait Floats: FromExpr + ToTokens + Display + Debug {
+pub trait Floats: FromExpr + ToTokens + Display + Debug + PartialOrd + Default {
     fn name_of_fracs_counter_util() -> TokenStream2;
+    fn name_of_multiple_checker_util() -> TokenStream2;
     fn exp() -> &'static str;
     fn ty() -> TokenStream2;
 }
@@ -20,6 +21,10 @@ impl Floats for f32 {
         quote! {num_of_f32_fracs}
     }
 
+    fn name_of_multiple_checker_util() -> TokenStream2 {
+        quote! {is_multiple_of_f32}
+    }
+
     fn exp() -> &'static str {
         "f32"
     }
@@ -33,6 +38,10 @@ impl Floats for f64 {
         quote! {num_of_f64_fracs}
     }
 
+    fn name_of_multiple_checker_util() -> TokenStream2 {
+        quote! {is_multiple_of_f64}
+    }
+
     fn exp() -> &'static str {
         "f64"
     }
@@ -114,10 +123,13 @@ impl Processing {
         if !metas_holder.contains_ident("allow_infinite") {
             let meta_name_values = metas_holder.parse_nvs_from_list_or_empty("infinite_msgs")?;
 
+            let code = utils::msgs_attribute::code("infinite");
+
             let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                 &meta_name_values,
                 &quoted_field_ident,
                 dflt_lang,
+                code,
                 || -> TokenStream2 {
                     let msg = format!("number must not be Infinite");
                     quote! {String::from(#msg)}
@@ -157,7 +169,8 @@ impl Processing {
 
             let msg;
             let sign;
-            if metas.contains_ident("inclusive") {
+            // 'inclusive' is kept for backward compatibility
+            if metas.contains_ident("exclusive") || metas.contains_ident("inclusive") {
                 msg = format!("number must be less than {}", value);
                 sign = quote! {>=};
             } else {
@@ -165,35 +178,47 @@ impl Processing {
                 sign = quote! {>};
             };
 
+            let code = utils::msgs_attribute::code("max")
+                .param("max", &value)
+                .placeholder("value", &value)
+                .param(
+                    "exclusive",
+                    metas.contains_ident("exclusive") || metas.contains_ident("inclusive"),
+                )
+                .param("actual", quote! {val});
+
             let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                 &meta_name_values,
                 &quoted_field_ident,
                 dflt_lang,
+                code,
                 || -> TokenStream2 {
                     quote! {String::from(#msg)}
                 },
             )?;
 
+            let cond = utils::groups::guard(&metas, quote! {val #sign #value})?;
+
             valdg.push(quote! {
-                if val #sign #value {
+                if #cond {
                     return Err(From::from(#single_msg_err));
                 }
             });
 
             valdg_lang.push(quote! {
-                if val #sign #value {
+                if #cond {
                     return Err(From::from(#multi_msgs_err));
                 }
             });
 
             valdg_stack_errs.push(quote! {
-                if val #sign #value {
+                if #cond {
                     errs.push(#single_msg_err);
                 }
             });
 
             valdg_stack_errs_lang.push(quote! {
-                if val #sign #value {
+                if #cond {
                     errs.push(#multi_msgs_err);
                 }
             });
@@ -207,7 +232,8 @@ impl Processing {
 
             let msg;
             let sign;
-            if metas.contains_ident("inclusive") {
+            // 'inclusive' is kept for backward compatibility
+            if metas.contains_ident("exclusive") || metas.contains_ident("inclusive") {
                 msg = format!("number must be greater than {}", value);
                 sign = quote! {<=};
             } else {
@@ -215,35 +241,174 @@ impl Processing {
                 sign = quote! {<};
             };
 
+            let code = utils::msgs_attribute::code("min")
+                .param("min", &value)
+                .placeholder("value", &value)
+                .param(
+                    "exclusive",
+                    metas.contains_ident("exclusive") || metas.contains_ident("inclusive"),
+                )
+                .param("actual", quote! {val});
+
             let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                 &meta_name_values,
                 &quoted_field_ident,
                 dflt_lang,
+                code,
+                || -> TokenStream2 {
+                    quote! {String::from(#msg)}
+                },
+            )?;
+
+            let cond = utils::groups::guard(&metas, quote! {val #sign #value})?;
+
+            valdg.push(quote! {
+                if #cond {
+                    return Err(From::from(#single_msg_err));
+                }
+            });
+
+            valdg_lang.push(quote! {
+                if #cond {
+                    return Err(From::from(#multi_msgs_err));
+                }
+            });
+
+            valdg_stack_errs.push(quote! {
+                if #cond {
+                    errs.push(#single_msg_err);
+                }
+            });
+
+            valdg_stack_errs_lang.push(quote! {
+                if #cond {
+                    errs.push(#multi_msgs_err);
+                }
+            });
+        };
+
+        if let Some(metas) = metas_holder.parse_list_if_found("range")? {
+            let (min, _) =
+                metas.parse_value_or_err::<F>("min", "'min' NameValue attribute is required")?;
+
+            let (max, max_nv) =
+                metas.parse_value_or_err::<F>("max", "'max' NameValue attribute is required")?;
+
+            let exclusive = metas.contains_ident("exclusive");
+
+            if (exclusive && min >= max) || min > max {
+                return Err(utils::compile_err(
+                    max_nv,
+                    "'max' must be greater than 'min' (or equal to it if the range is not 'exclusive')",
+                ));
+            };
+
+            let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
+
+            let msg;
+            let cond;
+            if exclusive {
+                msg = format!("number must be between {} and {} (exclusive)", min, max);
+                cond = quote! {val <= #min || val >= #max};
+            } else {
+                msg = format!("number must be between {} and {}", min, max);
+                cond = quote! {!(#min..=#max).contains(&val)};
+            };
+
+            let code = utils::msgs_attribute::code("range")
+                .param("min", &min)
+                .param("max", &max)
+                .param("exclusive", exclusive)
+                .param("actual", quote! {val});
+
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &meta_name_values,
+                quoted_field_ident,
+                dflt_lang,
+                code,
+                || -> TokenStream2 {
+                    quote! {String::from(#msg)}
+                },
+            )?;
+
+            let cond = utils::groups::guard(&metas, cond)?;
+
+            valdg.push(quote! {
+                if #cond {
+                    return Err(From::from(#single_msg_err));
+                }
+            });
+
+            valdg_lang.push(quote! {
+                if #cond {
+                    return Err(From::from(#multi_msgs_err));
+                }
+            });
+
+            valdg_stack_errs.push(quote! {
+                if #cond {
+                    errs.push(#single_msg_err);
+                }
+            });
+
+            valdg_stack_errs_lang.push(quote! {
+                if #cond {
+                    errs.push(#multi_msgs_err);
+                }
+            });
+        };
+
+        if let Some(metas) = metas_holder.parse_list_if_found("multiple_of")? {
+            let (value, nv) = metas
+                .parse_value_or_err::<F>("value", "'value' NameValue attribute is required")?;
+
+            if value == F::default() {
+                return Err(utils::compile_err(nv, "'value' must not be zero"));
+            };
+
+            let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
+
+            let code = utils::msgs_attribute::code("multiple_of")
+                .param("multiple_of", &value)
+                .placeholder("value", &value)
+                .param("actual", quote! {val});
+
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &meta_name_values,
+                quoted_field_ident,
+                dflt_lang,
+                code,
                 || -> TokenStream2 {
+                    let msg = format!("number must be a multiple of {}", value);
+
                     quote! {String::from(#msg)}
                 },
             )?;
 
+            let multiple_checker = F::name_of_multiple_checker_util();
+
+            let cond = utils::groups::guard(&metas, quote! {!::from::utils::#multiple_checker(val, #value)})?;
+
             valdg.push(quote! {
-                if val #sign #value {
+                if #cond {
                     return Err(From::from(#single_msg_err));
                 }
             });
 
             valdg_lang.push(quote! {
-                if val #sign #value {
+                if #cond {
                     return Err(From::from(#multi_msgs_err));
                 }
             });
 
             valdg_stack_errs.push(quote! {
-                if val #sign #value {
+                if #cond {
                     errs.push(#single_msg_err);
                 }
             });
 
             valdg_stack_errs_lang.push(quote! {
-                if val #sign #value {
+                if #cond {
                     errs.push(#multi_msgs_err);
                 }
             });
@@ -257,10 +422,15 @@ impl Processing {
 
             let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
 
+            let code = utils::msgs_attribute::code("enum")
+                .list_param("values", &values)
+                .param("actual", quote! {val});
+
             let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                 &meta_name_values,
                 &quoted_field_ident,
                 dflt_lang,
+                code,
                 || -> TokenStream2 {
                     let msg = format!("number must be one of: {:?}", values,);
 
@@ -269,26 +439,78 @@ impl Processing {
             )?;
 
             let values = utils::vec_into_token_stream2_array(values);
+            let cond = utils::groups::guard(&metas, quote! {::from::utils::array_not_contains(#values, val)})?;
+
             valdg.push(quote! {
-                if ::from::utils::array_not_contains(#values, val) {
+                if #cond {
                     return Err(From::from(#single_msg_err));
                 }
             });
 
             valdg_lang.push(quote! {
-                if ::from::utils::array_not_contains(#values, val) {
+                if #cond {
                     return Err(From::from(#multi_msgs_err));
                 }
             });
 
             valdg_stack_errs.push(quote! {
-                if ::from::utils::array_not_contains(#values, val) {
+                if #cond {
                     errs.push(#single_msg_err);
                 }
             });
 
             valdg_stack_errs_lang.push(quote! {
-                if ::from::utils::array_not_contains(#values, val) {
+                if #cond {
+                    errs.push(#multi_msgs_err);
+                }
+            });
+        };
+
+        if let Some(metas) = metas_holder.parse_list_if_found("not_enum")? {
+            let (values, _) = metas.parse_value_or_err::<Vec<F>>(
+                "values",
+                "'values' NameValue attribute is required",
+            )?;
+
+            let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
+
+            let code = utils::msgs_attribute::code("not_enum")
+                .list_param("values", &values)
+                .param("actual", quote! {val});
+
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &meta_name_values,
+                quoted_field_ident,
+                dflt_lang,
+                code,
+                || -> TokenStream2 {
+                    quote! {format!("number {} is not allowed", val)}
+                },
+            )?;
+
+            let values = utils::vec_into_token_stream2_array(values);
+            let cond = utils::groups::guard(&metas, quote! {::from::utils::array_contains(#values, val)})?;
+
+            valdg.push(quote! {
+                if #cond {
+                    return Err(From::from(#single_msg_err));
+                }
+            });
+
+            valdg_lang.push(quote! {
+                if #cond {
+                    return Err(From::from(#multi_msgs_err));
+                }
+            });
+
+            valdg_stack_errs.push(quote! {
+                if #cond {
+                    errs.push(#single_msg_err);
+                }
+            });
+
+            valdg_stack_errs_lang.push(quote! {
+                if #cond {
                     errs.push(#multi_msgs_err);
                 }
             });
@@ -300,10 +522,18 @@ impl Processing {
 
             let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
 
+            let fracs_counter = F::name_of_fracs_counter_util();
+
+            let code = utils::msgs_attribute::code("max_fracs")
+                .param("max", value)
+                .placeholder("value", value)
+                .param("actual", quote! {::from::utils::#fracs_counter(val)});
+
             let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                 &meta_name_values,
                 &quoted_field_ident,
                 dflt_lang,
+                code,
                 || -> TokenStream2 {
                     let msg = format!("fraction digits must not be more than {}", value,);
 
@@ -311,58 +541,45 @@ impl Processing {
                 },
             )?;
 
-            let fracs_counter = F::name_of_fracs_counter_util();
+            let cond = utils::groups::guard(
+                &metas,
+                quote! {::from::utils::#fracs_counter(val) > #value},
+            )?;
 
             valdg.push(quote! {
-                if ::from::utils::#fracs_counter(val) > #value {
+                if #cond {
                     return Err(From::from(#single_msg_err));
                 }
             });
 
             valdg_lang.push(quote! {
-                if ::from::utils::#fracs_counter(val) > #value {
+                if #cond {
                     return Err(From::from(#multi_msgs_err));
                 }
             });
 
             valdg_stack_errs.push(quote! {
-                if ::from::utils::num_of_f32_fracs(val) > #value {
+                if #cond {
                     errs.push(#single_msg_err);
                 }
             });
 
             valdg_stack_errs_lang.push(quote! {
-                if ::from::utils::num_of_f32_fracs(val) > #value {
+                if #cond {
                     errs.push(#multi_msgs_err);
                 }
             });
         };
 
-        if let Some(paths) = metas_holder.parse_paths_from_list_if_found("validators")? {
-            let ty = F::ty();
-            for path in paths {
-                valdg.push(quote! {
-                    if let Err(e) = <#path as ::from::Validator<#ty>>::none(&val, &path) {
-                        return Err(From::from(e));
-                    }
-                });
-                valdg_lang.push(quote! {
-                    if let Err(e) = <#path as ::from::Validator<#ty>>::lang(&val, &path, lang) {
-                        return Err(From::from(e));
-                    }
-                });
-                valdg_stack_errs.push(quote! {
-                    if let Err(mut errs2) = <#path as ::from::Validator<#ty>>::stack_errs(&val, &path) {
-                        errs.append(&mut errs2);
-                    }
-                });
-                valdg_stack_errs_lang.push(quote! {
-                    if let Err(mut errs2) = <#path as ::from::Validator<#ty>>::stack_errs_lang(&val, &path, lang) {
-                        errs.append(&mut errs2);
-                    }
-                });
-            }
-        };
+        let ty = F::ty();
+        for validator in utils::validators::parse(metas_holder)? {
+            let calls = validator.calls(&ty, &quote! {&val}, &quote! {&path}, Some(quoted_field_ident));
+
+            valdg.push(calls.none);
+            valdg_lang.push(calls.lang);
+            valdg_stack_errs.push(calls.stack_errs);
+            valdg_stack_errs_lang.push(calls.stack_errs_lang);
+        }
 
         let r#else = quote! {else};
         let semi_colon = quote! {;};
@@ -530,10 +747,7 @@ impl JsonSegVars {
             &metas_holder,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: {}, found: null", F::exp());
-                quote! {String::from(#msg)}
-            },
+            F::exp(),
         )?;
 
         let ty = F::ty();
@@ -692,11 +906,7 @@ impl JsonSegVars {
             &metas_holder,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: {}, found: null", F::exp());
-
-                quote! {String::from(#msg)}
-            },
+            F::exp(),
         )?;
 
         let ty = F::ty();
@@ -743,11 +953,7 @@ impl JsonSegVars {
             &metas_holder,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: {}, found: null", F::exp());
-
-                quote! {String::from(#msg)}
-            },
+            F::exp(),
         )?;
 
         let ty = F::ty();
@@ -10,7 +10,7 @@ use crate::{
     utils, Tokens,
 };
 
-pub trait Ints: FromExpr + Display + ToTokens + Debug {
+pub trait Ints: FromExpr + Display + ToTokens + Debug + PartialOrd + Default {
     fn expc() -> &'static str;
     fn ty() -> TokenStream2;
 }
@@ -168,37 +168,54 @@ impl Processing {
 
             let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
 
+            let msg;
+            let sign;
+            if metas.contains_ident("exclusive") {
+                msg = format!("number must be less than {}", value);
+                sign = quote! {>=};
+            } else {
+                msg = format!("number must be less than or equal to {}", value);
+                sign = quote! {>};
+            };
+
+            let code = utils::msgs_attribute::code("max")
+                .param("max", &value)
+                .placeholder("value", &value)
+                .param("exclusive", metas.contains_ident("exclusive"))
+                .param("actual", quote! {val});
+
             let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                 &meta_name_values,
                 &prop_or_idx,
                 dflt_lang,
+                code,
                 || -> TokenStream2 {
-                    let msg = format!("number must be less than or equal to {}", value);
-
                     quote! {String::from(#msg)}
                 },
             )?;
 
+            let cond = utils::groups::guard(&metas, quote! {val #sign #value})?;
+
             valdg.push(quote! {
-                if val > #value {
+                if #cond {
                     return Err(From::from(#single_msg_err));
                 }
             });
 
             valdg_lang.push(quote! {
-                if val > #value {
+                if #cond {
                     return Err(From::from(#multi_msgs_err));
                 }
             });
 
             valdg_stack_errs.push(quote! {
-                if val > #value {
+                if #cond {
                     errs.push(#single_msg_err);
                 }
             });
 
             valdg_stack_errs_lang.push(quote! {
-                if val > #value {
+                if #cond {
                     errs.push(#multi_msgs_err);
                 }
             });
@@ -210,37 +227,180 @@ impl Processing {
 
             let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
 
+            let msg;
+            let sign;
+            if metas.contains_ident("exclusive") {
+                msg = format!("number must be greater than {}", value);
+                sign = quote! {<=};
+            } else {
+                msg = format!("number must be greater than or equal to {}", value);
+                sign = quote! {<};
+            };
+
+            let code = utils::msgs_attribute::code("min")
+                .param("min", &value)
+                .placeholder("value", &value)
+                .param("exclusive", metas.contains_ident("exclusive"))
+                .param("actual", quote! {val});
+
             let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                 &meta_name_values,
                 &prop_or_idx,
                 dflt_lang,
+                code,
                 || -> TokenStream2 {
-                    let msg = format!("number must be greater than or equal to {}", value);
+                    quote! {String::from(#msg)}
+                },
+            )?;
+
+            let cond = utils::groups::guard(&metas, quote! {val #sign #value})?;
+
+            valdg.push(quote! {
+                if #cond {
+                    return Err(From::from(#single_msg_err));
+                }
+            });
+
+            valdg_lang.push(quote! {
+                if #cond {
+                    return Err(From::from(#multi_msgs_err));
+                }
+            });
+
+            valdg_stack_errs.push(quote! {
+                if #cond {
+                    errs.push(#single_msg_err);
+                }
+            });
+
+            valdg_stack_errs_lang.push(quote! {
+                if #cond {
+                    errs.push(#multi_msgs_err);
+                }
+            });
+        };
+
+        if let Some(metas) = metas_holder.parse_list_if_found("range")? {
+            let (min, _) =
+                metas.parse_value_or_err::<I>("min", "'min' NameValue attribute is required")?;
+
+            let (max, max_nv) =
+                metas.parse_value_or_err::<I>("max", "'max' NameValue attribute is required")?;
+
+            let exclusive = metas.contains_ident("exclusive");
 
+            if (exclusive && min >= max) || min > max {
+                return Err(utils::compile_err(
+                    max_nv,
+                    "'max' must be greater than 'min' (or equal to it if the range is not 'exclusive')",
+                ));
+            };
+
+            let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
+
+            let msg;
+            let cond;
+            if exclusive {
+                msg = format!("number must be between {} and {} (exclusive)", min, max);
+                cond = quote! {val <= #min || val >= #max};
+            } else {
+                msg = format!("number must be between {} and {}", min, max);
+                cond = quote! {!(#min..=#max).contains(&val)};
+            };
+
+            let code = utils::msgs_attribute::code("range")
+                .param("min", &min)
+                .param("max", &max)
+                .param("exclusive", exclusive)
+                .param("actual", quote! {val});
+
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &meta_name_values,
+                prop_or_idx,
+                dflt_lang,
+                code,
+                || -> TokenStream2 {
                     quote! {String::from(#msg)}
                 },
             )?;
 
+            let cond = utils::groups::guard(&metas, cond)?;
+
             valdg.push(quote! {
-                if val < #value {
+                if #cond {
                     return Err(From::from(#single_msg_err));
                 }
             });
 
             valdg_lang.push(quote! {
-                if val < #value {
+                if #cond {
                     return Err(From::from(#multi_msgs_err));
                 }
             });
 
             valdg_stack_errs.push(quote! {
-                if val < #value {
+                if #cond {
                     errs.push(#single_msg_err);
                 }
             });
 
             valdg_stack_errs_lang.push(quote! {
-                if val < #value {
+                if #cond {
+                    errs.push(#multi_msgs_err);
+                }
+            });
+        };
+
+        if let Some(metas) = metas_holder.parse_list_if_found("multiple_of")? {
+            let (value, nv) = metas
+                .parse_value_or_err::<I>("value", "'value' NameValue attribute is required")?;
+
+            if value == I::default() {
+                return Err(utils::compile_err(nv, "'value' must not be zero"));
+            };
+
+            let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
+
+            let code = utils::msgs_attribute::code("multiple_of")
+                .param("multiple_of", &value)
+                .placeholder("value", &value)
+                .param("actual", quote! {val});
+
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &meta_name_values,
+                prop_or_idx,
+                dflt_lang,
+                code,
+                || -> TokenStream2 {
+                    let msg = format!("number must be a multiple of {}", value);
+
+                    quote! {String::from(#msg)}
+                },
+            )?;
+
+            // 'wrapping_rem' since 'MIN % -1' overflows
+            let cond = utils::groups::guard(&metas, quote! {val.wrapping_rem(#value) != 0})?;
+
+            valdg.push(quote! {
+                if #cond {
+                    return Err(From::from(#single_msg_err));
+                }
+            });
+
+            valdg_lang.push(quote! {
+                if #cond {
+                    return Err(From::from(#multi_msgs_err));
+                }
+            });
+
+            valdg_stack_errs.push(quote! {
+                if #cond {
+                    errs.push(#single_msg_err);
+                }
+            });
+
+            valdg_stack_errs_lang.push(quote! {
+                if #cond {
                     errs.push(#multi_msgs_err);
                 }
             });
@@ -254,10 +414,15 @@ impl Processing {
 
             let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
 
+            let code = utils::msgs_attribute::code("enum")
+                .list_param("values", &values)
+                .param("actual", quote! {val});
+
             let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                 &meta_name_values,
                 &prop_or_idx,
                 dflt_lang,
+                code,
                 || -> TokenStream2 {
                     let msg = format!("number must be one of: {:?}", values,);
 
@@ -266,57 +431,93 @@ impl Processing {
             )?;
 
             let values = utils::vec_into_token_stream2_array(values);
+            let cond = utils::groups::guard(&metas, quote! {::from::utils::array_not_contains(#values, val)})?;
+
             valdg.push(quote! {
-                if ::from::utils::array_not_contains(#values, val) {
+                if #cond {
                     return Err(From::from(#single_msg_err));
                 }
             });
 
             valdg_lang.push(quote! {
-                if ::from::utils::array_not_contains(#values, val) {
+                if #cond {
                     return Err(From::from(#multi_msgs_err));
                 }
             });
 
             valdg_stack_errs.push(quote! {
-                if ::from::utils::array_not_contains(#values, val) {
+                if #cond {
                     errs.push(#single_msg_err);
                 }
             });
 
             valdg_stack_errs_lang.push(quote! {
-                if ::from::utils::array_not_contains(#values, val) {
+                if #cond {
                     errs.push(#multi_msgs_err);
                 }
             });
         };
 
-        if let Some(paths) = metas_holder.parse_paths_from_list_if_found("validators")? {
-            let ty = I::ty();
-            for path in paths {
-                valdg.push(quote! {
-                    if let Err(e) = <#path as ::from::Validator<#ty>>::none(&val, &path) {
-                        return Err(From::from(e));
-                    }
-                });
-                valdg_lang.push(quote! {
-                    if let Err(e) = <#path as ::from::Validator<#ty>>::lang(&val, &path, lang) {
-                        return Err(From::from(e));
-                    }
-                });
-                valdg_stack_errs.push(quote! {
-                    if let Err(mut errs2) = <#path as ::from::Validator<#ty>>::stack_errs(&val, &path) {
-                        errs.append(&mut errs2);
-                    }
-                });
-                valdg_stack_errs_lang.push(quote! {
-                    if let Err(mut errs2) = <#path as ::from::Validator<#ty>>::stack_errs_lang(&val, &path, lang) {
-                        errs.append(&mut errs2);
-                    }
-                });
-            }
+        if let Some(metas) = metas_holder.parse_list_if_found("not_enum")? {
+            let (values, _) = metas.parse_value_or_err::<Vec<I>>(
+                "values",
+                "'values' NameValue attribute is required",
+            )?;
+
+            let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
+
+            let code = utils::msgs_attribute::code("not_enum")
+                .list_param("values", &values)
+                .param("actual", quote! {val});
+
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &meta_name_values,
+                prop_or_idx,
+                dflt_lang,
+                code,
+                || -> TokenStream2 {
+                    quote! {format!("number {} is not allowed", val)}
+                },
+            )?;
+
+            let values = utils::vec_into_token_stream2_array(values);
+            let cond = utils::groups::guard(&metas, quote! {::from::utils::array_contains(#values, val)})?;
+
+            valdg.push(quote! {
+                if #cond {
+                    return Err(From::from(#single_msg_err));
+                }
+            });
+
+            valdg_lang.push(quote! {
+                if #cond {
+                    return Err(From::from(#multi_msgs_err));
+                }
+            });
+
+            valdg_stack_errs.push(quote! {
+                if #cond {
+                    errs.push(#single_msg_err);
+                }
+            });
+
+            valdg_stack_errs_lang.push(quote! {
+                if #cond {
+                    errs.push(#multi_msgs_err);
+                }
+            });
         };
 
+        let ty = I::ty();
+        for validator in utils::validators::parse(metas_holder)? {
+            let calls = validator.calls(&ty, &quote! {&val}, &quote! {&path}, Some(prop_or_idx));
+
+            valdg.push(calls.none);
+            valdg_lang.push(calls.lang);
+            valdg_stack_errs.push(calls.stack_errs);
+            valdg_stack_errs_lang.push(calls.stack_errs_lang);
+        }
+
         let r#else = quote! {else};
         let semi_colon = quote! {;};
 
@@ -506,6 +707,11 @@ pub struct TooLargeErr {
 }
 
 impl TooLargeErr {
+    #[inline]
+    fn code() -> utils::msgs_attribute::Code {
+        utils::msgs_attribute::code("too_large").param("type", quote! {typ})
+    }
+
     pub fn try_build<H: MetasHolder>(
         metas_holder: &H,
         prop_or_idx: &TokenStream2,
@@ -514,7 +720,7 @@ impl TooLargeErr {
         let metas = metas_holder.parse_nvs_from_list_or_empty("too_large_msgs")?;
 
         let (single_msg_err, multi_msgs_err) =
-            utils::msgs_attribute::process(&metas, prop_or_idx, dflt_lang, || -> TokenStream2 {
+            utils::msgs_attribute::process(&metas, prop_or_idx, dflt_lang, Self::code(), || -> TokenStream2 {
                 quote! { format!("number is too large to fit in '{}' type", typ) }
             })?;
 
@@ -537,9 +743,11 @@ impl TooLargeErr {
     }
 
     #[inline]
-    pub fn new_dflt(prop_or_idx: &TokenStream2) -> Self {
+    pub fn new_dflt(prop_or_idx: &TokenStream2, dflt_lang: &str) -> Self {
         let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::construct_dflt(
             prop_or_idx,
+            dflt_lang,
+            Self::code(),
             quote! { format!("number is too large to fit in '{}' type", typ) },
         );
 
@@ -570,6 +778,11 @@ pub struct TooSmallErr {
 }
 
 impl TooSmallErr {
+    #[inline]
+    fn code() -> utils::msgs_attribute::Code {
+        utils::msgs_attribute::code("too_small").param("type", quote! {typ})
+    }
+
     pub fn try_build<H: MetasHolder>(
         metas_holder: &H,
         prop_or_idx: &TokenStream2,
@@ -578,7 +791,7 @@ impl TooSmallErr {
         let metas = metas_holder.parse_nvs_from_list_or_empty("too_small_msgs")?;
 
         let (single_msg_err, multi_msgs_err) =
-            utils::msgs_attribute::process(&metas, prop_or_idx, dflt_lang, || -> TokenStream2 {
+            utils::msgs_attribute::process(&metas, prop_or_idx, dflt_lang, Self::code(), || -> TokenStream2 {
                 quote! { format!("number is too small to fit in '{}' type", typ) }
             })?;
 
@@ -601,9 +814,11 @@ impl TooSmallErr {
     }
 
     #[inline]
-    pub fn new_dflt(prop_or_idx: &TokenStream2) -> Self {
+    pub fn new_dflt(prop_or_idx: &TokenStream2, dflt_lang: &str) -> Self {
         let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::construct_dflt(
             prop_or_idx,
+            dflt_lang,
+            Self::code(),
             quote! { format!("number is too small to fit in '{}' type", typ) },
         );
 
@@ -822,10 +1037,7 @@ where
             &metas_holder,
             &prop_or_idx,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: {}, found: null", exp);
-                quote! {String::from(#msg)}
-            },
+            exp,
         )?;
 
         Ok(Self {
@@ -994,11 +1206,7 @@ where
             &metas_holder,
             &prop_or_idx,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: {}, found: null", exp);
-
-                quote! {String::from(#msg)}
-            },
+            exp,
         )?;
 
         Ok(Self {
@@ -1050,11 +1258,7 @@ where
             &metas_holder,
             &prop_or_idx,
             &dflt_lang,
-            || -> TokenStream2 {
-                let msg = format!("expected: {}, found: null", exp);
-
-                quote! {String::from(#msg)}
-            },
+            exp,
         )?;
 
         Ok(Self {
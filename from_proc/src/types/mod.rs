@@ -9,3 +9,5 @@ pub mod floats;
 pub mod custom;
 
 pub mod vec;
+
+pub mod structure;
@@ -1,18 +1,398 @@
 use proc_macro::TokenStream;
 
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, Expr, Ident, Meta, MetaNameValue};
+
+#[cfg(feature = "regex")]
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
 
 //
 //
 //
 
 use crate::{
-    metas_holder::MetasHolder,
+    metas_holder::{value::FromExpr, MetaNameValues, Metas, MetasHolder},
     utils::{self, Append},
     Tokens,
 };
 
+/// The unit in which the length attributes (`len`, `max_len`
+/// and `min_len`) measure the string, selected with the
+/// `unit` NameValue attribute (`"bytes"` by default).
+#[derive(Clone, Copy, PartialEq)]
+enum LenUnit {
+    Bytes,
+    Chars,
+    Utf16,
+}
+
+impl LenUnit {
+    fn try_build(metas: &Metas) -> Result<Self, TokenStream> {
+        let (unit, refr) = match metas.parse_value_if_found::<String>("unit")? {
+            Some(unit) => unit,
+            None => return Ok(Self::Bytes),
+        };
+
+        match unit.as_str() {
+            "bytes" => Ok(Self::Bytes),
+            "chars" => Ok(Self::Chars),
+            "utf16" => Ok(Self::Utf16),
+            _ => Err(utils::compile_err(
+                &refr.value,
+                "expected one of: \"bytes\", \"chars\", \"utf16\"",
+            )),
+        }
+    }
+
+    fn len(&self) -> TokenStream2 {
+        match self {
+            Self::Bytes => quote! {val.len()},
+            Self::Chars => quote! {val.chars().count()},
+            Self::Utf16 => quote! {val.encode_utf16().count()},
+        }
+    }
+
+    // the value of the 'unit' attribute
+    fn key(&self) -> &'static str {
+        match self {
+            Self::Bytes => "bytes",
+            Self::Chars => "chars",
+            Self::Utf16 => "utf16",
+        }
+    }
+
+    fn name(&self, value: usize) -> &'static str {
+        match (self, value > 1) {
+            (Self::Bytes, true) => "bytes",
+            (Self::Bytes, false) => "byte",
+            (Self::Chars, true) => "characters",
+            (Self::Chars, false) => "character",
+            (Self::Utf16, true) => "UTF-16 code units",
+            (Self::Utf16, false) => "UTF-16 code unit",
+        }
+    }
+}
+
+/// The standard formats supported by the `format` attribute,
+/// each one checked by the function of the same name (prefixed
+/// by `is_`) in `from::formats`.
+#[derive(Clone, Copy)]
+enum Format {
+    Email,
+    Url,
+    Uuid,
+    Hostname,
+    Ipv4,
+    Ipv6,
+    Ip,
+    Date,
+    Time,
+    DateTime,
+}
+
+impl Format {
+    fn try_build(metas: &Metas) -> Result<Self, TokenStream> {
+        let mut format = Option::<Self>::None;
+
+        for meta in metas {
+            let path = match meta {
+                Meta::Path(path) => path,
+                _ => continue,
+            };
+
+            if format.is_some() {
+                return Err(utils::compile_err(path, "only one format can be specified"));
+            };
+
+            let ident = match path.get_ident() {
+                Some(ident) => ident.unraw().to_string(),
+                None => String::new(),
+            };
+
+            format = Some(match ident.as_str() {
+                "email" => Self::Email,
+                "url" => Self::Url,
+                "uuid" => Self::Uuid,
+                "hostname" => Self::Hostname,
+                "ipv4" => Self::Ipv4,
+                "ipv6" => Self::Ipv6,
+                "ip" => Self::Ip,
+                "date" => Self::Date,
+                "time" => Self::Time,
+                "date_time" => Self::DateTime,
+                _ => {
+                    return Err(utils::compile_err(
+                        path,
+                        "expected one of: email, url, uuid, hostname, ipv4, ipv6, ip, date, time, date_time",
+                    ))
+                }
+            });
+        }
+
+        match format {
+            Some(format) => Ok(format),
+            None => Err(utils::compile_err(metas, "the format is required (e.g. 'format(email)')")),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::Url => "url",
+            Self::Uuid => "uuid",
+            Self::Hostname => "hostname",
+            Self::Ipv4 => "ipv4",
+            Self::Ipv6 => "ipv6",
+            Self::Ip => "ip",
+            Self::Date => "date",
+            Self::Time => "time",
+            Self::DateTime => "date_time",
+        }
+    }
+
+    fn checker(&self) -> Ident {
+        format_ident!("is_{}", self.name())
+    }
+
+    fn msg(&self) -> &'static str {
+        match self {
+            Self::Email => "value must be a valid email address",
+            Self::Url => "value must be a valid URL",
+            Self::Uuid => "value must be a valid UUID",
+            Self::Hostname => "value must be a valid hostname",
+            Self::Ipv4 => "value must be a valid IPv4 address",
+            Self::Ipv6 => "value must be a valid IPv6 address",
+            Self::Ip => "value must be a valid IP address",
+            Self::Date => "value must be a valid date (YYYY-MM-DD)",
+            Self::Time => "value must be a valid time (HH:MM:SS)",
+            Self::DateTime => "value must be a valid date-time (RFC 3339)",
+        }
+    }
+}
+
+/// The value, its expression, the messages and the groups check of
+/// a string constraint.
+type StrConstraint = (String, Expr, MetaNameValues, Option<TokenStream2>);
+
+/// Parses a constraint whose value is a string literal, either
+/// as a NameValue attribute (`name = "..."`) or as a list
+/// (`name(value = "...", msgs{...})`), returning the value, its
+/// expression (for spans), the messages and the groups check.
+fn parse_str_constraint<H: MetasHolder>(
+    metas_holder: &H,
+    name: &str,
+) -> Result<Option<StrConstraint>, TokenStream> {
+    if let Some((value, nv)) = metas_holder.parse_value_if_found::<String>(name)? {
+        return Ok(Some((value, nv.value.clone(), MetaNameValues::new(), None)));
+    };
+
+    let metas = match metas_holder.parse_list_if_found(name)? {
+        Some(metas) => metas,
+        None => return Ok(None),
+    };
+
+    let (value, nv) =
+        metas.parse_value_or_err::<String>("value", "'value' NameValue attribute is required")?;
+
+    let expr = nv.value.clone();
+
+    Ok(Some((
+        value,
+        expr,
+        metas.parse_nvs_from_list_or_empty("msgs")?,
+        utils::groups::parse(&metas)?,
+    )))
+}
+
+/// The character classes accepted by the `charset` attribute.
+const CHARSET_CLASSES: [&str; 15] = [
+    "ascii",
+    "ascii_alphabetic",
+    "ascii_alphanumeric",
+    "ascii_digit",
+    "ascii_graphic",
+    "ascii_hexdigit",
+    "ascii_lowercase",
+    "ascii_punctuation",
+    "ascii_uppercase",
+    "alphabetic",
+    "alphanumeric",
+    "lowercase",
+    "numeric",
+    "uppercase",
+    "whitespace",
+];
+
+/// Builds the condition that is true for the allowed characters
+/// (`c` is a `&char`).
+fn charset_cond(metas: &Metas) -> Result<TokenStream2, TokenStream> {
+    let mut conds = Vec::<TokenStream2>::new();
+
+    for meta in metas {
+        let path = match meta {
+            Meta::Path(path) => path,
+            _ => continue,
+        };
+
+        let class = match path.get_ident() {
+            Some(ident) => ident.unraw().to_string(),
+            None => String::new(),
+        };
+
+        if !CHARSET_CLASSES.contains(&class.as_str()) {
+            return Err(utils::compile_err(
+                path,
+                format!("expected one of: {}", CHARSET_CLASSES.join(", ")),
+            ));
+        };
+
+        let method = format_ident!("is_{}", class);
+
+        conds.push(quote! {c.#method()});
+    }
+
+    if let Some((extra, _)) = metas.parse_value_if_found::<String>("extra")? {
+        conds.push(quote! {#extra.contains(*c)});
+    };
+
+    if conds.is_empty() {
+        return Err(utils::compile_err(
+            metas,
+            "at least one character class or 'extra' is required (e.g. 'charset(ascii_alphanumeric, extra = \"-_\")')",
+        ));
+    };
+
+    Ok(quote! {#(#conds)||*})
+}
+
+/// Validates the regular expression and registers the static
+/// that holds it (one per distinct pattern), returning its ident.
+#[cfg(feature = "regex")]
+fn pattern_static(src: &str, value: &Expr) -> Result<Ident, TokenStream> {
+    if let Err(e) = regex::Regex::new(src) {
+        return Err(utils::compile_err(value, e));
+    };
+
+    let mut hasher = DefaultHasher::new();
+    src.hash(&mut hasher);
+
+    let ident = format_ident!("__FROM_PATTERN_{:X}", hasher.finish());
+
+    utils::statics::register(
+        ident.to_string(),
+        quote! {
+            static #ident: ::from::pattern::Pattern = ::from::pattern::Pattern::new(#src);
+        },
+    );
+
+    Ok(ident)
+}
+
+#[cfg(not(feature = "regex"))]
+fn pattern_static(_: &str, value: &Expr) -> Result<Ident, TokenStream> {
+    Err(utils::compile_err(
+        value,
+        "the 'pattern' attribute requires the 'regex' feature of the 'from' crate",
+    ))
+}
+
+/// Returns the sanitizing code of the given meta, or `None` if
+/// it is not a sanitizer.
+fn sanitizer(meta: &Meta) -> Result<Option<TokenStream2>, TokenStream> {
+    let nv = match meta {
+        Meta::Path(path) => {
+            let ident = match path.get_ident() {
+                Some(ident) => ident.unraw(),
+                None => return Ok(None),
+            };
+
+            return Ok(Some(match ident.to_string().as_str() {
+                "trim" => quote! {
+                    val = String::from(val.trim());
+                },
+
+                "trim_start" => quote! {
+                    val = String::from(val.trim_start());
+                },
+
+                "trim_end" => quote! {
+                    val = String::from(val.trim_end());
+                },
+
+                "sanitize_xss" => quote! {
+                    ::from::json::string::sanitize_xss(&mut val);
+                },
+
+                "lowercase" => quote! {
+                    val = val.to_lowercase();
+                },
+
+                "uppercase" => quote! {
+                    val = val.to_uppercase();
+                },
+
+                "collapse_whitespace" => quote! {
+                    ::from::json::string::collapse_whitespace(&mut val);
+                },
+
+                "strip_control_chars" => quote! {
+                    ::from::json::string::strip_control_chars(&mut val);
+                },
+
+                _ => return Ok(None),
+            }));
+        }
+
+        Meta::NameValue(nv) => nv,
+
+        _ => return Ok(None),
+    };
+
+    match nv.path.get_ident() {
+        Some(ident) if ident.unraw().eq("normalize") => {}
+        _ => return Ok(None),
+    };
+
+    let form = String::from_expr(&nv.value)?;
+
+    let normalizer = match form.as_str() {
+        "nfc" => format_ident!("normalize_nfc"),
+        "nfd" => format_ident!("normalize_nfd"),
+        "nfkc" => format_ident!("normalize_nfkc"),
+        "nfkd" => format_ident!("normalize_nfkd"),
+        _ => {
+            return Err(utils::compile_err(
+                &nv.value,
+                "expected one of: \"nfc\", \"nfd\", \"nfkc\" or \"nfkd\"",
+            ))
+        }
+    };
+
+    normalize_available(nv)?;
+
+    Ok(Some(quote! {
+        ::from::json::string::#normalizer(&mut val);
+    }))
+}
+
+#[cfg(feature = "normalize")]
+#[inline]
+fn normalize_available(_: &MetaNameValue) -> Result<(), TokenStream> {
+    Ok(())
+}
+
+#[cfg(not(feature = "normalize"))]
+fn normalize_available(nv: &MetaNameValue) -> Result<(), TokenStream> {
+    Err(utils::compile_err(
+        nv,
+        "the 'normalize' attribute requires the 'normalize' feature of the 'from' crate",
+    ))
+}
+
 pub struct Processing {
     pub mutable: TokenStream2,
     pub sanitizing: TokenStream2,
@@ -41,42 +421,12 @@ impl Processing {
     ) -> Result<Self, TokenStream> {
         let mut sanitizing = TokenStream2::new();
 
-        if metas_holder.contains_ident("trim") {
-            sanitizing.append(quote! {
-                val = String::from(val.trim());
-            });
-        } else {
-            match (
-                metas_holder.contains_ident("trim_start"),
-                metas_holder.contains_ident("trim_end"),
-            ) {
-                (true, true) => {
-                    sanitizing.append(quote! {
-                        val = String::from(val.trim());
-                    });
-                }
-
-                (true, false) => {
-                    sanitizing.append(quote! {
-                        val = String::from(val.trim_start());
-                    });
-                }
-
-                (false, true) => {
-                    sanitizing.append(quote! {
-                        val = String::from(val.trim_end());
-                    });
-                }
-
-                _ => {}
+        // sanitizers are applied in the order they are declared
+        for meta in metas_holder.metas() {
+            if let Some(sanitizer) = sanitizer(meta)? {
+                sanitizing.append(sanitizer);
             };
-        };
-
-        if metas_holder.contains_ident("sanitize_xss") {
-            sanitizing.append(quote! {
-                ::from::json::string::sanitize_xss(&mut val);
-            });
-        };
+        }
 
         //
         //
@@ -95,50 +445,58 @@ impl Processing {
             let (value, _) = metas
                 .parse_value_or_err::<usize>("value", "'value' NameValue attribute is required")?;
 
+            let unit = LenUnit::try_build(&metas)?;
+            let len = unit.len();
+
             let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
 
+            let code = utils::msgs_attribute::code("len")
+                .param("len", value)
+                .placeholder("value", value)
+                .param("actual", &len)
+                .param("unit", unit.key());
+
             let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                 &meta_name_values,
                 &quoted_field_ident,
                 dflt_lang,
+                code,
                 || -> TokenStream2 {
-                    let msg = format!(
-                        "string must be {} {} long",
-                        value,
-                        if value > 1 { "bytes" } else { "byte" },
-                    );
+                    let msg = format!("string must be {} {} long", value, unit.name(value));
 
                     quote! {String::from(#msg)}
                 },
             )?;
 
+            let cond = utils::groups::guard(&metas, quote! {#len != #value})?;
+
             valdg.push(quote! {
-                if val.len() != #value {
+                if #cond {
                     return Err(From::from(#single_msg_err));
                 }
             });
 
             valdg_lang.push(quote! {
-                if val.len() != #value {
+                if #cond {
                     return Err(From::from(#multi_msgs_err));
                 }
             });
 
             valdg_stack_errs.push(quote! {
-                if val.len() != #value {
+                if #cond {
                     errs.push(#single_msg_err);
                 }
             });
 
             valdg_stack_errs_lang.push(quote! {
-                if val.len() != #value {
+                if #cond {
                     errs.push(#multi_msgs_err);
                 }
             });
 
         // if len attribute is missing
         } else {
-            let mut max = Option::<usize>::None;
+            let mut max = Option::<(usize, LenUnit)>::None;
 
             // FIXME: 'metas' span pointing to the 'from' attribute not
             // to the 'max_len' attribute
@@ -148,48 +506,60 @@ impl Processing {
                     "'value' NameValue attribute is required",
                 )?;
 
+                let unit = LenUnit::try_build(&metas)?;
+                let len = unit.len();
+
                 let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
 
+                let code = utils::msgs_attribute::code("max_len")
+                    .param("max", value)
+                    .placeholder("value", value)
+                    .param("actual", &len)
+                    .param("unit", unit.key());
+
                 let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                     &meta_name_values,
                     &quoted_field_ident,
                     dflt_lang,
+                    code,
                     || -> TokenStream2 {
                         let msg = format!(
                             "the string value must be no longer than {} {}",
                             value,
-                            if value > 1 { "bytes" } else { "byte" },
+                            unit.name(value),
                         );
 
                         quote! {String::from(#msg)}
                     },
                 )?;
 
+                let cond = utils::groups::guard(&metas, quote! {#len > #value})?;
+
                 valdg.push(quote! {
-                    if val.len() > #value {
+                    if #cond {
                         return Err(From::from(#single_msg_err));
                     }
                 });
 
                 valdg_lang.push(quote! {
-                    if val.len() > #value {
+                    if #cond {
                         return Err(From::from(#multi_msgs_err));
                     }
                 });
 
                 valdg_stack_errs.push(quote! {
-                    if val.len() > #value {
+                    if #cond {
                         errs.push(#single_msg_err);
                     }
                 });
 
                 valdg_stack_errs_lang.push(quote! {
-                    if val.len() > #value {
+                    if #cond {
                         errs.push(#multi_msgs_err);
                     }
                 });
 
-                max = Some(value);
+                max = Some((value, unit));
             };
 
             // FIXME: 'metas' span pointing to the 'from' attribute not
@@ -200,7 +570,11 @@ impl Processing {
                     "'value' NameValue attribute is required",
                 )?;
 
-                if let Some(max) = max {
+                let unit = LenUnit::try_build(&metas)?;
+                let len = unit.len();
+
+                // lengths measured in different units cannot be compared
+                if let Some((max, _)) = max.filter(|(_, max_unit)| *max_unit == unit) {
                     if value > max {
                         return Err(utils::compile_err(
                             &refr.value,
@@ -218,41 +592,50 @@ impl Processing {
 
                 let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
 
+                let code = utils::msgs_attribute::code("min_len")
+                    .param("min", value)
+                    .placeholder("value", value)
+                    .param("actual", &len)
+                    .param("unit", unit.key());
+
                 let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                     &meta_name_values,
                     &quoted_field_ident,
                     dflt_lang,
+                    code,
                     || -> TokenStream2 {
                         let msg = format!(
                             "the string value must be at least {} {} long",
                             value,
-                            if value > 1 { "bytes" } else { "byte" },
+                            unit.name(value),
                         );
 
                         quote! {String::from(#msg)}
                     },
                 )?;
 
+                let cond = utils::groups::guard(&metas, quote! {#len < #value})?;
+
                 valdg.push(quote! {
-                    if val.len() < #value {
+                    if #cond {
                         return Err(From::from(#single_msg_err));
                     }
                 });
 
                 valdg_lang.push(quote! {
-                    if val.len() < #value {
+                    if #cond {
                         return Err(From::from(#multi_msgs_err));
                     }
                 });
 
                 valdg_stack_errs.push(quote! {
-                    if val.len() < #value {
+                    if #cond {
                         errs.push(#single_msg_err);
                     }
                 });
 
                 valdg_stack_errs_lang.push(quote! {
-                    if val.len() < #value {
+                    if #cond {
                         errs.push(#multi_msgs_err);
                     }
                 });
@@ -267,6 +650,215 @@ impl Processing {
         //
         //
 
+        for (name, dflt_msg) in [
+            ("starts_with", "value must start with: "),
+            ("ends_with", "value must end with: "),
+            ("contains", "value must contain: "),
+            ("not_contains", "value must not contain: "),
+        ] {
+            // FIXME: 'metas' span pointing to the 'from' attribute not
+            // to the predicate attribute
+            let (value, _, meta_name_values, groups) = match parse_str_constraint(metas_holder, name)? {
+                Some(constraint) => constraint,
+                None => continue,
+            };
+
+            let code = utils::msgs_attribute::code(name).param("value", &value);
+
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &meta_name_values,
+                quoted_field_ident,
+                dflt_lang,
+                code,
+                || -> TokenStream2 {
+                    let msg = format!("{}{:?}", dflt_msg, value);
+
+                    quote! {String::from(#msg)}
+                },
+            )?;
+
+            let cond = match name {
+                "starts_with" => quote! {!val.starts_with(#value)},
+                "ends_with" => quote! {!val.ends_with(#value)},
+                "contains" => quote! {!val.contains(#value)},
+                _ => quote! {val.contains(#value)},
+            };
+
+            let cond = utils::groups::and(groups, cond);
+
+            valdg.push(quote! {
+                if #cond {
+                    return Err(From::from(#single_msg_err));
+                }
+            });
+
+            valdg_lang.push(quote! {
+                if #cond {
+                    return Err(From::from(#multi_msgs_err));
+                }
+            });
+
+            valdg_stack_errs.push(quote! {
+                if #cond {
+                    errs.push(#single_msg_err);
+                }
+            });
+
+            valdg_stack_errs_lang.push(quote! {
+                if #cond {
+                    errs.push(#multi_msgs_err);
+                }
+            });
+        }
+
+        // FIXME: 'metas' span pointing to the 'from' attribute not
+        // to the 'charset' attribute
+        if let Some(metas) = metas_holder.parse_list_if_found("charset")? {
+            let allowed = charset_cond(&metas)?;
+
+            let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
+
+            // '__char' is the first character that is not allowed
+            // (the leading underscores keep the compiler quiet when
+            // the messages do not use it)
+            let code = utils::msgs_attribute::code("charset").param("char", quote! {__char});
+
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &meta_name_values,
+                quoted_field_ident,
+                dflt_lang,
+                code,
+                || -> TokenStream2 {
+                    quote! {format!("character {:?} is not allowed", __char)}
+                },
+            )?;
+
+            let find =
+                utils::groups::guard_option(&metas, quote! {val.chars().find(|c| !(#allowed))})?;
+
+            valdg.push(quote! {
+                if let Some(__char) = #find {
+                    return Err(From::from(#single_msg_err));
+                }
+            });
+
+            valdg_lang.push(quote! {
+                if let Some(__char) = #find {
+                    return Err(From::from(#multi_msgs_err));
+                }
+            });
+
+            valdg_stack_errs.push(quote! {
+                if let Some(__char) = #find {
+                    errs.push(#single_msg_err);
+                }
+            });
+
+            valdg_stack_errs_lang.push(quote! {
+                if let Some(__char) = #find {
+                    errs.push(#multi_msgs_err);
+                }
+            });
+        };
+
+        // FIXME: 'metas' span pointing to the 'from' attribute not
+        // to the 'format' attribute
+        if let Some(metas) = metas_holder.parse_list_if_found("format")? {
+            let format = Format::try_build(&metas)?;
+
+            let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
+
+            let code = utils::msgs_attribute::code("format").param("format", format.name());
+
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &meta_name_values,
+                quoted_field_ident,
+                dflt_lang,
+                code,
+                || -> TokenStream2 {
+                    let msg = format.msg();
+
+                    quote! {String::from(#msg)}
+                },
+            )?;
+
+            let checker = format.checker();
+
+            let cond = utils::groups::guard(&metas, quote! {!::from::formats::#checker(&val)})?;
+
+            valdg.push(quote! {
+                if #cond {
+                    return Err(From::from(#single_msg_err));
+                }
+            });
+
+            valdg_lang.push(quote! {
+                if #cond {
+                    return Err(From::from(#multi_msgs_err));
+                }
+            });
+
+            valdg_stack_errs.push(quote! {
+                if #cond {
+                    errs.push(#single_msg_err);
+                }
+            });
+
+            valdg_stack_errs_lang.push(quote! {
+                if #cond {
+                    errs.push(#multi_msgs_err);
+                }
+            });
+        };
+
+        // FIXME: 'metas' span pointing to the 'from' attribute not
+        // to the 'pattern' attribute
+        if let Some((src, value, meta_name_values, groups)) =
+            parse_str_constraint(metas_holder, "pattern")?
+        {
+            let pattern = pattern_static(&src, &value)?;
+
+            let cond = utils::groups::and(groups, quote! {!#pattern.is_match(&val)});
+
+            let code = utils::msgs_attribute::code("pattern").param("pattern", &src);
+
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &meta_name_values,
+                quoted_field_ident,
+                dflt_lang,
+                code,
+                || -> TokenStream2 {
+                    let msg = format!("value must match the pattern: {}", src);
+
+                    quote! {String::from(#msg)}
+                },
+            )?;
+
+            valdg.push(quote! {
+                if #cond {
+                    return Err(From::from(#single_msg_err));
+                }
+            });
+
+            valdg_lang.push(quote! {
+                if #cond {
+                    return Err(From::from(#multi_msgs_err));
+                }
+            });
+
+            valdg_stack_errs.push(quote! {
+                if #cond {
+                    errs.push(#single_msg_err);
+                }
+            });
+
+            valdg_stack_errs_lang.push(quote! {
+                if #cond {
+                    errs.push(#multi_msgs_err);
+                }
+            });
+        };
+
         // FIXME: 'metas' span pointing to the 'from' attribute not
         // to the 'r#enum' attribute
         if let Some(metas) = metas_holder.parse_list_if_found("enum")? {
@@ -277,10 +869,15 @@ impl Processing {
 
             let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
 
+            let code = utils::msgs_attribute::code("enum")
+                .list_param("values", &values)
+                .param("actual", quote! {val.as_str()});
+
             let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                 &meta_name_values,
                 &quoted_field_ident,
                 dflt_lang,
+                code,
                 || -> TokenStream2 {
                     let msg = format!("value must be one of: {:?}", values,);
 
@@ -289,56 +886,111 @@ impl Processing {
             )?;
 
             let values = utils::vec_into_token_stream2_array(values);
+            let cond = utils::groups::guard(&metas, quote! {::from::utils::array_not_contains(#values, &val)})?;
+
             valdg.push(quote! {
-                if ::from::utils::array_not_contains(#values, &val) {
+                if #cond {
                     return Err(From::from(#single_msg_err));
                 }
             });
 
             valdg_lang.push(quote! {
-                if ::from::utils::array_not_contains(#values, &val) {
+                if #cond {
                     return Err(From::from(#multi_msgs_err));
                 }
             });
 
             valdg_stack_errs.push(quote! {
-                if ::from::utils::array_not_contains(#values, &val) {
+                if #cond {
                     errs.push(#single_msg_err);
                 }
             });
 
             valdg_stack_errs_lang.push(quote! {
-                if ::from::utils::array_not_contains(#values, &val) {
+                if #cond {
                     errs.push(#multi_msgs_err);
                 }
             });
         };
 
-        if let Some(paths) = metas_holder.parse_paths_from_list_if_found("validators")? {
-            for path in paths {
-                valdg.push(quote! {
-                    if let Err(e) = <#path as ::from::Validator<String>>::none(&val, &path) {
-                        return Err(From::from(e));
-                    }
-                });
-                valdg_lang.push(quote! {
-                    if let Err(e) = <#path as ::from::Validator<String>>::lang(&val, &path, lang) {
-                        return Err(From::from(e));
-                    }
-                });
-                valdg_stack_errs.push(quote! {
-                    if let Err(mut errs2) = <#path as ::from::Validator<String>>::stack_errs(&val, &path) {
-                        errs.append(&mut errs2);
-                    }
-                });
-                valdg_stack_errs_lang.push(quote! {
-                    if let Err(mut errs2) = <#path as ::from::Validator<String>>::stack_errs_lang(&val, &path, lang) {
-                        errs.append(&mut errs2);
-                    }
-                });
-            }
+        if let Some(metas) = metas_holder.parse_list_if_found("not_enum")? {
+            let (values, _) = metas.parse_value_or_err::<Vec<String>>(
+                "values",
+                "'values' NameValue attribute is required",
+            )?;
+
+            let case_insensitive = metas.contains_ident("case_insensitive");
+
+            let values: Vec<String> = if case_insensitive {
+                values.iter().map(|v| v.to_lowercase()).collect()
+            } else {
+                values
+            };
+
+            let cmp_val = if case_insensitive {
+                quote! {val.to_lowercase().as_str()}
+            } else {
+                quote! {&val}
+            };
+
+            let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
+
+            let code = utils::msgs_attribute::code("not_enum")
+                .list_param("values", &values)
+                .param("actual", quote! {val.as_str()});
+
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &meta_name_values,
+                quoted_field_ident,
+                dflt_lang,
+                code,
+                || -> TokenStream2 {
+                    quote! {format!("value {:?} is not allowed", val)}
+                },
+            )?;
+
+            let values = utils::vec_into_token_stream2_array(values);
+            let cond = utils::groups::guard(&metas, quote! {::from::utils::array_contains(#values, #cmp_val)})?;
+
+            valdg.push(quote! {
+                if #cond {
+                    return Err(From::from(#single_msg_err));
+                }
+            });
+
+            valdg_lang.push(quote! {
+                if #cond {
+                    return Err(From::from(#multi_msgs_err));
+                }
+            });
+
+            valdg_stack_errs.push(quote! {
+                if #cond {
+                    errs.push(#single_msg_err);
+                }
+            });
+
+            valdg_stack_errs_lang.push(quote! {
+                if #cond {
+                    errs.push(#multi_msgs_err);
+                }
+            });
         };
 
+        for validator in utils::validators::parse(metas_holder)? {
+            let calls = validator.calls(
+                &quote! {String},
+                &quote! {&val},
+                &quote! {&path},
+                Some(quoted_field_ident),
+            );
+
+            valdg.push(calls.none);
+            valdg_lang.push(calls.lang);
+            valdg_stack_errs.push(calls.stack_errs);
+            valdg_stack_errs_lang.push(calls.stack_errs_lang);
+        }
+
         let r#else = quote! {else};
         let semi_colon = quote! {;};
 
@@ -502,9 +1154,7 @@ impl JsonSegVars {
             &metas_holder,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                quote! {String::from("expected: string, found: null")}
-            },
+            "string",
         )?;
 
         Ok(Self {
@@ -654,9 +1304,7 @@ impl JsonSegVars {
             &metas_holder,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                quote! {String::from("expected: string, found: null")}
-            },
+            "string",
         )?;
 
         Ok(Self {
@@ -701,9 +1349,7 @@ impl JsonSegVars {
             &metas_holder,
             &quoted_field_ident,
             &dflt_lang,
-            || -> TokenStream2 {
-                quote! {String::from("expected: string, found: null")}
-            },
+            "string",
         )?;
 
         Ok(Self {
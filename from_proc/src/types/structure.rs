@@ -0,0 +1,250 @@
+use proc_macro::TokenStream;
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{ext::IdentExt, Attribute, Ident, MetaNameValue};
+
+use crate::{
+    custom_types::{find_field, FieldIdent},
+    kind::{Kind, Type},
+    metas_holder::{MetaNameValues, MetasHolder},
+    utils::{self, validators::Validator},
+    Tokens,
+};
+
+// struct-level validation, runs after all the fields are assembled
+#[derive(Clone)]
+pub struct Processing {
+    valdg: Tokens,
+    valdg_lang: Tokens,
+    valdg_stack_errs: Tokens,
+    valdg_stack_errs_lang: Tokens,
+    validators: Vec<Validator>,
+    pub always: bool, // run even if field-level errors exist (stack errs only)
+}
+
+impl Processing {
+    pub fn try_build<H>(metas_holder: &H) -> Result<Self, TokenStream>
+    where
+        H: MetasHolder,
+    {
+        Ok(Self {
+            valdg: Tokens::new(),
+            valdg_lang: Tokens::new(),
+            valdg_stack_errs: Tokens::new(),
+            valdg_stack_errs_lang: Tokens::new(),
+            validators: utils::validators::parse(metas_holder)?,
+            always: metas_holder.contains_ident("always_validate"),
+        })
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.valdg.len() == 0 && self.validators.is_empty()
+    }
+
+    pub fn add_field_comparisons(
+        &mut self,
+        attrs: &Vec<Attribute>,
+        field_ident: &FieldIdent,
+        kind: &Kind,
+        fields: &[(FieldIdent, Kind)],
+        dflt_lang: &str,
+    ) -> Result<(), TokenStream> {
+        for cmp in [Cmp::Eq, Cmp::Gt, Cmp::Lt] {
+            let (other, nv, meta_name_values, groups) =
+                match attrs.parse_value_if_found::<String>(cmp.name())? {
+                    Some((other, nv)) => (other, nv.clone(), MetaNameValues::new(), None),
+
+                    None => match attrs.parse_list_if_found(cmp.name())? {
+                        Some(metas) => {
+                            let (other, nv) = metas.parse_value_or_err::<String>(
+                                "field",
+                                "'field' NameValue attribute is required",
+                            )?;
+
+                            (
+                                other,
+                                nv.clone(),
+                                metas.parse_nvs_from_list_or_empty("msgs")?,
+                                utils::groups::parse(&metas)?,
+                            )
+                        }
+
+                        None => continue,
+                    },
+                };
+
+            let (other_ident, other_kind) =
+                check_comparable(&cmp, &other, &nv, &field_ident.ident, kind, fields)?;
+
+            let code = utils::msgs_attribute::code(cmp.name()).param("other", &other);
+
+            let (single, multi) = utils::msgs_attribute::process(
+                &meta_name_values,
+                &field_ident.quoted,
+                dflt_lang,
+                code,
+                || {
+                    let msg = cmp.dflt_msg(&other);
+                    quote! {String::from(#msg)}
+                },
+            )?;
+
+            let this = &field_ident.ident;
+
+            let cond = match cmp {
+                Cmp::Eq => quote! {__this != __other},
+                Cmp::Gt => quote! {!(__this > __other)},
+                Cmp::Lt => quote! {!(__this < __other)},
+            };
+
+            let cond = utils::groups::and(groups, cond);
+
+            let compare = |then: TokenStream2| -> TokenStream2 {
+                if !kind.option && !kind.null && !other_kind.option && !other_kind.null {
+                    return quote! {
+                        {
+                            let (__this, __other) = (&val.#this, &val.#other_ident);
+                            if #cond {
+                                #then
+                            };
+                        }
+                    };
+                };
+
+                let this_pat = kind.some_pattern(quote! {__this});
+                let other_pat = other_kind.some_pattern(quote! {__other});
+
+                // only compared when both values are present
+                quote! {
+                    if let (#this_pat, #other_pat) = (&val.#this, &val.#other_ident) {
+                        if #cond {
+                            #then
+                        };
+                    }
+                }
+            };
+
+            self.valdg
+                .push(compare(quote! {return Err(From::from(#single));}));
+            self.valdg_lang
+                .push(compare(quote! {return Err(From::from(#multi));}));
+            self.valdg_stack_errs
+                .push(compare(quote! {errs.push(#single);}));
+            self.valdg_stack_errs_lang
+                .push(compare(quote! {errs.push(#multi);}));
+        }
+
+        Ok(())
+    }
+
+    pub fn into_parts(self) -> (TokenStream2, TokenStream2, TokenStream2, TokenStream2) {
+        let Self {
+            mut valdg,
+            mut valdg_lang,
+            mut valdg_stack_errs,
+            mut valdg_stack_errs_lang,
+            validators,
+            ..
+        } = self;
+
+        // unlike field-level validators, every struct-level rule is
+        // independent, so all of them run and their errors are merged
+        for validator in validators {
+            let calls = validator.calls(&quote! {Self}, &quote! {&val}, &quote! {path}, None);
+
+            valdg.push(calls.none);
+            valdg_lang.push(calls.lang);
+            valdg_stack_errs.push(calls.stack_errs);
+            valdg_stack_errs_lang.push(calls.stack_errs_lang);
+        }
+
+        let semi_colon = quote! {;};
+
+        (
+            valdg.join_with_suffix(&semi_colon, &semi_colon),
+            valdg_lang.join_with_suffix(&semi_colon, &semi_colon),
+            valdg_stack_errs.join_with_suffix(&semi_colon, &semi_colon),
+            valdg_stack_errs_lang.join_with_suffix(&semi_colon, &semi_colon),
+        )
+    }
+}
+
+enum Cmp {
+    Eq,
+    Gt,
+    Lt,
+}
+
+impl Cmp {
+    #[inline]
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Eq => "eq_field",
+            Self::Gt => "gt_field",
+            Self::Lt => "lt_field",
+        }
+    }
+
+    #[inline]
+    fn dflt_msg(&self, other: &str) -> String {
+        match self {
+            Self::Eq => format!("must be equal to '{}'", other),
+            Self::Gt => format!("must be greater than '{}'", other),
+            Self::Lt => format!("must be less than '{}'", other),
+        }
+    }
+}
+
+fn check_comparable<'a>(
+    cmp: &Cmp,
+    other: &str,
+    nv: &MetaNameValue,
+    this: &Ident,
+    kind: &Kind,
+    fields: &'a [(FieldIdent, Kind)],
+) -> Result<(&'a Ident, &'a Kind), TokenStream> {
+    if this.unraw().eq(other) {
+        return Err(utils::compile_err(
+            nv,
+            "a field can not be compared with itself",
+        ));
+    };
+
+    let (other, other_kind) = find_field(other, nv, fields)?;
+
+    if !same_type(&kind.ty, &other_kind.ty) {
+        return Err(utils::compile_err(
+            nv,
+            format!(
+                "'{}' requires both fields to be of the same type (ignoring 'Option', 'Null' and 'OptionNull')",
+                cmp.name()
+            ),
+        ));
+    };
+
+    if let Cmp::Gt | Cmp::Lt = cmp {
+        if let Type::Bool | Type::Vec(..) | Type::Custom(_) = kind.ty {
+            return Err(utils::compile_err(
+                nv,
+                format!(
+                    "'{}' is only supported for numbers and 'String'",
+                    cmp.name()
+                ),
+            ));
+        };
+    };
+
+    Ok((&other.ident, other_kind))
+}
+
+fn same_type(a: &Type, b: &Type) -> bool {
+    match (a, b) {
+        (Type::Vec(a, _), Type::Vec(b, _)) | (Type::Custom(a), Type::Custom(b)) => {
+            a.to_string() == b.to_string()
+        }
+
+        _ => std::mem::discriminant(a) == std::mem::discriminant(b),
+    }
+}
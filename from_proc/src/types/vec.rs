@@ -2,11 +2,16 @@ use proc_macro::TokenStream;
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::Ident;
+use syn::{ext::IdentExt, Expr, ExprArray, Ident, Meta};
 
-use crate::{metas_holder::MetasHolder, utils, Tokens};
+use crate::{
+    metas_holder::{MetaNameValues, MetasHolder},
+    utils::{self, Append},
+    Tokens,
+};
 
 pub struct Processing {
+    pub sanitizing: TokenStream2,
     pub valdg: TokenStream2,                 // instant return - dflt message
     pub valdg_lang: TokenStream2,            // instant return - match lang
     pub valdg_stack_errs: TokenStream2,      // stack errs - dflt message
@@ -17,6 +22,7 @@ impl Processing {
     #[inline]
     pub fn new() -> Self {
         Self {
+            sanitizing: TokenStream2::new(),
             valdg: TokenStream2::new(),
             valdg_lang: TokenStream2::new(),
             valdg_stack_errs: TokenStream2::new(),
@@ -31,6 +37,36 @@ impl Processing {
         quoted_field_ident: &TokenStream2,
         dflt_lang: &str,
     ) -> Result<Self, TokenStream> {
+        let mut sanitizing = TokenStream2::new();
+
+        // transforms are applied in the order they are declared
+        for meta in metas_holder.metas() {
+            let ident = match meta {
+                Meta::Path(path) => match path.get_ident() {
+                    Some(ident) => ident.unraw(),
+                    None => continue,
+                },
+
+                _ => continue,
+            };
+
+            match ident.to_string().as_str() {
+                "dedup" => sanitizing.append(quote! {
+                    ::from::utils::dedup(&mut #field_var_name);
+                }),
+
+                "sort" => sanitizing.append(quote! {
+                    #field_var_name.sort_by(|a, b| a.partial_cmp(b).unwrap_or(::std::cmp::Ordering::Equal));
+                }),
+
+                "sort_desc" => sanitizing.append(quote! {
+                    #field_var_name.sort_by(|a, b| b.partial_cmp(a).unwrap_or(::std::cmp::Ordering::Equal));
+                }),
+
+                _ => {}
+            };
+        }
+
         let mut valdg = Tokens::new();
         let mut valdg_lang = Tokens::new();
         let mut valdg_stack_errs = Tokens::new();
@@ -44,10 +80,16 @@ impl Processing {
 
             let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
 
+            let code = utils::msgs_attribute::code("len")
+                .param("len", value)
+                .placeholder("value", value)
+                .param("actual", quote! {#field_var_name.len()});
+
             let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                 &meta_name_values,
                 &quoted_field_ident,
                 dflt_lang,
+                code,
                 || -> TokenStream2 {
                     let msg = format!(
                         "array must contains {} {}",
@@ -59,26 +101,28 @@ impl Processing {
                 },
             )?;
 
+            let cond = utils::groups::guard(&metas, quote! {#field_var_name.len() != #value})?;
+
             valdg.push(quote! {
-                if #field_var_name.len() != #value {
+                if #cond {
                     return Err(From::from(#single_msg_err));
                 }
             });
 
             valdg_lang.push(quote! {
-                if #field_var_name.len() != #value {
+                if #cond {
                     return Err(From::from(#multi_msgs_err));
                 }
             });
 
             valdg_stack_errs.push(quote! {
-                if #field_var_name.len() != #value {
+                if #cond {
                     errs.push(#single_msg_err);
                 }
             });
 
             valdg_stack_errs_lang.push(quote! {
-                if #field_var_name.len() != #value {
+                if #cond {
                     errs.push(#multi_msgs_err);
                 }
             });
@@ -97,10 +141,16 @@ impl Processing {
 
                 let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
 
+                let code = utils::msgs_attribute::code("max_len")
+                    .param("max", value)
+                    .placeholder("value", value)
+                    .param("actual", quote! {#field_var_name.len()});
+
                 let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                     &meta_name_values,
                     &quoted_field_ident,
                     dflt_lang,
+                    code,
                     || -> TokenStream2 {
                         let msg = format!(
                             "array must not contains more than {} {}",
@@ -112,26 +162,28 @@ impl Processing {
                     },
                 )?;
 
+                let cond = utils::groups::guard(&metas, quote! {#field_var_name.len() > #value})?;
+
                 valdg.push(quote! {
-                    if #field_var_name.len() > #value {
+                    if #cond {
                         return Err(From::from(#single_msg_err));
                     }
                 });
 
                 valdg_lang.push(quote! {
-                    if #field_var_name.len() > #value {
+                    if #cond {
                         return Err(From::from(#multi_msgs_err));
                     }
                 });
 
                 valdg_stack_errs.push(quote! {
-                    if #field_var_name.len() > #value {
+                    if #cond {
                         errs.push(#single_msg_err);
                     }
                 });
 
                 valdg_stack_errs_lang.push(quote! {
-                    if #field_var_name.len() > #value {
+                    if #cond {
                         errs.push(#multi_msgs_err);
                     }
                 });
@@ -165,10 +217,16 @@ impl Processing {
 
                 let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
 
+                let code = utils::msgs_attribute::code("min_len")
+                    .param("min", value)
+                    .placeholder("value", value)
+                    .param("actual", quote! {#field_var_name.len()});
+
                 let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
                     &meta_name_values,
                     &quoted_field_ident,
                     dflt_lang,
+                    code,
                     || -> TokenStream2 {
                         let msg = format!(
                             "array must not contains less than {} {}",
@@ -180,61 +238,177 @@ impl Processing {
                     },
                 )?;
 
+                let cond = utils::groups::guard(&metas, quote! {#field_var_name.len() < #value})?;
+
                 valdg.push(quote! {
-                    if #field_var_name.len() < #value {
+                    if #cond {
                         return Err(From::from(#single_msg_err));
                     }
                 });
 
                 valdg_lang.push(quote! {
-                    if #field_var_name.len() < #value {
+                    if #cond {
                         return Err(From::from(#multi_msgs_err));
                     }
                 });
 
                 valdg_stack_errs.push(quote! {
-                    if #field_var_name.len() < #value {
+                    if #cond {
                         errs.push(#single_msg_err);
                     }
                 });
 
                 valdg_stack_errs_lang.push(quote! {
-                    if #field_var_name.len() < #value {
+                    if #cond {
                         errs.push(#multi_msgs_err);
                     }
                 });
             };
         };
 
-        if let Some(paths) = metas_holder.parse_paths_from_list_if_found("validators")? {
-            for path in paths {
-                valdg.push(quote! {
-                    if let Err(e) = <#path as ::from::Validator<#ty>>::none(&#field_var_name, &path) {
-                        return Err(From::from(e));
-                    }
-                });
-                valdg_lang.push(quote! {
-                    if let Err(e) = <#path as ::from::Validator<#ty>>::lang(&#field_var_name, &path, lang) {
-                        return Err(From::from(e));
-                    }
-                });
-                valdg_stack_errs.push(quote! {
-                    if let Err(mut errs2) = <#path as ::from::Validator<#ty>>::stack_errs(&#field_var_name, &path) {
-                        errs.append(&mut errs2);
-                    }
-                });
-                valdg_stack_errs_lang.push(quote! {
-                    if let Err(mut errs2) = <#path as ::from::Validator<#ty>>::stack_errs_lang(&#field_var_name, &path, lang) {
-                        errs.append(&mut errs2);
-                    }
-                });
-            }
+        if let Some(metas) = metas_holder.parse_list_if_found("contains")? {
+            let (values, _) = metas.parse_value_or_err::<ExprArray>(
+                "values",
+                "'values' NameValue attribute is required",
+            )?;
+
+            let meta_name_values = metas.parse_nvs_from_list_or_empty("msgs")?;
+
+            let mut code = utils::msgs_attribute::code("contains");
+
+            // only literals are known to be convertible into a 'Param'
+            if values.elems.iter().all(|elem| matches!(elem, Expr::Lit(_))) {
+                code = code.param("missing", quote! {*__missing});
+            };
+
+            // '__missing' is prefixed with an underscore since
+            // the custom messages do not use it
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &meta_name_values,
+                quoted_field_ident,
+                dflt_lang,
+                code,
+                || -> TokenStream2 {
+                    quote! {format!("array must contain: {:?}", __missing)}
+                },
+            )?;
+
+            let missing = utils::groups::guard_option(
+                &metas,
+                quote! {::from::utils::first_missing(&#values, &#field_var_name)},
+            )?;
+
+            valdg.push(quote! {
+                if let Some(__missing) = #missing {
+                    return Err(From::from(#single_msg_err));
+                }
+            });
+
+            valdg_lang.push(quote! {
+                if let Some(__missing) = #missing {
+                    return Err(From::from(#multi_msgs_err));
+                }
+            });
+
+            valdg_stack_errs.push(quote! {
+                if let Some(__missing) = #missing {
+                    errs.push(#single_msg_err);
+                }
+            });
+
+            valdg_stack_errs_lang.push(quote! {
+                if let Some(__missing) = #missing {
+                    errs.push(#multi_msgs_err);
+                }
+            });
         };
 
+        for (name, order, cmp) in [
+            ("sorted", "ascending", quote! {w[0] > w[1]}),
+            ("sorted_desc", "descending", quote! {w[0] < w[1]}),
+        ] {
+            // either a path ('sorted') or a list ('sorted(msgs{...})')
+            let (meta_name_values, groups) = if metas_holder.contains_ident(name) {
+                (MetaNameValues::new(), None)
+            } else {
+                match metas_holder.parse_list_if_found(name)? {
+                    Some(metas) => (
+                        metas.parse_nvs_from_list_or_empty("msgs")?,
+                        utils::groups::parse(&metas)?,
+                    ),
+                    None => continue,
+                }
+            };
+
+            // '__idx' is prefixed with an underscore since the
+            // custom messages do not use it
+            let code = utils::msgs_attribute::code(name).param("index", quote! {__idx + 1});
+
+            let (single_msg_err, multi_msgs_err) = utils::msgs_attribute::process(
+                &meta_name_values,
+                quoted_field_ident,
+                dflt_lang,
+                code,
+                || -> TokenStream2 {
+                    let msg = format!(
+                        "array must be sorted in {} order (element at index {{}} is out of order)",
+                        order
+                    );
+
+                    quote! {format!(#msg, __idx + 1)}
+                },
+            )?;
+
+            let position = utils::groups::and_option(
+                groups,
+                quote! {#field_var_name.windows(2).position(|w| #cmp)},
+            );
+
+            valdg.push(quote! {
+                if let Some(__idx) = #position {
+                    return Err(From::from(#single_msg_err));
+                }
+            });
+
+            valdg_lang.push(quote! {
+                if let Some(__idx) = #position {
+                    return Err(From::from(#multi_msgs_err));
+                }
+            });
+
+            valdg_stack_errs.push(quote! {
+                if let Some(__idx) = #position {
+                    errs.push(#single_msg_err);
+                }
+            });
+
+            valdg_stack_errs_lang.push(quote! {
+                if let Some(__idx) = #position {
+                    errs.push(#multi_msgs_err);
+                }
+            });
+        }
+
+        for validator in utils::validators::parse(metas_holder)? {
+            let calls = validator.calls(
+                ty,
+                &quote! {&#field_var_name},
+                &quote! {&path},
+                Some(quoted_field_ident),
+            );
+
+            valdg.push(calls.none);
+            valdg_lang.push(calls.lang);
+            valdg_stack_errs.push(calls.stack_errs);
+            valdg_stack_errs_lang.push(calls.stack_errs_lang);
+        }
+
         let r#else = quote! {else};
         let semi_colon = quote! {;};
 
         Ok(Self {
+            sanitizing,
+
             valdg: valdg.join_with_suffix(&r#else, &semi_colon),
 
             valdg_lang: valdg_lang.join_with_suffix(&r#else, &semi_colon),
@@ -0,0 +1,67 @@
+use proc_macro::TokenStream;
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::ext::IdentExt;
+
+use crate::metas_holder::MetasHolder;
+
+// 'groups(create, update)' sub-attribute, returns the runtime check of the
+// listed groups
+pub fn parse<H: MetasHolder>(metas_holder: &H) -> Result<Option<TokenStream2>, TokenStream> {
+    let paths = match metas_holder.parse_paths_from_list_if_found("groups")? {
+        Some(paths) => paths,
+        None => return Ok(None),
+    };
+
+    let mut groups = Vec::with_capacity(paths.len());
+
+    for path in &paths {
+        match path.get_ident() {
+            Some(ident) => groups.push(ident.unraw().to_string()),
+            None => return Err(super::compile_err(path, "expected: group name")),
+        };
+    }
+
+    if groups.is_empty() {
+        return Err(super::compile_err(
+            &paths,
+            "at least one group is required",
+        ));
+    };
+
+    Ok(Some(quote! {::from::groups::is_active(&[#(#groups),*])}))
+}
+
+// the condition of a constraint, checked only if one of its groups is active
+#[inline]
+pub fn guard<H: MetasHolder>(metas_holder: &H, cond: TokenStream2) -> Result<TokenStream2, TokenStream> {
+    Ok(and(parse(metas_holder)?, cond))
+}
+
+// combines an already parsed groups check with the condition
+#[inline]
+pub fn and(active: Option<TokenStream2>, cond: TokenStream2) -> TokenStream2 {
+    match active {
+        Some(active) => quote! {#active && (#cond)},
+        None => cond,
+    }
+}
+
+// same as 'guard' but for 'if let Some(..) = #expr' constraints
+#[inline]
+pub fn guard_option<H: MetasHolder>(
+    metas_holder: &H,
+    expr: TokenStream2,
+) -> Result<TokenStream2, TokenStream> {
+    Ok(and_option(parse(metas_holder)?, expr))
+}
+
+// same as 'and' but for 'if let Some(..) = #expr' constraints
+#[inline]
+pub fn and_option(active: Option<TokenStream2>, expr: TokenStream2) -> TokenStream2 {
+    match active {
+        Some(active) => quote! {if #active {#expr} else {None}},
+        None => expr,
+    }
+}
@@ -0,0 +1,327 @@
+use std::{cell::RefCell, collections::HashSet, fs, path::PathBuf};
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+// the codes of the built-in constraints ('ErrKind' of 'from')
+const CODES: &[&str] = &[
+    "required",
+    "required_if",
+    "required_unless",
+    "exactly_one_of",
+    "at_most_one_of",
+    "not_null",
+    "type_mismatch",
+    "too_large",
+    "too_small",
+    "max",
+    "min",
+    "range",
+    "multiple_of",
+    "enum",
+    "not_enum",
+    "infinite",
+    "max_fracs",
+    "len",
+    "max_len",
+    "min_len",
+    "starts_with",
+    "ends_with",
+    "contains",
+    "not_contains",
+    "charset",
+    "format",
+    "pattern",
+    "sorted",
+    "sorted_desc",
+    "must_be",
+    "eq_field",
+    "gt_field",
+    "lt_field",
+];
+
+// a '<lang>.json' file of the locales directory
+struct Locale {
+    lang: String,
+    file: String,
+    msgs: Vec<(String, String)>,
+}
+
+#[derive(Default)]
+struct State {
+    locales: Vec<Locale>,
+    struct_name: String,
+
+    // the '<struct>.<field>.<code>' keys of the constraints of the
+    // struct being expanded
+    used: HashSet<String>,
+}
+
+// the locales ('locales = "locales/"') of the struct being expanded
+thread_local! {
+    static STATE: RefCell<State> = RefCell::new(State::default());
+}
+
+/// Loads the `*.json` files of the directory (relative to
+/// `CARGO_MANIFEST_DIR`), each one is a flat object of the messages
+/// of its language (the name of the file) keyed by the code of the
+/// constraint (e.g. `"max_len"`) or by `<struct>.<field>.<code>`
+/// (e.g. `"User.name.max_len"`). The files are embedded (in statics)
+/// so changing them triggers a rebuild. A proc macro can not track a
+/// directory on stable Rust, so a new file is only picked up once the
+/// crate is rebuilt: the crate tracks the directory in its build script
+/// (`cargo:rerun-if-changed=<dir>`).
+pub fn load(dir: Option<&LitStr>) -> Result<(), TokenStream> {
+    let mut locales = Vec::new();
+
+    if let Some(dir) = dir {
+        let root = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+        let path = PathBuf::from(root).join(dir.value());
+
+        let entries = fs::read_dir(&path).map_err(|err| {
+            super::compile_err(dir, format!("can not read '{}': {}", path.display(), err))
+        })?;
+
+        let mut files = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|file| file.extension().map_or(false, |ext| ext == "json"))
+            .collect::<Vec<_>>();
+
+        files.sort();
+
+        for file in files {
+            let lang = file
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+
+            let display = file.display().to_string();
+
+            let src = fs::read_to_string(&file)
+                .map_err(|err| super::compile_err(dir, format!("can not read '{}': {}", display, err)))?;
+
+            let msgs = parse(&src)
+                .map_err(|err| super::compile_err(dir, format!("{}: {}", display, err)))?;
+
+            for (key, _) in &msgs {
+                let code = key.rsplit('.').next().unwrap_or_default();
+
+                if !CODES.contains(&code) || !matches!(key.matches('.').count(), 0 | 2) {
+                    return Err(super::compile_err(
+                        dir,
+                        format!(
+                            "{}: unknown key '{}', expected: '<code>' or '<struct>.<field>.<code>' (e.g. 'max_len' or 'User.name.max_len')",
+                            display, key
+                        ),
+                    ));
+                };
+            }
+
+            locales.push(Locale {
+                lang,
+                file: display,
+                msgs,
+            });
+        }
+    };
+
+    for locale in &locales {
+        let file = &locale.file;
+
+        super::statics::register(
+            format!("locale {}", file),
+            quote! {
+                const _: &[u8] = include_bytes!(#file);
+            },
+        );
+    }
+
+    STATE.with(|state| {
+        *state.borrow_mut() = State {
+            locales,
+            ..State::default()
+        }
+    });
+
+    Ok(())
+}
+
+#[inline]
+pub fn set_struct_name(name: String) {
+    STATE.with(|state| state.borrow_mut().struct_name = name);
+}
+
+/// Returns the `(lang, file, template)` of the message of the
+/// constraint per language, the specific message of the field takes
+/// precedence over the one of the code.
+pub fn templates(field: Option<&str>, code: &str) -> Vec<(String, String, String)> {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+
+        let key = field.map(|field| format!("{}.{}.{}", state.struct_name, field, code));
+
+        if let Some(key) = &key {
+            state.used.insert(key.clone());
+        };
+
+        let mut templates = Vec::new();
+
+        for locale in &state.locales {
+            let find = |key: &str| locale.msgs.iter().find(|(k, _)| k == key);
+
+            let msg = key.as_deref().and_then(find).or_else(|| find(code));
+
+            if let Some((_, template)) = msg {
+                templates.push((locale.lang.clone(), locale.file.clone(), template.clone()));
+            };
+        }
+
+        templates
+    })
+}
+
+/// Checks that the keys of the struct being expanded match its
+/// constraints.
+pub fn check(span: &syn::Ident) -> Result<(), TokenStream> {
+    STATE.with(|state| {
+        let state = state.borrow();
+        let prefix = format!("{}.", state.struct_name);
+
+        for locale in &state.locales {
+            for (key, _) in &locale.msgs {
+                if key.starts_with(&prefix) && !state.used.contains(key) {
+                    return Err(super::compile_err(
+                        span,
+                        format!(
+                            "{}: the key '{}' does not match any constraint of '{}'",
+                            locale.file, key, state.struct_name
+                        ),
+                    ));
+                };
+            }
+        }
+
+        Ok(())
+    })
+}
+
+// a flat JSON object of strings
+fn parse(src: &str) -> Result<Vec<(String, String)>, String> {
+    let mut chars = src.chars().peekable();
+    let mut msgs = Vec::<(String, String)>::new();
+
+    let skip_ws = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+        while chars.peek().map_or(false, |c| c.is_whitespace()) {
+            chars.next();
+        }
+    };
+
+    let expect = |chars: &mut std::iter::Peekable<std::str::Chars>, exp: char| {
+        skip_ws(chars);
+
+        match chars.next() {
+            Some(c) if c == exp => Ok(()),
+            Some(c) => Err(format!("expected: '{}', found: '{}'", exp, c)),
+            None => Err(format!("expected: '{}', found: end of file", exp)),
+        }
+    };
+
+    expect(&mut chars, '{')?;
+    skip_ws(&mut chars);
+
+    if chars.peek() == Some(&'}') {
+        chars.next();
+    } else {
+        loop {
+            expect(&mut chars, '"')?;
+            let key = parse_str(&mut chars)?;
+
+            expect(&mut chars, ':')?;
+            expect(&mut chars, '"')?;
+            let msg = parse_str(&mut chars)?;
+
+            if msgs.iter().any(|(k, _)| *k == key) {
+                return Err(format!("duplicated key '{}'", key));
+            };
+
+            msgs.push((key, msg));
+
+            skip_ws(&mut chars);
+
+            match chars.next() {
+                Some(',') => continue,
+                Some('}') => break,
+                Some(c) => return Err(format!("expected: ',' or '}}', found: '{}'", c)),
+                None => return Err(String::from("expected: ',' or '}', found: end of file")),
+            };
+        }
+    };
+
+    skip_ws(&mut chars);
+
+    match chars.next() {
+        Some(c) => Err(format!("unexpected '{}' after the object", c)),
+        None => Ok(msgs),
+    }
+}
+
+// the rest of a JSON string (after the opening quote)
+fn parse_str(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<String, String> {
+    let mut s = String::new();
+
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(s),
+
+            Some('\\') => match chars.next() {
+                Some('"') => s.push('"'),
+                Some('\\') => s.push('\\'),
+                Some('/') => s.push('/'),
+                Some('b') => s.push('\u{8}'),
+                Some('f') => s.push('\u{c}'),
+                Some('n') => s.push('\n'),
+                Some('r') => s.push('\r'),
+                Some('t') => s.push('\t'),
+                Some('u') => s.push(parse_unicode_escape(chars)?),
+                Some(c) => return Err(format!("invalid escape '\\{}'", c)),
+                None => return Err(String::from("unterminated string")),
+            },
+
+            Some(c) => s.push(c),
+
+            None => return Err(String::from("unterminated string")),
+        }
+    }
+}
+
+// the rest of a '\uXXXX' escape (after the 'u'), the characters outside
+// the BMP are escaped as a UTF-16 surrogate pair (e.g. '\uD83D\uDE00')
+fn parse_unicode_escape(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<char, String> {
+    let hex = chars.by_ref().take(4).collect::<String>();
+    let invalid = || format!("invalid unicode escape '\\u{}'", hex);
+
+    let high = match u32::from_str_radix(&hex, 16) {
+        Ok(code) if hex.len() == 4 => code,
+        _ => return Err(invalid()),
+    };
+
+    if !(0xD800..0xDC00).contains(&high) {
+        return char::from_u32(high).ok_or_else(invalid);
+    };
+
+    if chars.next() != Some('\\') || chars.next() != Some('u') {
+        return Err(invalid());
+    };
+
+    let low_hex = chars.by_ref().take(4).collect::<String>();
+
+    match u32::from_str_radix(&low_hex, 16) {
+        Ok(low) if low_hex.len() == 4 && (0xDC00..0xE000).contains(&low) => {
+            char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+                .ok_or_else(invalid)
+        }
+
+        _ => Err(format!("invalid unicode escape '\\u{}\\u{}'", hex, low_hex)),
+    }
+}
@@ -5,6 +5,16 @@ use syn::spanned::Spanned;
 
 pub mod msgs_attribute;
 
+pub mod locales;
+
+pub mod translations;
+
+pub mod statics;
+
+pub mod groups;
+
+pub mod validators;
+
 #[track_caller]
 #[inline]
 pub fn compile_err<M: std::fmt::Display, S: Spanned>(s: &S, err: M) -> TokenStream {
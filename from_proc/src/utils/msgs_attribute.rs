@@ -1,47 +1,184 @@
-use std::collections::HashMap;
+use std::{cell::RefCell, collections::HashMap};
 
 use proc_macro::TokenStream;
 
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
-use syn::ext::IdentExt;
+use quote::{format_ident, quote, ToTokens};
+use syn::{ext::IdentExt, Ident, Lit, Path};
 
 use crate::metas_holder::{value::FromExpr, MetaNameValues};
 
 use super::Append;
 
+// the catalog of the default messages ('messages = MyCatalog') of
+// the struct being expanded
+thread_local! {
+    static CATALOG: RefCell<Option<Path>> = const { RefCell::new(None) };
+}
+
+#[inline]
+pub fn set_catalog(catalog: Option<Path>) {
+    CATALOG.with(|current| *current.borrow_mut() = catalog);
+}
+
+#[inline]
+pub fn catalog() -> Option<Path> {
+    CATALOG.with(|current| current.borrow().clone())
+}
+
+// the code of a built-in constraint (e.g. 'max_len' for the
+// 'ErrKind::MaxLen' kind) and its parameters (e.g. 'max' and
+// 'actual'), evaluated where the error is constructed so 'val'
+// can be used
+//
+// the parameters, the extra placeholders and 'field' are the
+// placeholders of the messages (e.g. '{max}')
+pub struct Code {
+    code: &'static str,
+    params: Vec<(&'static str, TokenStream2)>,
+    placeholders: Vec<(&'static str, TokenStream2)>,
+}
+
+#[inline]
+pub fn code(code: &'static str) -> Code {
+    Code {
+        code,
+        params: Vec::new(),
+        placeholders: Vec::new(),
+    }
+}
+
+impl Code {
+    #[inline]
+    pub fn param<T: ToTokens>(mut self, name: &'static str, value: T) -> Self {
+        self.params.push((name, value.into_token_stream()));
+        self
+    }
+
+    #[inline]
+    pub fn list_param<T: ToTokens>(self, name: &'static str, values: &[T]) -> Self {
+        self.param(
+            name,
+            quote! {::from::Param::List(vec![#(::from::Param::from(#values)),*])},
+        )
+    }
+
+    // a placeholder that is not a parameter (e.g. '{value}' of
+    // 'max(value = 5)' which is the 'max' parameter)
+    #[inline]
+    pub fn placeholder<T: ToTokens>(mut self, name: &'static str, value: T) -> Self {
+        self.placeholders.push((name, value.into_token_stream()));
+        self
+    }
+
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.code
+    }
+
+    #[inline]
+    pub fn has(&self, name: &str) -> bool {
+        self.placeholder_value(name).is_some()
+    }
+
+    // the text of a literal parameter (e.g. 'true' of 'exclusive')
+    #[inline]
+    pub fn literal(&self, name: &str) -> Option<String> {
+        literal(self.placeholder_value(name)?)
+    }
+
+    fn placeholder_value(&self, name: &str) -> Option<&TokenStream2> {
+        self.params
+            .iter()
+            .chain(self.placeholders.iter())
+            .find(|(param_name, _)| *param_name == name)
+            .map(|(_, value)| value)
+    }
+
+    fn placeholders_names(&self) -> String {
+        let mut names = vec!["{field}".to_string()];
+
+        for (name, _) in self.params.iter().chain(self.placeholders.iter()) {
+            names.push(format!("{{{}}}", name));
+        }
+
+        names.join(", ")
+    }
+
+    // 'max_len' => 'MaxLen'
+    fn kind(&self) -> Ident {
+        format_ident!(
+            "{}",
+            self.code
+                .split('_')
+                .map(|word| word[..1].to_uppercase() + &word[1..])
+                .collect::<String>()
+        )
+    }
+
+    fn fields(&self) -> TokenStream2 {
+        let kind = self.kind();
+
+        let params = match self.params.len() {
+            0 => quote! {Vec::new()},
+
+            _ => {
+                let params = self.params.iter().map(|(name, value)| {
+                    quote! {(#name, ::from::Param::from(#value))}
+                });
+
+                quote! {vec![#(#params),*]}
+            }
+        };
+
+        quote! {
+            kind: ::from::ErrKind::#kind,
+            params: #params,
+        }
+    }
+}
+
 #[track_caller]
 #[inline(always)]
 pub fn process<F>(
     meta_name_values: &MetaNameValues,
     prop_or_idx: &TokenStream2,
     dflt_lang: &str,
+    code: Code,
     backup_msg: F,
 ) -> Result<(TokenStream2, TokenStream2), TokenStream>
 where
     F: Fn() -> TokenStream2,
 {
-    let mut langs_msgs = convert_to_hash_map(meta_name_values)?;
+    let langs_msgs = convert_to_hash_map(meta_name_values, prop_or_idx, &code)?;
 
-    let dflt_msg = match langs_msgs.remove(dflt_lang) {
-        Some(msg) => quote! {String::from(#msg)},
-        None => backup_msg(),
+    let backup_msg = match langs_msgs.contains_key(dflt_lang) {
+        true => TokenStream2::new(),
+        false => backup_msg(),
     };
 
-    Ok(construct(langs_msgs, prop_or_idx, dflt_lang, dflt_msg))
+    Ok(construct(langs_msgs, prop_or_idx, dflt_lang, code, backup_msg))
 }
 
+// the messages are converted into the expressions that build them
 #[track_caller]
 #[inline]
-fn convert_to_hash_map(metas: &MetaNameValues) -> Result<HashMap<String, String>, TokenStream> {
-    let mut lang_msgs = HashMap::<String, String>::new();
+fn convert_to_hash_map(
+    metas: &MetaNameValues,
+    prop_or_idx: &TokenStream2,
+    code: &Code,
+) -> Result<HashMap<String, TokenStream2>, TokenStream> {
+    let mut lang_msgs = HashMap::<String, TokenStream2>::new();
 
     for nv in metas {
         match nv.path.get_ident() {
             Some(ident) => {
                 let ident = ident.unraw().to_string();
 
-                lang_msgs.insert(ident, String::from_expr(&nv.value)?);
+                let msg = render(&String::from_expr(&nv.value)?, prop_or_idx, code)
+                    .map_err(|err| super::compile_err(&nv.value, err))?;
+
+                lang_msgs.insert(ident, msg);
             }
 
             None => continue,
@@ -51,62 +188,278 @@ fn convert_to_hash_map(metas: &MetaNameValues) -> Result<HashMap<String, String>
     Ok(lang_msgs)
 }
 
+/// Returns the expression that builds the message with its
+/// placeholders (e.g. `{max}`) replaced: literal values (e.g. the
+/// limits) are replaced at compile time while the others (e.g.
+/// `{actual}`) are formatted at runtime. `{{` and `}}` are the
+/// escaped braces.
+fn render(msg: &str, prop_or_idx: &TokenStream2, code: &Code) -> Result<TokenStream2, String> {
+    let mut fmt = String::new();
+    let mut args = Vec::new();
+    let mut chars = msg.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.as_str().starts_with('{') => {
+                chars.next();
+                fmt.push_str("{{");
+            }
+
+            '}' if chars.as_str().starts_with('}') => {
+                chars.next();
+                fmt.push_str("}}");
+            }
+
+            '{' => {
+                let rest = chars.as_str();
+
+                let end = match rest.find('}') {
+                    Some(end) => end,
+                    None => return Err(String::from("unclosed '{' (use '{{' to escape it)")),
+                };
+
+                let name = &rest[..end];
+                chars = rest[end + 1..].chars();
+
+                let value = match name {
+                    "field" => prop_or_idx,
+                    _ => code.placeholder_value(name).ok_or_else(|| {
+                        format!(
+                            "unknown placeholder '{{{}}}', expected one of: {}",
+                            name,
+                            code.placeholders_names()
+                        )
+                    })?,
+                };
+
+                match literal(value) {
+                    Some(lit) => fmt.push_str(&lit.replace('{', "{{").replace('}', "}}")),
+
+                    None => {
+                        fmt.push_str("{}");
+                        args.push(quote! {::from::Param::from(#value)});
+                    }
+                };
+            }
+
+            '}' => return Err(String::from("unmatched '}' (use '}}' to escape it)")),
+
+            c => fmt.push(c),
+        };
+    }
+
+    if args.is_empty() {
+        let msg = fmt.replace("{{", "{").replace("}}", "}");
+
+        return Ok(quote! {String::from(#msg)});
+    };
+
+    Ok(quote! {format!(#fmt, #(#args),*)})
+}
+
+// the text of a literal placeholder value
+fn literal(value: &TokenStream2) -> Option<String> {
+    match syn::parse2::<Lit>(value.clone()).ok()? {
+        Lit::Str(lit) => Some(lit.value()),
+        Lit::Char(lit) => Some(lit.value().to_string()),
+        Lit::Bool(lit) => Some(lit.value.to_string()),
+        Lit::Int(lit) => Some(lit.base10_digits().to_string()),
+
+        // formatted the same way as the default messages
+        Lit::Float(lit) => match lit.suffix() {
+            "f32" => Some(lit.base10_parse::<f32>().ok()?.to_string()),
+            _ => Some(lit.base10_parse::<f64>().ok()?.to_string()),
+        },
+
+        _ => None,
+    }
+}
+
 #[inline(always)]
 pub fn construct(
-    langs_msgs: HashMap<String, String>,
+    mut langs_msgs: HashMap<String, TokenStream2>,
     prop_or_idx: &TokenStream2,
     dflt_lang: &str,
-    dflt_msg: TokenStream2,
+    code: Code,
+    backup_msg: TokenStream2,
 ) -> (TokenStream2, TokenStream2) {
-    let single_msg_err = quote! {
-        ::from::ValidationErr {
-            target: From::from(#prop_or_idx),
-            path: path.clone(),
-            msg: #dflt_msg,
-        }
+    let catalog = catalog();
+    let kind = code.kind();
+    let fields = code.fields();
+
+    // 'msgs' of the field itself (not the locale files)
+    let custom_msgs = !langs_msgs.is_empty();
+
+    // the messages of the locale files ('locales = "locales/"'), the
+    // custom messages take precedence over them
+    let field = match syn::parse2::<Lit>(prop_or_idx.clone()) {
+        Ok(Lit::Str(lit)) => Some(lit.value()),
+        _ => None,
     };
 
-    let multi_msgs_err = {
-        match langs_msgs.len() {
-            0 => single_msg_err.clone(),
+    for (lang, file, template) in super::locales::templates(field.as_deref(), code.code) {
+        if langs_msgs.contains_key(&lang) {
+            continue;
+        };
 
-            // > 1
-            _ => {
-                let mut arms = TokenStream2::new();
-                for (lang, msg) in langs_msgs {
-                    arms.append(quote! {
-                        #lang => String::from(#msg),
-                    });
+        let msg = render(&template, prop_or_idx, &code).unwrap_or_else(|err| {
+            let err = format!("{}: '{}': {}", file, code.code, err);
+            quote! {compile_error!(#err)}
+        });
+
+        langs_msgs.insert(lang, msg);
+    }
+
+    // the built-in translations ('locale-*' features), the catalog
+    // takes precedence over them. A field with custom messages falls
+    // back to its own default message in the other languages, so only
+    // the translation of 'dflt_lang' (its default message) is kept
+    let mut translations = Vec::new();
+    let mut backup_msg = backup_msg;
+
+    for (lang, template) in super::translations::templates(&code) {
+        if langs_msgs.contains_key(lang) || (custom_msgs && lang != dflt_lang) {
+            continue;
+        };
+
+        let msg = render(template, prop_or_idx, &code).unwrap_or_else(|err| {
+            let err = format!("'{}' translation of '{}': {}", lang, code.code, err);
+            quote! {compile_error!(#err)}
+        });
+
+        match lang == dflt_lang {
+            true => backup_msg = msg,
+            false => translations.push((lang, msg)),
+        };
+    }
+
+    // the custom messages take precedence over the catalog
+    let (custom_dflt_msg, dflt_msg) = match langs_msgs.remove(dflt_lang) {
+        Some(msg) => (true, msg),
+        None => (false, backup_msg),
+    };
+
+    let from_catalog = |lang: TokenStream2| match &catalog {
+        Some(catalog) => quote! {
+            <#catalog as ::from::Messages>::template(::from::ErrKind::#kind, #lang)
+                .map(|template| __err.render(template))
+        },
+
+        None => quote! {None},
+    };
+
+    // the catalog templates are rendered with the params of the error
+    let err = |msg: TokenStream2| match catalog {
+        Some(_) => quote! {
+            {
+                let mut __err = ::from::ValidationErr {
+                    target: From::from(#prop_or_idx),
+                    path: path.clone(),
+                    msg: String::new(),
+                    #fields
+                };
+
+                __err.msg = #msg;
+
+                __err
+            }
+        },
+
+        None => quote! {
+            ::from::ValidationErr {
+                target: From::from(#prop_or_idx),
+                path: path.clone(),
+                msg: #msg,
+                #fields
+            }
+        },
+    };
+
+    let dflt_msg = match custom_dflt_msg || catalog.is_none() {
+        true => dflt_msg,
+
+        false => {
+            let msg = from_catalog(quote! {#dflt_lang});
+
+            quote! {
+                match #msg {
+                    Some(msg) => msg,
+                    None => #dflt_msg,
                 }
+            }
+        }
+    };
+
+    let single_msg_err = err(dflt_msg.clone());
+
+    if langs_msgs.is_empty() && translations.is_empty() && catalog.is_none() {
+        return (single_msg_err.clone(), single_msg_err);
+    };
+
+    // the language tags are looked up lowercased (see 'from::lang')
+    let tag = |lang: &str| lang.replace('_', "-").to_ascii_lowercase();
+
+    let mut arms = TokenStream2::new();
+    for (lang, msg) in langs_msgs {
+        let lang = tag(&lang);
 
-                arms.append(quote! { #dflt_lang | _ => #dflt_msg, });
+        arms.append(quote! {
+            #lang => Some(#msg),
+        });
+    }
+
+    for (lang, msg) in translations {
+        let lang = tag(lang);
+
+        let msg = match &catalog {
+            Some(_) => {
+                let from_catalog = from_catalog(quote! {lang});
 
                 quote! {
-                    ::from::ValidationErr {
-                        target: From::from(#prop_or_idx),
-                        path: path.clone(),
-                        msg: match lang {#arms},
+                    match #from_catalog {
+                        Some(msg) => msg,
+                        None => #msg,
                     }
                 }
             }
+
+            None => msg,
+        };
+
+        arms.append(quote! {
+            #lang => Some(#msg),
+        });
+    }
+
+    // the default language is a candidate as well (e.g. 'en' of 'en,ar')
+    let lang = tag(dflt_lang);
+    arms.append(quote! { #lang => Some(#dflt_msg), });
+
+    let fallback = from_catalog(quote! {lang});
+    arms.append(quote! { _ => #fallback, });
+
+    let msg = quote! {
+        match ::from::lang::negotiate(lang, |lang| match lang {#arms}) {
+            Some(msg) => msg,
+            None => #dflt_msg,
         }
     };
 
-    (single_msg_err, multi_msgs_err)
+    (single_msg_err, err(msg))
 }
 
 #[inline]
 pub fn construct_dflt(
     prop_or_idx: &TokenStream2,
+    dflt_lang: &str,
+    code: Code,
     dflt_msg: TokenStream2,
 ) -> (TokenStream2, TokenStream2) {
-    let msg = quote! {
-        ::from::ValidationErr {
-            target: From::from(#prop_or_idx),
-            path: path.clone(),
-            msg: String::from(#dflt_msg),
-        }
-    };
-
-    (msg.clone(), msg)
+    construct(
+        HashMap::new(),
+        prop_or_idx,
+        dflt_lang,
+        code,
+        quote! {String::from(#dflt_msg)},
+    )
 }
@@ -0,0 +1,27 @@
+use std::{cell::RefCell, collections::BTreeMap};
+
+use proc_macro2::TokenStream as TokenStream2;
+
+// Items (e.g. lazily initialized statics) shared by all the
+// generated methods of the struct being expanded. They are
+// collected while the fields are processed and emitted once
+// next to the impls, deduplicated by name.
+thread_local! {
+    static STATICS: RefCell<BTreeMap<String, TokenStream2>> = RefCell::new(BTreeMap::new());
+}
+
+#[inline]
+pub fn register(name: String, item: TokenStream2) {
+    STATICS.with(|statics| {
+        statics.borrow_mut().entry(name).or_insert(item);
+    });
+}
+
+#[inline]
+pub fn take() -> TokenStream2 {
+    STATICS.with(|statics| {
+        let mut items = TokenStream2::new();
+        items.extend(std::mem::take(&mut *statics.borrow_mut()).into_values());
+        items
+    })
+}
@@ -0,0 +1,67 @@
+pub fn template(key: &str) -> Option<&'static str> {
+    let template = match key {
+        "required" | "required_if" | "required_unless" => "حقل مطلوب",
+        "exactly_one_of" => "يجب تقديم واحد فقط من {fields}",
+        "at_most_one_of" => "لا يمكن تقديم أكثر من واحد من {fields}",
+        "not_null" => "المتوقع: {expected}، المستلم: null",
+        "type_mismatch" => "المتوقع: {expected}، المستلم: {found}",
+        "too_large" => "الرقم أكبر من أن يتسع في النوع '{type}'",
+        "too_small" => "الرقم أصغر من أن يتسع في النوع '{type}'",
+
+        "max" => "يجب أن يكون الرقم أصغر من أو يساوي {max}",
+        "max_exclusive" => "يجب أن يكون الرقم أصغر من {max}",
+        "min" => "يجب أن يكون الرقم أكبر من أو يساوي {min}",
+        "min_exclusive" => "يجب أن يكون الرقم أكبر من {min}",
+        "range" => "يجب أن يكون الرقم بين {min} و {max}",
+        "range_exclusive" => "يجب أن يكون الرقم بين {min} و {max} (دون الحدين)",
+        "multiple_of" => "يجب أن يكون الرقم من مضاعفات {multiple_of}",
+        "enum" => "يجب أن تكون القيمة إحدى: {values}",
+        "not_enum" => "القيمة {actual} غير مسموح بها",
+        "infinite" => "يجب ألا يكون الرقم لا نهائيا",
+        "max_fracs" => "يجب ألا يزيد عدد الأرقام العشرية عن {max}",
+
+        "len_bytes" => "يجب أن يكون طول النص بالبايت: {len}",
+        "len_chars" => "يجب أن يكون عدد أحرف النص: {len}",
+        "len_utf16" => "يجب أن يكون عدد وحدات UTF-16 في النص: {len}",
+        "len_elements" => "يجب أن يكون عدد عناصر المصفوفة: {len}",
+        "max_len_bytes" => "الحد الأقصى لطول النص بالبايت: {max}",
+        "max_len_chars" => "الحد الأقصى لعدد أحرف النص: {max}",
+        "max_len_utf16" => "الحد الأقصى لعدد وحدات UTF-16 في النص: {max}",
+        "max_len_elements" => "الحد الأقصى لعدد عناصر المصفوفة: {max}",
+        "min_len_bytes" => "الحد الأدنى لطول النص بالبايت: {min}",
+        "min_len_chars" => "الحد الأدنى لعدد أحرف النص: {min}",
+        "min_len_utf16" => "الحد الأدنى لعدد وحدات UTF-16 في النص: {min}",
+        "min_len_elements" => "الحد الأدنى لعدد عناصر المصفوفة: {min}",
+
+        "starts_with" => "يجب أن تبدأ القيمة بـ: \"{value}\"",
+        "ends_with" => "يجب أن تنتهي القيمة بـ: \"{value}\"",
+        "contains" => "يجب أن تحتوي القيمة على: \"{value}\"",
+        "not_contains" => "يجب ألا تحتوي القيمة على: \"{value}\"",
+        "contains_missing" => "يجب أن تحتوي المصفوفة على: {missing}",
+        "contains_values" => "يجب أن تحتوي المصفوفة على جميع القيم المطلوبة",
+        "charset" => "الحرف '{char}' غير مسموح به",
+        "pattern" => "يجب أن تطابق القيمة النمط: {pattern}",
+
+        "format_email" => "يجب أن تكون القيمة بريدا إلكترونيا صالحا",
+        "format_url" => "يجب أن تكون القيمة رابطا (URL) صالحا",
+        "format_uuid" => "يجب أن تكون القيمة معرفا (UUID) صالحا",
+        "format_hostname" => "يجب أن تكون القيمة اسم مضيف صالحا",
+        "format_ipv4" => "يجب أن تكون القيمة عنوان IPv4 صالحا",
+        "format_ipv6" => "يجب أن تكون القيمة عنوان IPv6 صالحا",
+        "format_ip" => "يجب أن تكون القيمة عنوان IP صالحا",
+        "format_date" => "يجب أن تكون القيمة تاريخا صالحا (YYYY-MM-DD)",
+        "format_time" => "يجب أن تكون القيمة وقتا صالحا (HH:MM:SS)",
+        "format_date_time" => "يجب أن تكون القيمة تاريخا ووقتا صالحين (RFC 3339)",
+
+        "sorted" => "يجب أن تكون المصفوفة مرتبة تصاعديا (العنصر في الموضع {index} خارج الترتيب)",
+        "sorted_desc" => "يجب أن تكون المصفوفة مرتبة تنازليا (العنصر في الموضع {index} خارج الترتيب)",
+        "must_be" => "يجب أن تكون القيمة {value}",
+        "eq_field" => "يجب أن يساوي '{other}'",
+        "gt_field" => "يجب أن يكون أكبر من '{other}'",
+        "lt_field" => "يجب أن يكون أصغر من '{other}'",
+
+        _ => return None,
+    };
+
+    Some(template)
+}
@@ -0,0 +1,67 @@
+pub fn template(key: &str) -> Option<&'static str> {
+    let template = match key {
+        "required" | "required_if" | "required_unless" => "Pflichtfeld",
+        "exactly_one_of" => "genau eines von {fields} muss angegeben werden",
+        "at_most_one_of" => "höchstens eines von {fields} darf angegeben werden",
+        "not_null" => "erwartet: {expected}, erhalten: null",
+        "type_mismatch" => "erwartet: {expected}, erhalten: {found}",
+        "too_large" => "die Zahl ist zu groß für den Typ '{type}'",
+        "too_small" => "die Zahl ist zu klein für den Typ '{type}'",
+
+        "max" => "die Zahl muss kleiner oder gleich {max} sein",
+        "max_exclusive" => "die Zahl muss kleiner als {max} sein",
+        "min" => "die Zahl muss größer oder gleich {min} sein",
+        "min_exclusive" => "die Zahl muss größer als {min} sein",
+        "range" => "die Zahl muss zwischen {min} und {max} liegen",
+        "range_exclusive" => "die Zahl muss zwischen {min} und {max} liegen (ausschließlich)",
+        "multiple_of" => "die Zahl muss ein Vielfaches von {multiple_of} sein",
+        "enum" => "der Wert muss einer der folgenden sein: {values}",
+        "not_enum" => "der Wert {actual} ist nicht erlaubt",
+        "infinite" => "die Zahl darf nicht unendlich sein",
+        "max_fracs" => "die Anzahl der Nachkommastellen darf {max} nicht überschreiten",
+
+        "len_bytes" => "die Zeichenkette muss {len} Bytes lang sein",
+        "len_chars" => "die Zeichenkette muss {len} Zeichen lang sein",
+        "len_utf16" => "die Zeichenkette muss {len} UTF-16-Codeeinheiten lang sein",
+        "len_elements" => "das Array muss {len} Elemente enthalten",
+        "max_len_bytes" => "die Zeichenkette darf nicht länger als {max} Bytes sein",
+        "max_len_chars" => "die Zeichenkette darf nicht länger als {max} Zeichen sein",
+        "max_len_utf16" => "die Zeichenkette darf nicht länger als {max} UTF-16-Codeeinheiten sein",
+        "max_len_elements" => "das Array darf nicht mehr als {max} Elemente enthalten",
+        "min_len_bytes" => "die Zeichenkette muss mindestens {min} Bytes lang sein",
+        "min_len_chars" => "die Zeichenkette muss mindestens {min} Zeichen lang sein",
+        "min_len_utf16" => "die Zeichenkette muss mindestens {min} UTF-16-Codeeinheiten lang sein",
+        "min_len_elements" => "das Array muss mindestens {min} Elemente enthalten",
+
+        "starts_with" => "der Wert muss beginnen mit: \"{value}\"",
+        "ends_with" => "der Wert muss enden mit: \"{value}\"",
+        "contains" => "der Wert muss enthalten: \"{value}\"",
+        "not_contains" => "der Wert darf nicht enthalten: \"{value}\"",
+        "contains_missing" => "das Array muss enthalten: {missing}",
+        "contains_values" => "das Array muss alle erforderlichen Werte enthalten",
+        "charset" => "das Zeichen '{char}' ist nicht erlaubt",
+        "pattern" => "der Wert muss dem Muster entsprechen: {pattern}",
+
+        "format_email" => "der Wert muss eine gültige E-Mail-Adresse sein",
+        "format_url" => "der Wert muss eine gültige URL sein",
+        "format_uuid" => "der Wert muss eine gültige UUID sein",
+        "format_hostname" => "der Wert muss ein gültiger Hostname sein",
+        "format_ipv4" => "der Wert muss eine gültige IPv4-Adresse sein",
+        "format_ipv6" => "der Wert muss eine gültige IPv6-Adresse sein",
+        "format_ip" => "der Wert muss eine gültige IP-Adresse sein",
+        "format_date" => "der Wert muss ein gültiges Datum sein (JJJJ-MM-TT)",
+        "format_time" => "der Wert muss eine gültige Uhrzeit sein (HH:MM:SS)",
+        "format_date_time" => "der Wert muss ein gültiger Zeitstempel sein (RFC 3339)",
+
+        "sorted" => "das Array muss aufsteigend sortiert sein (das Element an Index {index} ist nicht in der Reihenfolge)",
+        "sorted_desc" => "das Array muss absteigend sortiert sein (das Element an Index {index} ist nicht in der Reihenfolge)",
+        "must_be" => "der Wert muss {value} sein",
+        "eq_field" => "muss gleich '{other}' sein",
+        "gt_field" => "muss größer als '{other}' sein",
+        "lt_field" => "muss kleiner als '{other}' sein",
+
+        _ => return None,
+    };
+
+    Some(template)
+}
@@ -0,0 +1,67 @@
+pub fn template(key: &str) -> Option<&'static str> {
+    let template = match key {
+        "required" | "required_if" | "required_unless" => "campo obligatorio",
+        "exactly_one_of" => "se debe proporcionar exactamente uno de {fields}",
+        "at_most_one_of" => "se puede proporcionar como máximo uno de {fields}",
+        "not_null" => "se esperaba: {expected}, se recibió: null",
+        "type_mismatch" => "se esperaba: {expected}, se recibió: {found}",
+        "too_large" => "el número es demasiado grande para el tipo '{type}'",
+        "too_small" => "el número es demasiado pequeño para el tipo '{type}'",
+
+        "max" => "el número debe ser menor o igual que {max}",
+        "max_exclusive" => "el número debe ser menor que {max}",
+        "min" => "el número debe ser mayor o igual que {min}",
+        "min_exclusive" => "el número debe ser mayor que {min}",
+        "range" => "el número debe estar entre {min} y {max}",
+        "range_exclusive" => "el número debe estar entre {min} y {max} (exclusivo)",
+        "multiple_of" => "el número debe ser múltiplo de {multiple_of}",
+        "enum" => "el valor debe ser uno de: {values}",
+        "not_enum" => "el valor {actual} no está permitido",
+        "infinite" => "el número no debe ser infinito",
+        "max_fracs" => "el número de decimales no debe ser mayor que {max}",
+
+        "len_bytes" => "la cadena debe tener {len} bytes",
+        "len_chars" => "la cadena debe tener {len} caracteres",
+        "len_utf16" => "la cadena debe tener {len} unidades de código UTF-16",
+        "len_elements" => "el arreglo debe contener {len} elementos",
+        "max_len_bytes" => "la cadena no debe tener más de {max} bytes",
+        "max_len_chars" => "la cadena no debe tener más de {max} caracteres",
+        "max_len_utf16" => "la cadena no debe tener más de {max} unidades de código UTF-16",
+        "max_len_elements" => "el arreglo no debe contener más de {max} elementos",
+        "min_len_bytes" => "la cadena debe tener al menos {min} bytes",
+        "min_len_chars" => "la cadena debe tener al menos {min} caracteres",
+        "min_len_utf16" => "la cadena debe tener al menos {min} unidades de código UTF-16",
+        "min_len_elements" => "el arreglo debe contener al menos {min} elementos",
+
+        "starts_with" => "el valor debe comenzar con: \"{value}\"",
+        "ends_with" => "el valor debe terminar con: \"{value}\"",
+        "contains" => "el valor debe contener: \"{value}\"",
+        "not_contains" => "el valor no debe contener: \"{value}\"",
+        "contains_missing" => "el arreglo debe contener: {missing}",
+        "contains_values" => "el arreglo debe contener todos los valores requeridos",
+        "charset" => "el carácter '{char}' no está permitido",
+        "pattern" => "el valor debe coincidir con el patrón: {pattern}",
+
+        "format_email" => "el valor debe ser una dirección de correo electrónico válida",
+        "format_url" => "el valor debe ser una URL válida",
+        "format_uuid" => "el valor debe ser un UUID válido",
+        "format_hostname" => "el valor debe ser un nombre de host válido",
+        "format_ipv4" => "el valor debe ser una dirección IPv4 válida",
+        "format_ipv6" => "el valor debe ser una dirección IPv6 válida",
+        "format_ip" => "el valor debe ser una dirección IP válida",
+        "format_date" => "el valor debe ser una fecha válida (AAAA-MM-DD)",
+        "format_time" => "el valor debe ser una hora válida (HH:MM:SS)",
+        "format_date_time" => "el valor debe ser una fecha y hora válidas (RFC 3339)",
+
+        "sorted" => "el arreglo debe estar ordenado de forma ascendente (el elemento en el índice {index} está fuera de orden)",
+        "sorted_desc" => "el arreglo debe estar ordenado de forma descendente (el elemento en el índice {index} está fuera de orden)",
+        "must_be" => "el valor debe ser {value}",
+        "eq_field" => "debe ser igual a '{other}'",
+        "gt_field" => "debe ser mayor que '{other}'",
+        "lt_field" => "debe ser menor que '{other}'",
+
+        _ => return None,
+    };
+
+    Some(template)
+}
@@ -0,0 +1,67 @@
+pub fn template(key: &str) -> Option<&'static str> {
+    let template = match key {
+        "required" | "required_if" | "required_unless" => "champ obligatoire",
+        "exactly_one_of" => "exactement un champ parmi {fields} doit être fourni",
+        "at_most_one_of" => "au plus un champ parmi {fields} peut être fourni",
+        "not_null" => "attendu : {expected}, reçu : null",
+        "type_mismatch" => "attendu : {expected}, reçu : {found}",
+        "too_large" => "le nombre est trop grand pour le type '{type}'",
+        "too_small" => "le nombre est trop petit pour le type '{type}'",
+
+        "max" => "le nombre doit être inférieur ou égal à {max}",
+        "max_exclusive" => "le nombre doit être inférieur à {max}",
+        "min" => "le nombre doit être supérieur ou égal à {min}",
+        "min_exclusive" => "le nombre doit être supérieur à {min}",
+        "range" => "le nombre doit être compris entre {min} et {max}",
+        "range_exclusive" => "le nombre doit être compris entre {min} et {max} (exclus)",
+        "multiple_of" => "le nombre doit être un multiple de {multiple_of}",
+        "enum" => "la valeur doit être l'une des suivantes : {values}",
+        "not_enum" => "la valeur {actual} n'est pas autorisée",
+        "infinite" => "le nombre ne doit pas être infini",
+        "max_fracs" => "le nombre de décimales ne doit pas dépasser {max}",
+
+        "len_bytes" => "la chaîne doit contenir {len} octets",
+        "len_chars" => "la chaîne doit contenir {len} caractères",
+        "len_utf16" => "la chaîne doit contenir {len} unités de code UTF-16",
+        "len_elements" => "le tableau doit contenir {len} éléments",
+        "max_len_bytes" => "la chaîne ne doit pas dépasser {max} octets",
+        "max_len_chars" => "la chaîne ne doit pas dépasser {max} caractères",
+        "max_len_utf16" => "la chaîne ne doit pas dépasser {max} unités de code UTF-16",
+        "max_len_elements" => "le tableau ne doit pas contenir plus de {max} éléments",
+        "min_len_bytes" => "la chaîne doit contenir au moins {min} octets",
+        "min_len_chars" => "la chaîne doit contenir au moins {min} caractères",
+        "min_len_utf16" => "la chaîne doit contenir au moins {min} unités de code UTF-16",
+        "min_len_elements" => "le tableau doit contenir au moins {min} éléments",
+
+        "starts_with" => "la valeur doit commencer par : \"{value}\"",
+        "ends_with" => "la valeur doit se terminer par : \"{value}\"",
+        "contains" => "la valeur doit contenir : \"{value}\"",
+        "not_contains" => "la valeur ne doit pas contenir : \"{value}\"",
+        "contains_missing" => "le tableau doit contenir : {missing}",
+        "contains_values" => "le tableau doit contenir toutes les valeurs requises",
+        "charset" => "le caractère '{char}' n'est pas autorisé",
+        "pattern" => "la valeur doit correspondre au motif : {pattern}",
+
+        "format_email" => "la valeur doit être une adresse e-mail valide",
+        "format_url" => "la valeur doit être une URL valide",
+        "format_uuid" => "la valeur doit être un UUID valide",
+        "format_hostname" => "la valeur doit être un nom d'hôte valide",
+        "format_ipv4" => "la valeur doit être une adresse IPv4 valide",
+        "format_ipv6" => "la valeur doit être une adresse IPv6 valide",
+        "format_ip" => "la valeur doit être une adresse IP valide",
+        "format_date" => "la valeur doit être une date valide (AAAA-MM-JJ)",
+        "format_time" => "la valeur doit être une heure valide (HH:MM:SS)",
+        "format_date_time" => "la valeur doit être une date et heure valide (RFC 3339)",
+
+        "sorted" => "le tableau doit être trié par ordre croissant (l'élément à l'indice {index} n'est pas à sa place)",
+        "sorted_desc" => "le tableau doit être trié par ordre décroissant (l'élément à l'indice {index} n'est pas à sa place)",
+        "must_be" => "la valeur doit être {value}",
+        "eq_field" => "doit être égal à '{other}'",
+        "gt_field" => "doit être supérieur à '{other}'",
+        "lt_field" => "doit être inférieur à '{other}'",
+
+        _ => return None,
+    };
+
+    Some(template)
+}
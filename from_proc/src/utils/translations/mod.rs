@@ -0,0 +1,66 @@
+use super::msgs_attribute::Code;
+
+#[cfg(feature = "locale-ar")]
+mod ar;
+
+#[cfg(feature = "locale-de")]
+mod de;
+
+#[cfg(feature = "locale-es")]
+mod es;
+
+#[cfg(feature = "locale-fr")]
+mod fr;
+
+// the key of the default message of the constraint, its code
+// followed by the variant of the message (e.g. 'max_len_chars' or
+// 'min_exclusive')
+fn key(code: &Code) -> String {
+    let name = code.name();
+
+    match name {
+        "max" | "min" | "range" => match code.literal("exclusive").as_deref() {
+            Some("true") => format!("{}_exclusive", name),
+            _ => name.to_string(),
+        },
+
+        // the arrays have no unit
+        "len" | "max_len" | "min_len" => match code.literal("unit") {
+            Some(unit) => format!("{}_{}", name, unit),
+            None => format!("{}_elements", name),
+        },
+
+        // the strings have a value, the arrays the missing element
+        // (if its values are literals)
+        "contains" if !code.has("value") => match code.has("missing") {
+            true => String::from("contains_missing"),
+            false => String::from("contains_values"),
+        },
+
+        "format" => format!("format_{}", code.literal("format").unwrap_or_default()),
+
+        _ => name.to_string(),
+    }
+}
+
+/// Returns the `(lang, template)` of the translations of the default
+/// message of the constraint, one per enabled `locale-*` feature.
+#[allow(unused_mut, unused_variables)]
+pub fn templates(code: &Code) -> Vec<(&'static str, &'static str)> {
+    let key = key(code);
+    let mut templates = Vec::new();
+
+    #[cfg(feature = "locale-ar")]
+    templates.extend(ar::template(&key).map(|template| ("ar", template)));
+
+    #[cfg(feature = "locale-de")]
+    templates.extend(de::template(&key).map(|template| ("de", template)));
+
+    #[cfg(feature = "locale-es")]
+    templates.extend(es::template(&key).map(|template| ("es", template)));
+
+    #[cfg(feature = "locale-fr")]
+    templates.extend(fr::template(&key).map(|template| ("fr", template)));
+
+    templates
+}
@@ -0,0 +1,155 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Expr, Path};
+
+use crate::metas_holder::MetasHolder;
+
+// an entry of the 'validators(..)' attribute
+#[derive(Clone)]
+pub enum Validator {
+    // e.g. 'NotAdmin', called through the static 'Validator' trait
+    Path(Path),
+
+    // e.g. 'MinAge(18)', called through the method-based
+    // 'ParamValidator' trait on the given instance
+    Instance(TokenStream2),
+
+    // e.g. 'UniqueUsername' of 'ctx_validators(..)', called through
+    // the 'ValidatorCtx' trait with the current context, the type of
+    // the context is inferred from the implementation
+    Ctx(Path),
+}
+
+// the four generated calls of a validator, one per parsing mode
+pub struct Calls {
+    pub none: TokenStream2,
+    pub lang: TokenStream2,
+    pub stack_errs: TokenStream2,
+    pub stack_errs_lang: TokenStream2,
+}
+
+// the entries of 'validators(..)' followed by those of 'ctx_validators(..)'
+pub fn parse<H>(metas_holder: &H) -> Result<Vec<Validator>, TokenStream>
+where
+    H: MetasHolder,
+{
+    let mut validators = Vec::new();
+
+    if let Some(exprs) = metas_holder.parse_exprs_from_list_if_found("validators")? {
+        validators.extend(exprs.into_iter().map(|expr| match expr {
+            Expr::Path(expr_path) if expr_path.qself.is_none() => Validator::Path(expr_path.path),
+
+            expr => Validator::Instance(instance(&expr)),
+        }));
+    };
+
+    if let Some(paths) = metas_holder.parse_paths_from_list_if_found("ctx_validators")? {
+        validators.extend(paths.into_iter().map(Validator::Ctx));
+    };
+
+    Ok(validators)
+}
+
+/// Returns the instance of a parameterised validator, borrowed by the
+/// calls (`&(MinAge(18))`). Its type is not named so the generic
+/// validators (e.g. `MinVal(5u32)` of `MinVal<T>`) are inferred, the
+/// constant constructors are promoted to statics by the compiler and
+/// the others (e.g. `Len::new(3)`) are evaluated on every call.
+fn instance(expr: &Expr) -> TokenStream2 {
+    quote! {(#expr)}
+}
+
+impl Validator {
+    /// `val` and `path` are references, `target` is the property or
+    /// index of the value (`None` if it is the last one of `path`).
+    pub fn calls(
+        &self,
+        ty: &TokenStream2,
+        val: &TokenStream2,
+        path: &TokenStream2,
+        target: Option<&TokenStream2>,
+    ) -> Calls {
+        let (callee, receiver) = match self {
+            Validator::Path(validator) => (quote! {<#validator as ::from::Validator<#ty>>}, None),
+
+            Validator::Instance(instance) => (
+                quote! {<_ as ::from::ParamValidator<#ty>>},
+                Some(quote! {&#instance,}),
+            ),
+
+            Validator::Ctx(validator) => return ctx_calls(validator, ty, val, path, target),
+        };
+
+        Calls {
+            none: quote! {
+                if let Err(e) = #callee::none(#receiver #val, #path) {
+                    return Err(From::from(e));
+                }
+            },
+            lang: quote! {
+                if let Err(e) = #callee::lang(#receiver #val, #path, lang) {
+                    return Err(From::from(e));
+                }
+            },
+            stack_errs: quote! {
+                if let Err(mut errs2) = #callee::stack_errs(#receiver #val, #path) {
+                    errs.append(&mut errs2);
+                }
+            },
+            stack_errs_lang: quote! {
+                if let Err(mut errs2) = #callee::stack_errs_lang(#receiver #val, #path, lang) {
+                    errs.append(&mut errs2);
+                }
+            },
+        }
+    }
+}
+
+// fail with a 'MissingCtx' error when no context of the expected type
+// is provided
+fn ctx_calls(
+    validator: &Path,
+    ty: &TokenStream2,
+    val: &TokenStream2,
+    path: &TokenStream2,
+    target: Option<&TokenStream2>,
+) -> Calls {
+    let callee = quote! {<#validator as ::from::ValidatorCtx<#ty, _>>};
+
+    let target = match target {
+        Some(target) => quote! {Some(From::from(#target))},
+        None => quote! {None},
+    };
+
+    Calls {
+        none: quote! {
+            if let Err(e) = ::from::ctx::require(#target, #path, |ctx| {
+                #callee::none(#val, ctx, #path)
+            }) {
+                return Err(From::from(e));
+            }
+        },
+        lang: quote! {
+            if let Err(e) = ::from::ctx::require(#target, #path, |ctx| {
+                #callee::lang(#val, ctx, #path, lang)
+            }) {
+                return Err(From::from(e));
+            }
+        },
+        stack_errs: quote! {
+            if let Err(mut errs2) = ::from::ctx::require_stack_errs(#target, #path, |ctx| {
+                #callee::stack_errs(#val, ctx, #path)
+            }) {
+                errs.append(&mut errs2);
+            }
+        },
+        stack_errs_lang: quote! {
+            if let Err(mut errs2) = ::from::ctx::require_stack_errs(#target, #path, |ctx| {
+                #callee::stack_errs_lang(#val, ctx, #path, lang)
+            }) {
+                errs.append(&mut errs2);
+            }
+        },
+    }
+}
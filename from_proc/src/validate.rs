@@ -0,0 +1,585 @@
+use proc_macro::TokenStream;
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Attribute, Generics, Ident, Meta};
+
+use crate::{
+    custom_types::{ConditionalCheck, FieldIdent},
+    kind::{Kind, Type},
+    metas_holder::{Metas, MetasHolder},
+    types::{self, floats::Floats, ints::Ints},
+    utils::{self, Append},
+};
+
+// the checks of one value (bound to 'val' as a reference)
+struct Valdg {
+    none: TokenStream2,
+    lang: TokenStream2,
+    stack_errs: TokenStream2,
+    stack_errs_lang: TokenStream2,
+}
+
+impl Valdg {
+    #[inline]
+    fn new() -> Self {
+        Self {
+            none: TokenStream2::new(),
+            lang: TokenStream2::new(),
+            stack_errs: TokenStream2::new(),
+            stack_errs_lang: TokenStream2::new(),
+        }
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.none.is_empty() && self.stack_errs.is_empty()
+    }
+
+    #[inline]
+    fn map<F: Fn(TokenStream2) -> TokenStream2>(self, f: F) -> Self {
+        Self {
+            none: f(self.none),
+            lang: f(self.lang),
+            stack_errs: f(self.stack_errs),
+            stack_errs_lang: f(self.stack_errs_lang),
+        }
+    }
+
+    #[inline]
+    fn append(&mut self, other: Self) {
+        self.none.append(other.none);
+        self.lang.append(other.lang);
+        self.stack_errs.append(other.stack_errs);
+        self.stack_errs_lang.append(other.stack_errs_lang);
+    }
+}
+
+// 'Validate' impl: the same constraints checked by 'FromJsonValue' but on
+// an already constructed value (sanitizers are not applied)
+pub struct ValidateImpl {
+    fields: Valdg,
+    conditional_checks: Valdg,
+    var_bindings: TokenStream2,
+}
+
+impl ValidateImpl {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            fields: Valdg::new(),
+            conditional_checks: Valdg::new(),
+            var_bindings: TokenStream2::new(),
+        }
+    }
+
+    pub fn add_field(
+        &mut self,
+        attrs: &Vec<Attribute>,
+        field_ident: &FieldIdent,
+        kind: &Kind,
+        dflt_lang: &str,
+    ) -> Result<(), TokenStream> {
+        let ident = &field_ident.ident;
+        let var_name = &field_ident.var_name;
+
+        // the conditional checks expect the variables of the parsing
+        // methods (plain numbers and booleans are not references there)
+        self.var_bindings.append(match (kind.option || kind.null, &kind.ty) {
+            (false, Type::Vec(..) | Type::Custom(_) | Type::String) | (true, _) => {
+                quote! {let #var_name = &self.#ident;}
+            }
+
+            (false, _) => quote! {let #var_name = self.#ident;},
+        });
+
+        // the custom values are checked by their own 'Validate' impl
+        // unless the field opts out with 'validate(skip)' (e.g. for the
+        // types that only implement 'FromJsonValue')
+        let nested = match attrs.parse_list_if_found("validate")? {
+            Some(metas) => {
+                if let Some(meta) = metas
+                    .iter()
+                    .find(|meta| !matches!(meta, Meta::Path(path) if path.is_ident("skip")))
+                {
+                    return Err(utils::compile_err(meta, "expected 'skip'"));
+                };
+
+                metas.is_empty()
+            }
+
+            None => true,
+        };
+
+        let valdg = value_valdg(
+            Some(attrs),
+            &kind.ty,
+            &field_ident.quoted,
+            dflt_lang,
+            nested,
+        )?;
+
+        if valdg.is_empty() {
+            return Ok(());
+        };
+
+        let pat = kind.some_pattern(quote! {val});
+
+        self.fields.append(valdg.map(|valdg| {
+            if !kind.option && !kind.null {
+                return quote! {
+                    {
+                        let val = &self.#ident;
+                        #valdg
+                    }
+                };
+            };
+
+            quote! {
+                if let #pat = &self.#ident {
+                    #valdg
+                };
+            }
+        }));
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn add_conditional_check(&mut self, conditional_check: ConditionalCheck) {
+        self.conditional_checks.append(Valdg {
+            none: conditional_check.none,
+            lang: conditional_check.lang,
+            stack_errs: conditional_check.stack_errs,
+            stack_errs_lang: conditional_check.stack_errs_lang,
+        });
+    }
+
+    pub fn construct(
+        self,
+        ident: &Ident,
+        generics: &Generics,
+        struct_processing: types::structure::Processing,
+    ) -> TokenStream2 {
+        let Self {
+            fields,
+            conditional_checks,
+            var_bindings,
+        } = self;
+
+        let mut body = fields;
+
+        if !conditional_checks.is_empty() {
+            body.append(conditional_checks.map(|checks| {
+                quote! {
+                    {
+                        #var_bindings
+                        #checks
+                    }
+                }
+            }));
+        };
+
+        if !struct_processing.is_empty() {
+            let always = struct_processing.always;
+            let (valdg, valdg_lang, valdg_stack_errs, valdg_stack_errs_lang) =
+                struct_processing.into_parts();
+
+            // same as the parsing methods, struct-level validation is
+            // skipped when field-level errors exist unless 'always_validate'
+            let stack = |valdg: TokenStream2| -> TokenStream2 {
+                if always {
+                    quote! {
+                        {
+                            let val = self;
+                            #valdg
+                        }
+                    }
+                } else {
+                    quote! {
+                        if errs.is_empty() {
+                            let val = self;
+                            #valdg
+                        };
+                    }
+                }
+            };
+
+            body.append(Valdg {
+                none: quote! {
+                    {
+                        let val = self;
+                        #valdg
+                    }
+                },
+                lang: quote! {
+                    {
+                        let val = self;
+                        #valdg_lang
+                    }
+                },
+                stack_errs: stack(valdg_stack_errs),
+                stack_errs_lang: stack(valdg_stack_errs_lang),
+            });
+        };
+
+        let Valdg {
+            none,
+            lang,
+            stack_errs,
+            stack_errs_lang,
+        } = body;
+
+        quote! {
+            impl #generics ::from::Validate for #ident #generics {
+                #[allow(unused_variables)]
+                fn validate_at(&self, path: &::from::Path) -> Result<(), ::from::ValidationErr> {
+                    #none
+                    Ok(())
+                }
+
+                #[allow(unused_variables)]
+                fn validate_lang_at(&self, path: &::from::Path, lang: &str) -> Result<(), ::from::ValidationErr> {
+                    #lang
+                    Ok(())
+                }
+
+                #[allow(unused_variables, unused_mut)]
+                fn validate_stack_errs_at(&self, path: &::from::Path) -> Result<(), Vec<::from::ValidationErr>> {
+                    let mut errs = Vec::<::from::ValidationErr>::new();
+
+                    #stack_errs
+
+                    if errs.is_empty() {
+                        Ok(())
+                    } else {
+                        Err(errs)
+                    }
+                }
+
+                #[allow(unused_variables, unused_mut)]
+                fn validate_stack_errs_lang_at(&self, path: &::from::Path, lang: &str) -> Result<(), Vec<::from::ValidationErr>> {
+                    let mut errs = Vec::<::from::ValidationErr>::new();
+
+                    #stack_errs_lang
+
+                    if errs.is_empty() {
+                        Ok(())
+                    } else {
+                        Err(errs)
+                    }
+                }
+            }
+        }
+    }
+}
+
+// the checks of a value of type 'ty' bound to 'val' (a reference),
+// 'nested' calls 'Validate' on the custom values (e.g. the elements)
+fn value_valdg<H: MetasHolder>(
+    metas_holder: Option<&H>,
+    ty: &Type,
+    prop_or_idx: &TokenStream2,
+    dflt_lang: &str,
+    nested: bool,
+) -> Result<Valdg, TokenStream> {
+    match ty {
+        Type::I8 => int_valdg::<H, i8>(metas_holder, prop_or_idx, dflt_lang),
+        Type::I16 => int_valdg::<H, i16>(metas_holder, prop_or_idx, dflt_lang),
+        Type::I32 => int_valdg::<H, i32>(metas_holder, prop_or_idx, dflt_lang),
+        Type::I64 => int_valdg::<H, i64>(metas_holder, prop_or_idx, dflt_lang),
+        Type::I128 => int_valdg::<H, i128>(metas_holder, prop_or_idx, dflt_lang),
+        Type::ISize => int_valdg::<H, isize>(metas_holder, prop_or_idx, dflt_lang),
+        Type::U8 => int_valdg::<H, u8>(metas_holder, prop_or_idx, dflt_lang),
+        Type::U16 => int_valdg::<H, u16>(metas_holder, prop_or_idx, dflt_lang),
+        Type::U32 => int_valdg::<H, u32>(metas_holder, prop_or_idx, dflt_lang),
+        Type::U64 => int_valdg::<H, u64>(metas_holder, prop_or_idx, dflt_lang),
+        Type::U128 => int_valdg::<H, u128>(metas_holder, prop_or_idx, dflt_lang),
+        Type::USize => int_valdg::<H, usize>(metas_holder, prop_or_idx, dflt_lang),
+        Type::F32 => float_valdg::<H, f32>(metas_holder, prop_or_idx, dflt_lang),
+        Type::F64 => float_valdg::<H, f64>(metas_holder, prop_or_idx, dflt_lang),
+
+        Type::Bool => {
+            let metas_holder = match metas_holder {
+                Some(metas_holder) => metas_holder,
+                None => return Ok(Valdg::new()),
+            };
+
+            let processing =
+                types::bool::Processing::try_build(metas_holder, prop_or_idx, dflt_lang)?;
+
+            Ok(copied(Valdg {
+                none: processing.valdg,
+                lang: processing.valdg_lang,
+                stack_errs: processing.valdg_stack_errs,
+                stack_errs_lang: processing.valdg_stack_errs_lang,
+            }))
+        }
+
+        Type::String => {
+            let metas_holder = match metas_holder {
+                Some(metas_holder) => metas_holder,
+                None => return Ok(Valdg::new()),
+            };
+
+            // the sanitizers are not applied
+            let processing =
+                types::string::Processing::try_build(metas_holder, prop_or_idx, dflt_lang)?;
+
+            Ok(Valdg {
+                none: processing.valdg,
+                lang: processing.valdg_lang,
+                stack_errs: processing.valdg_stack_errs,
+                stack_errs_lang: processing.valdg_stack_errs_lang,
+            })
+        }
+
+        Type::Vec(vec_ty, of) => {
+            vec_valdg(metas_holder, vec_ty, of, prop_or_idx, dflt_lang, nested)
+        }
+
+        Type::Custom(custom_ty) => custom_valdg(metas_holder, custom_ty, prop_or_idx, nested),
+    }
+}
+
+#[inline]
+fn int_valdg<H: MetasHolder, I: Ints>(
+    metas_holder: Option<&H>,
+    prop_or_idx: &TokenStream2,
+    dflt_lang: &str,
+) -> Result<Valdg, TokenStream> {
+    let metas_holder = match metas_holder {
+        Some(metas_holder) => metas_holder,
+        None => return Ok(Valdg::new()),
+    };
+
+    let processing =
+        types::ints::Processing::try_build::<H, I>(metas_holder, prop_or_idx, dflt_lang)?;
+
+    Ok(copied(Valdg {
+        none: processing.valdg,
+        lang: processing.valdg_lang,
+        stack_errs: processing.valdg_stack_errs,
+        stack_errs_lang: processing.valdg_stack_errs_lang,
+    }))
+}
+
+#[inline]
+fn float_valdg<H: MetasHolder, F: Floats>(
+    metas_holder: Option<&H>,
+    prop_or_idx: &TokenStream2,
+    dflt_lang: &str,
+) -> Result<Valdg, TokenStream> {
+    let metas_holder = match metas_holder {
+        Some(metas_holder) => metas_holder,
+        None => return Ok(Valdg::new()),
+    };
+
+    let processing =
+        types::floats::Processing::try_build::<H, F>(metas_holder, prop_or_idx, dflt_lang)?;
+
+    Ok(copied(Valdg {
+        none: processing.valdg,
+        lang: processing.valdg_lang,
+        stack_errs: processing.valdg_stack_errs,
+        stack_errs_lang: processing.valdg_stack_errs_lang,
+    }))
+}
+
+// the checks of numbers and booleans take the value not a reference
+#[inline]
+fn copied(valdg: Valdg) -> Valdg {
+    if valdg.is_empty() {
+        return valdg;
+    };
+
+    valdg.map(|valdg| {
+        quote! {
+            {
+                let val = *val;
+                #valdg
+            }
+        }
+    })
+}
+
+fn vec_valdg<H: MetasHolder>(
+    metas_holder: Option<&H>,
+    vec_ty: &TokenStream2,
+    of: &Kind,
+    prop_or_idx: &TokenStream2,
+    dflt_lang: &str,
+    nested: bool,
+) -> Result<Valdg, TokenStream> {
+    let (elem_metas, processing) = match metas_holder {
+        Some(metas_holder) => (
+            metas_holder.parse_list_if_found("elem")?,
+            Some(types::vec::Processing::try_build(
+                metas_holder,
+                vec_ty,
+                &format_ident!("vec"),
+                prop_or_idx,
+                dflt_lang,
+            )?),
+        ),
+
+        None => (None, None),
+    };
+
+    let elem = value_valdg::<Metas>(elem_metas.as_ref(), &of.ty, &quote! {i}, dflt_lang, nested)?;
+
+    let vec_valdg = match processing {
+        Some(processing) => Valdg {
+            none: processing.valdg,
+            lang: processing.valdg_lang,
+            stack_errs: processing.valdg_stack_errs,
+            stack_errs_lang: processing.valdg_stack_errs_lang,
+        },
+
+        None => Valdg::new(),
+    };
+
+    if elem.is_empty() && vec_valdg.is_empty() {
+        return Ok(Valdg::new());
+    };
+
+    let elem_pat = of.some_pattern(quote! {val});
+    let elem_is_empty = elem.is_empty();
+
+    // the elements are checked first, same as the parsing methods
+    let mut valdg = elem.map(|elem| {
+        if elem_is_empty {
+            return quote! {let vec = val;};
+        };
+
+        let elem = if of.null {
+            quote! {
+                if let #elem_pat = val {
+                    #elem
+                };
+            }
+        } else {
+            elem
+        };
+
+        quote! {
+            let vec = val;
+
+            {
+                let mut path = path.clone();
+                path.push(From::from(#prop_or_idx));
+                let path = &path;
+
+                for (i, val) in vec.iter().enumerate() {
+                    #elem
+                }
+            }
+        }
+    });
+
+    valdg.append(vec_valdg);
+
+    Ok(valdg.map(|valdg| quote! {{#valdg}}))
+}
+
+fn custom_valdg<H: MetasHolder>(
+    metas_holder: Option<&H>,
+    custom_ty: &TokenStream2,
+    prop_or_idx: &TokenStream2,
+    nested: bool,
+) -> Result<Valdg, TokenStream> {
+    let processing = match metas_holder {
+        Some(metas_holder) => types::custom::Processing::try_build(metas_holder, custom_ty)?,
+        None => types::custom::Processing::new(),
+    };
+
+    let types::custom::Processing {
+        valdg,
+        valdg_lang,
+        valdg_stack_errs,
+        valdg_stack_errs_lang,
+    } = processing;
+
+    if !nested {
+        if valdg.is_empty() {
+            return Ok(Valdg::new());
+        };
+
+        return Ok(Valdg {
+            none: valdg,
+            lang: valdg_lang,
+            stack_errs: valdg_stack_errs,
+            stack_errs_lang: valdg_stack_errs_lang,
+        }
+        .map(|valdg| {
+            quote! {
+                {
+                    let mut path = path.clone();
+                    path.push(From::from(#prop_or_idx));
+
+                    #valdg
+                }
+            }
+        }));
+    };
+
+    // the validators only run if the value itself is valid, same as
+    // the parsing methods
+    Ok(Valdg {
+        none: quote! {
+            {
+                let mut path = path.clone();
+                path.push(From::from(#prop_or_idx));
+
+                <#custom_ty as ::from::Validate>::validate_at(val, &path)?;
+
+                #valdg
+            }
+        },
+
+        lang: quote! {
+            {
+                let mut path = path.clone();
+                path.push(From::from(#prop_or_idx));
+
+                <#custom_ty as ::from::Validate>::validate_lang_at(val, &path, lang)?;
+
+                #valdg_lang
+            }
+        },
+
+        stack_errs: quote! {
+            {
+                let mut path = path.clone();
+                path.push(From::from(#prop_or_idx));
+
+                match <#custom_ty as ::from::Validate>::validate_stack_errs_at(val, &path) {
+                    Ok(()) => {
+                        #valdg_stack_errs
+                    }
+
+                    Err(mut errs2) => {
+                        errs.append(&mut errs2);
+                    }
+                };
+            }
+        },
+
+        stack_errs_lang: quote! {
+            {
+                let mut path = path.clone();
+                path.push(From::from(#prop_or_idx));
+
+                match <#custom_ty as ::from::Validate>::validate_stack_errs_lang_at(val, &path, lang) {
+                    Ok(()) => {
+                        #valdg_stack_errs_lang
+                    }
+
+                    Err(mut errs2) => {
+                        errs.append(&mut errs2);
+                    }
+                };
+            }
+        },
+    })
+}